@@ -1,13 +1,24 @@
 use anyhow::{bail, Context, Result};
 use colored::Colorize;
 use indicatif::{ProgressBar, ProgressStyle};
-use std::path::PathBuf;
+use std::fs;
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use tracing::debug;
 
 use crate::config::Config;
-use crate::registry::Registry;
+use crate::registry::{self, Origin, Registry};
 use crate::utils;
+use crate::utils::undo::Snapshot;
+use crate::utils::ThemeTemplate;
 
-pub async fn run(components: Vec<String>, force: bool) -> Result<()> {
+pub async fn run(
+    components: Vec<String>,
+    force: bool,
+    from: Option<PathBuf>,
+    yes: bool,
+    no_theme: bool,
+) -> Result<()> {
     if components.is_empty() {
         bail!("Please specify at least one component to add. Run 'gpui-ui list' to see available components.");
     }
@@ -18,138 +29,356 @@ pub async fn run(components: Vec<String>, force: bool) -> Result<()> {
     }
 
     let mut config = Config::load()?;
-    let registry = Registry::new();
+    let registry = Registry::load(&config.registry_url).await;
+    // Fails on a theme value that would make the copied files not compile
+    let template = if no_theme {
+        ThemeTemplate::verbatim()
+    } else {
+        ThemeTemplate::from_style(&config.style)?
+    };
 
     println!("{}", "Adding components...".cyan().bold());
     println!();
 
-    // Get component source directory
-    let source_dir = utils::get_component_source_dir()
-        .context("Failed to locate component source directory")?;
+    // A local checkout given with --from, or the bundled sources for the
+    // bundled registry. Files of a fetched registry are downloaded below,
+    // once it's known which are needed.
+    let local_dir = match (&from, registry.origin()) {
+        (Some(dir), _) => {
+            if !dir.is_dir() {
+                bail!("Source directory {} does not exist", dir.display());
+            }
+            Some(dir.clone())
+        }
+        (None, Origin::Bundled) => Some(
+            utils::get_component_source_dir()
+                .context("Failed to locate component source directory")?,
+        ),
+        (None, Origin::Remote { .. }) => None,
+    };
+
+    super::preflight(&registry, &config, local_dir.as_deref())?;
 
     // Get destination directory from config
     let dest_dir = PathBuf::from(&config.component_path);
 
-    let mut added_components = Vec::new();
-
+    // Keep only the components the registry knows about, once each
+    let mut requested: Vec<String> = Vec::new();
     for component_name in components {
-        // Check if component exists in registry
-        let component = match registry.get_component(&component_name) {
-            Ok(c) => c,
-            Err(_) => {
-                println!(
-                    "  {} Component '{}' not found. Run 'gpui-ui list' to see available components.",
-                    "✗".red(),
-                    component_name.red()
-                );
-                continue;
+        if registry.get_component(&component_name).is_err() {
+            debug!(component = %component_name, "not in registry, skipping");
+            println!(
+                "  {} Component '{}' not found. Run 'gpui-ui list' to see available components.",
+                "✗".red(),
+                component_name.red()
+            );
+            continue;
+        }
+        if !requested.contains(&component_name) {
+            requested.push(component_name);
+        }
+    }
+
+    if requested.is_empty() {
+        bail!("None of the requested components exist.");
+    }
+
+    // Resolve everything up front so shared dependencies are copied once
+    let all_components = registry
+        .resolve_all(&requested)
+        .context("Failed to resolve component dependencies")?;
+    debug!(requested = ?requested, resolved = ?all_components, "resolved dependencies");
+
+    let mut files: Vec<(String, String)> = Vec::new();
+    for comp_name in &all_components {
+        let comp = registry.get_component(comp_name)?;
+        for file in &comp.files {
+            // Never join a path that could escape the source or destination
+            registry::check_file_path(file)
+                .context(format!("Component '{}' can't be installed", comp.name))?;
+            if let Some((_, owner)) = files.iter().find(|(existing, _)| existing == file) {
+                debug!(file = %file, component = %comp.name, owner = %owner, "file already planned");
+            } else {
+                files.push((file.clone(), comp.name.clone()));
             }
-        };
+        }
+    }
 
-        println!("  {} Adding {}", "→".cyan(), component.name.bold());
+    let source_dir = match (local_dir, registry.origin()) {
+        (Some(dir), _) => dir,
+        (None, Origin::Remote { url }) => {
+            let needed: Vec<String> = files.iter().map(|(file, _)| file.clone()).collect();
+            // The bundled files may be other versions than this registry
+            // lists, so copying them instead would install something else
+            registry::fetch_sources(url, &needed).await.context(
+                "Failed to download the component files; pass --from <dir> to copy them from a local checkout",
+            )?
+        }
+        (None, Origin::Bundled) => unreachable!("bundled registries use the bundled sources"),
+    };
+    debug!(source = %source_dir.display(), dest = %dest_dir.display(), "copying components");
 
-        // Resolve dependencies
-        let all_components = registry
-            .resolve_dependencies(&component_name)
-            .context(format!("Failed to resolve dependencies for {}", component_name))?;
+    // A custom source tree has to provide every file up front; copying half
+    // a component from it would leave the project in a broken state.
+    if from.is_some() {
+        let absent: Vec<&String> = files
+            .iter()
+            .map(|(file, _)| file)
+            .filter(|file| !source_dir.join(file).is_file())
+            .collect();
+        if !absent.is_empty() {
+            bail!(
+                "{} is missing files needed for these components: {}",
+                source_dir.display(),
+                absent
+                    .iter()
+                    .map(|file| file.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+    }
 
-        // Create progress bar
-        let total_files: usize = all_components
+    // Files edited since they were copied would lose those edits. Files
+    // still as installed, or already matching the source, are replaced quietly.
+    if force {
+        let edited: Vec<(PathBuf, &String)> = files
             .iter()
-            .filter_map(|name| registry.get_component(name).ok())
-            .map(|c| c.files.len())
-            .sum();
-
-        let pb = ProgressBar::new(total_files as u64);
-        pb.set_style(
-            ProgressStyle::default_bar()
-                .template("    [{bar:40.cyan/blue}] {pos}/{len} files")
-                .unwrap()
-                .progress_chars("=>-"),
-        );
+            .filter(|(file, _)| {
+                let source = read_themed(&source_dir, file, &template);
+                locally_edited(&config, file, source.as_deref(), &dest_dir.join(file))
+            })
+            .map(|(file, owner)| (dest_dir.join(file), owner))
+            .collect();
+        debug!(edited = ?edited, "checked for local edits");
+        if !edited.is_empty() {
+            println!("{}", "These files were edited since they were installed:".yellow());
+            for (path, owner) in &edited {
+                println!(
+                    "  {} {} (see {})",
+                    "▸".cyan(),
+                    path.display(),
+                    format!("gpui-ui diff {}", owner).cyan()
+                );
+            }
+            println!();
 
-        // Copy all files including dependencies
-        for comp_name in &all_components {
-            let comp = registry.get_component(comp_name)?;
-
-            for file in &comp.files {
-                let source_path = source_dir.join(file);
-                let dest_path = dest_dir.join(file);
-
-                // Check if source exists
-                if !source_path.exists() {
-                    println!(
-                        "    {} Source file not found: {}",
-                        "⚠".yellow(),
-                        source_path.display()
-                    );
-                    continue;
+            if !yes {
+                if !io::stdin().is_terminal() {
+                    bail!("Refusing to overwrite edited files without confirmation. Pass --yes to overwrite them.");
                 }
+                print!("Overwrite them? [y/N] ");
+                io::stdout().flush()?;
 
-                // Check if destination exists and we're not forcing
-                if dest_path.exists() && !force {
-                    if comp_name == &component_name {
-                        // Only warn for the main component, not dependencies
-                        println!(
-                            "    {} {} already exists (skipping, use --force to overwrite)",
-                            "⚠".yellow(),
-                            file
-                        );
-                    }
-                    pb.inc(1);
-                    continue;
-                }
+                let mut input = String::new();
+                io::stdin().read_line(&mut input)?;
 
-                // Copy the file
-                utils::copy_file(&source_path, &dest_path, force)
-                    .context(format!("Failed to copy {}", file))?;
+                if !input.trim().eq_ignore_ascii_case("y") {
+                    println!("{}", "Add cancelled.".yellow());
+                    return Ok(());
+                }
+            }
+        }
+    }
 
-                pb.inc(1);
+    // --force overwrites local edits, so keep what's there for `gpui-ui undo`
+    if force {
+        let mut snapshot = Snapshot::begin(format!("add --force {}", requested.join(" ")))?;
+        for (file, _) in &files {
+            if source_dir.join(file).exists() {
+                snapshot.stash(&dest_dir.join(file))?;
             }
+        }
+        snapshot.commit()?;
+    }
+
+    let pb = ProgressBar::new(files.len() as u64);
+    pb.set_style(
+        ProgressStyle::default_bar()
+            .template("  [{bar:40.cyan/blue}] {pos}/{len} files {msg}")
+            .unwrap()
+            .progress_chars("=>-"),
+    );
+
+    let mut copied = Vec::new();
+    let mut skipped = Vec::new();
+    let mut missing = Vec::new();
+
+    for (file, owner) in &files {
+        pb.set_message(file.clone());
+        let source_path = source_dir.join(file);
+        let dest_path = dest_dir.join(file);
+        debug!(
+            file = %file,
+            component = %owner,
+            source = %source_path.display(),
+            dest = %dest_path.display(),
+            "considering file"
+        );
+
+        // Check if source exists
+        let Some(content) = read_themed(&source_dir, file, &template) else {
+            debug!(file = %file, "skipped: source file not found");
+            missing.push(source_path.display().to_string());
+            pb.inc(1);
+            continue;
+        };
 
-            // Add to installed components list if not already there
-            if !config
-                .components
-                .iter()
-                .any(|c| c.name == comp.name)
-            {
-                added_components.push(comp.name.clone());
+        // Check if destination exists and we're not forcing. Only requested
+        // components are reported; existing dependency files are expected.
+        if dest_path.exists() && !force {
+            debug!(file = %file, "skipped: destination exists and --force not given");
+            if requested.contains(owner) {
+                skipped.push(file.clone());
             }
+            pb.inc(1);
+            continue;
         }
 
-        pb.finish_and_clear();
-        println!("    {} {} installed successfully", "✓".green(), component.name);
+        // Copy the file with the theme written in
+        utils::write_file(&dest_path, &content, force)
+            .context(format!("Failed to copy {}", file))?;
+        copied.push(file.clone());
+
+        pb.inc(1);
+    }
+
+    pb.finish_and_clear();
+
+    // Record the requested components (not their dependencies) in the config
+    let mut newly_added = Vec::new();
+    for component_name in &requested {
+        let component = registry.get_component(component_name)?;
+        if !config.components.iter().any(|c| c.name == component.name) {
+            config.add_component(component.name.clone(), component.version.clone());
+            newly_added.push(component.name.clone());
+        }
 
-        // If this is the main component (not a dependency), record it
-        if !config
+        // Fingerprint the files copied this time, its dependencies' included,
+        // so `lint` can spot later edits
+        let mut own_files = Vec::new();
+        for name in registry.resolve_dependencies(component_name)? {
+            own_files.extend(registry.get_component(&name)?.files.iter().cloned());
+        }
+        if let Some(installed) = config
             .components
-            .iter()
-            .any(|c| c.name == component.name)
+            .iter_mut()
+            .find(|c| c.name == component.name)
         {
-            config.add_component(component.name.clone(), component.version.clone());
+            for file in own_files.iter().filter(|file| copied.contains(*file)) {
+                let content = std::fs::read(dest_dir.join(file))
+                    .context(format!("Failed to read {}", file))?;
+                installed
+                    .checksums
+                    .insert(file.clone(), utils::checksum(&content));
+            }
+        }
+    }
+
+    let dependencies: Vec<&String> = all_components
+        .iter()
+        .filter(|name| !requested.contains(*name))
+        .collect();
+
+    // Crates the copied files use beyond gpui, once each
+    let mut crates: Vec<&String> = Vec::new();
+    for name in &all_components {
+        for krate in &registry.get_component(name)?.crates {
+            if !crates.contains(&krate) {
+                crates.push(krate);
+            }
         }
     }
 
     // Save updated config
     config.save()?;
 
-    println!();
     println!("{}", "Done!".green().bold());
+    println!();
+    println!("Components:");
+    for name in &requested {
+        let status = if newly_added.contains(name) {
+            "added".green()
+        } else {
+            "already installed".dimmed()
+        };
+        println!("  {} {} ({})", "▸".cyan(), name, status);
+    }
 
-    if !added_components.is_empty() {
+    if !dependencies.is_empty() {
         println!();
-        println!("Components added:");
-        for comp in added_components {
-            println!("  {} {}", "▸".cyan(), comp);
+        println!("Dependencies:");
+        for name in &dependencies {
+            println!("  {} {}", "▸".cyan(), name);
         }
     }
 
+    println!();
+    println!(
+        "Files: {} copied, {} skipped, {} missing",
+        copied.len().to_string().green(),
+        skipped.len().to_string().yellow(),
+        missing.len().to_string().red()
+    );
+    for file in &skipped {
+        let source = read_themed(&source_dir, file, &template);
+        let installed = fs::read_to_string(dest_dir.join(file)).ok();
+        if source.is_some() && source == installed {
+            println!("  {} {} already exists and matches the registry", "✓".green(), file);
+        } else {
+            println!(
+                "  {} {} already exists and differs from the registry (skipping; see {}, or use --force to overwrite)",
+                "⚠".yellow(),
+                file,
+                "gpui-ui diff <component>".cyan()
+            );
+        }
+    }
+    for path in &missing {
+        println!("  {} Source file not found: {}", "⚠".yellow(), path);
+    }
+
     println!();
     println!("Next steps:");
-    println!("  1. Import components in your code:");
+    let mut step = 1;
+    if !crates.is_empty() {
+        println!("  {}. Add the crates these components use to Cargo.toml's [dependencies]:", step);
+        for krate in &crates {
+            println!("     {}", krate.dimmed());
+        }
+        step += 1;
+    }
+    println!("  {}. Import components in your code:", step);
     println!("     {}", "mod components;".dimmed());
-    println!("  2. Use the components in your GPUI app:");
+    println!("  {}. Use the components in your GPUI app:", step + 1);
     println!("     {}", "use components::ui::Button;".dimmed());
 
     Ok(())
 }
+
+/// Whether the installed copy of `file` has changes that overwriting it would
+/// lose: it differs from the new source, and from the checksum recorded when
+/// it was copied. Files copied before checksums were recorded only count when
+/// they differ from the source.
+fn locally_edited(config: &Config, file: &str, source: Option<&str>, dest: &Path) -> bool {
+    let Ok(installed) = fs::read_to_string(dest) else {
+        return false;
+    };
+    if source == Some(installed.as_str()) {
+        return false;
+    }
+    let actual = utils::checksum(installed.as_bytes());
+    let mut recorded = config
+        .components
+        .iter()
+        .filter_map(|c| c.checksums.get(file))
+        .peekable();
+    recorded.peek().is_none() || recorded.all(|expected| expected != &actual)
+}
+
+/// The source file `file` in `source_dir` as it would be copied, or `None`
+/// if it can't be read.
+fn read_themed(source_dir: &Path, file: &str, template: &ThemeTemplate) -> Option<String> {
+    let content = fs::read_to_string(source_dir.join(file)).ok()?;
+    Some(template.apply(file, &content))
+}
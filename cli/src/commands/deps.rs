@@ -0,0 +1,84 @@
+use anyhow::Result;
+use colored::Colorize;
+use std::collections::HashSet;
+use tracing::debug;
+
+use crate::config::Config;
+use crate::registry::{DependencyNode, Registry};
+
+pub async fn run(component_name: String, dot: bool) -> Result<()> {
+    let registry = Registry::load(&Config::registry_url()).await;
+    let tree = registry.dependency_tree(&component_name)?;
+    debug!("Resolved dependency tree for {}", tree.name);
+
+    if dot {
+        print!("{}", to_dot(&tree));
+        return Ok(());
+    }
+
+    println!("{}", tree.name.bold());
+    if print_children(&tree, "", &mut HashSet::new()) {
+        println!();
+        println!("{}", "(*) dependencies already shown above".dimmed());
+    }
+
+    Ok(())
+}
+
+/// Prints `node`'s dependencies below it. A component that was already
+/// expanded is printed once more with a `(*)` marker instead of repeating
+/// its subtree, like `cargo tree`. Returns whether any marker was printed.
+fn print_children(node: &DependencyNode, prefix: &str, expanded: &mut HashSet<String>) -> bool {
+    let mut elided = false;
+    let count = node.dependencies.len();
+    for (index, child) in node.dependencies.iter().enumerate() {
+        let last = index + 1 == count;
+        let branch = if last { "└── " } else { "├── " };
+
+        if !child.dependencies.is_empty() && !expanded.insert(child.name.clone()) {
+            println!(
+                "{}{}{} {}",
+                prefix,
+                branch.dimmed(),
+                child.name,
+                "(*)".dimmed()
+            );
+            elided = true;
+            continue;
+        }
+
+        println!("{}{}{}", prefix, branch.dimmed(), child.name);
+        let child_prefix = format!("{}{}", prefix, if last { "    " } else { "│   " });
+        elided |= print_children(child, &child_prefix, expanded);
+    }
+    elided
+}
+
+/// Renders the tree as a Graphviz digraph, with one edge per dependency.
+fn to_dot(tree: &DependencyNode) -> String {
+    let mut edges = Vec::new();
+    let mut seen = HashSet::new();
+    collect_edges(tree, &mut edges, &mut seen);
+
+    let mut dot = String::from("digraph dependencies {\n");
+    dot.push_str(&format!("    \"{}\";\n", tree.name));
+    for (from, to) in edges {
+        dot.push_str(&format!("    \"{}\" -> \"{}\";\n", from, to));
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+fn collect_edges(
+    node: &DependencyNode,
+    edges: &mut Vec<(String, String)>,
+    seen: &mut HashSet<(String, String)>,
+) {
+    for child in &node.dependencies {
+        let edge = (node.name.clone(), child.name.clone());
+        if seen.insert(edge.clone()) {
+            edges.push(edge);
+            collect_edges(child, edges, seen);
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use similar::TextDiff;
+use std::fs;
+use std::path::PathBuf;
+use tracing::debug;
+
+use crate::config::Config;
+use crate::registry::{self, Origin, Registry};
+use crate::utils::{self, ThemeTemplate};
+
+pub async fn run(component_name: String, no_theme: bool) -> Result<()> {
+    if !Config::exists() {
+        bail!("gpui-ui is not initialized in this directory. Run 'gpui-ui init' first.");
+    }
+
+    let config = Config::load()?;
+    let registry = Registry::load(&config.registry_url).await;
+    // Compare against the files as `add` writes them
+    let template = if no_theme {
+        ThemeTemplate::verbatim()
+    } else {
+        ThemeTemplate::from_style(&config.style)?
+    };
+    let component = registry.get_component(&component_name)?;
+    let dest_dir = PathBuf::from(&config.component_path);
+
+    let source_dir = match registry.origin() {
+        Origin::Bundled => utils::get_component_source_dir()
+            .context("Failed to locate component source directory")?,
+        Origin::Remote { url } => registry::fetch_sources(url, &component.files).await?,
+    };
+    debug!(source = %source_dir.display(), dest = %dest_dir.display(), "diffing component");
+
+    let mut changed = 0;
+    for file in &component.files {
+        let source_path = source_dir.join(file);
+        let dest_path = dest_dir.join(file);
+
+        let Ok(installed) = fs::read_to_string(&dest_path) else {
+            println!("{} {} is not on disk", "⚠".yellow(), dest_path.display());
+            continue;
+        };
+        let source = fs::read_to_string(&source_path)
+            .context(format!("Failed to read {}", source_path.display()))?;
+        let source = template.apply(file, &source);
+        if source == installed {
+            println!("{} {} matches the registry", "✓".green(), dest_path.display());
+            continue;
+        }
+
+        changed += 1;
+        let diff = unified_diff(
+            &source,
+            &installed,
+            &format!("registry/{}", file),
+            &dest_path.display().to_string(),
+        );
+        for line in diff.lines() {
+            if line.starts_with("+++") || line.starts_with("---") {
+                println!("{}", line.bold());
+            } else if line.starts_with("@@") {
+                println!("{}", line.cyan());
+            } else if line.starts_with('+') {
+                println!("{}", line.green());
+            } else if line.starts_with('-') {
+                println!("{}", line.red());
+            } else {
+                println!("{}", line);
+            }
+        }
+    }
+
+    if changed > 0 {
+        println!();
+        println!(
+            "{} {} from the registry. {} would replace {}.",
+            changed.to_string().yellow().bold(),
+            if changed == 1 { "file differs" } else { "files differ" },
+            format!("gpui-ui add {} --force", component.name).cyan(),
+            if changed == 1 { "it" } else { "them" }
+        );
+    }
+
+    Ok(())
+}
+
+/// A unified diff from `old` to `new` with three lines of context.
+fn unified_diff(old: &str, new: &str, old_label: &str, new_label: &str) -> String {
+    TextDiff::from_lines(old, new)
+        .unified_diff()
+        .context_radius(3)
+        .header(old_label, new_label)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unified_diff() {
+        let old = "fn a() {}\nfn b() {}\n";
+        let new = "fn a() {}\nfn c() {}\n";
+        assert_eq!(
+            unified_diff(old, new, "registry/x.rs", "src/x.rs"),
+            "--- registry/x.rs\n+++ src/x.rs\n@@ -1,2 +1,2 @@\n fn a() {}\n-fn b() {}\n+fn c() {}\n"
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use tracing::debug;
+
+use crate::config::Config;
+
+/// What `eject` removed from the project.
+#[derive(Debug, PartialEq, Eq)]
+enum Ejected {
+    DeletedConfig,
+    /// The number of installed components cleared from gpui-ui.json.
+    Untracked(usize),
+}
+
+pub async fn run(delete_config: bool, yes: bool) -> Result<()> {
+    if !Config::exists() {
+        bail!("gpui-ui is not initialized in this directory. Nothing to eject.");
+    }
+
+    let config = Config::load()?;
+    let dest_dir = PathBuf::from(&config.component_path);
+
+    if !yes {
+        let what = if delete_config {
+            "delete gpui-ui.json"
+        } else {
+            "clear the installed-components list in gpui-ui.json"
+        };
+        print!(
+            "This will {}. Component files in {} stay as they are. Continue? [y/N] ",
+            what,
+            dest_dir.display()
+        );
+        io::stdout().flush()?;
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+
+        if !input.trim().eq_ignore_ascii_case("y") {
+            println!("{}", "Eject cancelled.".yellow());
+            return Ok(());
+        }
+    }
+
+    println!("{}", "Ejecting...".cyan().bold());
+    println!();
+
+    let project_dir = std::env::current_dir().context("Failed to get current directory")?;
+    match eject(&project_dir, delete_config)? {
+        Ejected::DeletedConfig => println!("  {} Deleted gpui-ui.json", "✓".green()),
+        Ejected::Untracked(0) => println!("  {} No installed components to untrack", "▸".cyan()),
+        Ejected::Untracked(count) => println!(
+            "  {} Cleared {} installed component(s) from gpui-ui.json",
+            "✓".green(),
+            count
+        ),
+    }
+
+    println!();
+    println!("{}", "Done!".green().bold());
+    println!(
+        "The files in {} are now yours; gpui-ui will no longer track or update them.",
+        dest_dir.display()
+    );
+
+    Ok(())
+}
+
+/// Drops gpui-ui's tracking from the project at `project_dir`. Only
+/// gpui-ui.json changes: the CLI adds no markers to the files it copies, so
+/// there is nothing in them to remove.
+fn eject(project_dir: &Path, delete_config: bool) -> Result<Ejected> {
+    if delete_config {
+        Config::delete_from(project_dir)?;
+        return Ok(Ejected::DeletedConfig);
+    }
+
+    let mut config = Config::load_from(project_dir)?;
+    let count = config.components.len();
+    debug!(count, "clearing installed components");
+    if count > 0 {
+        config.components.clear();
+        config.save_to(project_dir)?;
+    }
+    Ok(Ejected::Untracked(count))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils;
+    use std::fs;
+
+    #[test]
+    fn test_eject_leaves_component_files_unchanged() {
+        let project = std::env::temp_dir().join(format!("gpui-ui-eject-{}", std::process::id()));
+        let dest = project.join("src/components/ui");
+        fs::create_dir_all(&dest).unwrap();
+
+        // Both files open with module docs that name the CLI
+        let source_dir = utils::get_component_source_dir().unwrap();
+        let files = ["colors.rs", "theme.rs"];
+        for file in files {
+            fs::copy(source_dir.join(file), dest.join(file)).unwrap();
+        }
+        let mut config = Config::new();
+        config.add_component("theme".to_string(), "0.1.0".to_string());
+        config.save_to(&project).unwrap();
+
+        assert_eq!(eject(&project, false).unwrap(), Ejected::Untracked(1));
+        assert!(Config::load_from(&project).unwrap().components.is_empty());
+        for file in files {
+            assert_eq!(
+                fs::read(dest.join(file)).unwrap(),
+                fs::read(source_dir.join(file)).unwrap(),
+                "{} changed",
+                file
+            );
+        }
+
+        fs::remove_dir_all(&project).unwrap();
+    }
+}
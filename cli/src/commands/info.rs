@@ -4,8 +4,8 @@ use colored::Colorize;
 use crate::config::Config;
 use crate::registry::Registry;
 
-pub async fn run(component_name: String) -> Result<()> {
-    let registry = Registry::new();
+pub async fn run(component_name: String, examples: bool) -> Result<()> {
+    let registry = Registry::load(&Config::registry_url()).await;
 
     let component = registry.get_component(&component_name)?;
 
@@ -32,6 +32,19 @@ pub async fn run(component_name: String) -> Result<()> {
         println!("  {} {}", "▸".cyan(), file);
     }
 
+    if examples {
+        println!();
+        println!("{}", "Example:".cyan());
+        match &component.example {
+            Some(example) => {
+                for line in example.lines() {
+                    println!("  {}", line);
+                }
+            }
+            None => println!("  {}", "No example available".dimmed()),
+        }
+    }
+
     // Check if installed
     if Config::exists() {
         if let Ok(config) = Config::load() {
@@ -0,0 +1,213 @@
+use anyhow::{bail, Result};
+use colored::Colorize;
+use std::fs;
+use std::path::PathBuf;
+use tracing::debug;
+
+use crate::config::Config;
+use crate::registry::{parse_version, Origin, Registry, Severity};
+use crate::utils;
+
+/// Theme colors and the defaults the components fall back to, matching
+/// `colors.rs`. A literal default left in a component stops following the
+/// theme once gpui-ui.json overrides that color.
+const THEME_DEFAULTS: &[(&str, u32)] = &[
+    ("primary", 0x3b82f6),
+    ("secondary", 0x64748b),
+    ("destructive", 0xef4444),
+    ("success", 0x22c55e),
+    ("warning", 0xf59e0b),
+    ("muted", 0xf1f5f9),
+    ("accent", 0xf0f9ff),
+    ("background", 0xffffff),
+    ("foreground", 0x0f172a),
+    ("muted_foreground", 0x64748b),
+    ("border", 0xe2e8f0),
+];
+
+/// Files that define the defaults themselves, so their literals are expected.
+const TOKEN_FILES: &[&str] = &["colors.rs", "theme.rs"];
+
+/// A theme color whose configured value differs from its default.
+struct Override {
+    name: &'static str,
+    default: u32,
+    value: u32,
+}
+
+pub async fn run() -> Result<()> {
+    if !Config::exists() {
+        bail!("gpui-ui is not initialized in this directory. Run 'gpui-ui init' first.");
+    }
+
+    let config = Config::load()?;
+    let registry = Registry::load(&config.registry_url).await;
+    let dest_dir = PathBuf::from(&config.component_path);
+
+    if config.components.is_empty() {
+        println!("{}", "No components installed.".yellow());
+        println!("Run {} to add components", "gpui-ui add <component>".cyan());
+        return Ok(());
+    }
+
+    let overrides = theme_overrides(&config);
+    debug!(count = overrides.len(), "theme colors overridden in config");
+
+    println!("{}", "Linting installed components...".cyan().bold());
+    println!();
+
+    let source_dir = match registry.origin() {
+        Origin::Bundled => utils::get_component_source_dir().ok(),
+        Origin::Remote { .. } => None,
+    };
+    let issues = registry.check_project(&config, source_dir.as_deref());
+    let mut warnings = issues.len();
+    if !issues.is_empty() {
+        println!("{}", "Registry:".bold());
+        for issue in &issues {
+            match issue.severity {
+                Severity::Error => println!("  {} {}", "✗".red(), issue),
+                Severity::Warning => println!("  {} {}", "⚠".yellow(), issue),
+            }
+        }
+        println!();
+    }
+
+    for installed in &config.components {
+        let mut problems: Vec<String> = Vec::new();
+
+        // Components missing from the registry, or installed from a newer
+        // one, are reported with its issues
+        let Ok(component) = registry.get_component(&installed.name) else {
+            continue;
+        };
+        if parse_version(&component.version) > parse_version(&installed.version) {
+            problems.push(format!(
+                "update available: {} → {}. Run {} to see what changed",
+                installed.version,
+                component.version,
+                format!("gpui-ui update {}", installed.name).cyan()
+            ));
+        }
+
+        if installed.checksums.is_empty() {
+            problems.push(format!(
+                "no checksums recorded, so edits can't be detected. Reinstall with {} to start tracking",
+                format!("gpui-ui add {} --force", installed.name).cyan()
+            ));
+        }
+
+        for (file, expected) in &installed.checksums {
+            let path = dest_dir.join(file);
+            let Ok(content) = fs::read(&path) else {
+                problems.push(format!(
+                    "{} is missing. Run {} to restore it",
+                    path.display(),
+                    format!("gpui-ui add {} --force", installed.name).cyan()
+                ));
+                continue;
+            };
+            let actual = utils::checksum(&content);
+            debug!(file = %file, expected = %expected, actual = %actual, "comparing checksums");
+            if &actual != expected {
+                problems.push(format!(
+                    "{} was modified since install. {} will overwrite your changes, so port them by hand",
+                    path.display(),
+                    format!("gpui-ui add {} --force", installed.name).cyan()
+                ));
+            }
+        }
+
+        let files = component.files.iter();
+        for file in files.filter(|file| !TOKEN_FILES.contains(&file.as_str())) {
+            let path = dest_dir.join(file);
+            let Ok(content) = fs::read_to_string(&path) else {
+                continue;
+            };
+            for (line, color) in color_literals(&content) {
+                let stale: Vec<&Override> = overrides
+                    .iter()
+                    .filter(|o| o.default == color)
+                    .collect();
+                // Still correct if some theme color currently has this value
+                if stale.is_empty() || overrides_value(&overrides, color) {
+                    continue;
+                }
+                let names: Vec<&str> = stale.iter().map(|o| o.name).collect();
+                problems.push(format!(
+                    "{}:{} hardcodes rgb(0x{:06x}), the default {}, but the theme sets it to rgb(0x{:06x}). Use {} instead",
+                    path.display(),
+                    line,
+                    color,
+                    names.join("/"),
+                    stale[0].value,
+                    format!("cx.theme().{}", stale[0].name).cyan()
+                ));
+            }
+        }
+
+        if problems.is_empty() {
+            println!("  {} {}", "✓".green(), installed.name);
+        } else {
+            println!("  {} {}", "⚠".yellow(), installed.name.yellow());
+            for problem in &problems {
+                println!("    {} {}", "-".dimmed(), problem);
+            }
+            warnings += problems.len();
+        }
+    }
+
+    println!();
+    if warnings == 0 {
+        println!("{}", "No drift found.".green().bold());
+    } else {
+        println!(
+            "{} {}",
+            warnings.to_string().yellow().bold(),
+            if warnings == 1 { "warning" } else { "warnings" }
+        );
+    }
+
+    Ok(())
+}
+
+/// Theme colors gpui-ui.json sets to something other than their default.
+/// Unreadable values are skipped; the components ignore those too.
+fn theme_overrides(config: &Config) -> Vec<Override> {
+    THEME_DEFAULTS
+        .iter()
+        .filter_map(|&(name, default)| {
+            let (r, g, b) = utils::parse_color(config.style.colors.get(name)?)?;
+            let value = (u32::from(r) << 16) | (u32::from(g) << 8) | u32::from(b);
+            (value != default).then_some(Override {
+                name,
+                default,
+                value,
+            })
+        })
+        .collect()
+}
+
+fn overrides_value(overrides: &[Override], color: u32) -> bool {
+    overrides.iter().any(|o| o.value == color)
+}
+
+/// Every `rgb(0x......)` and `hex(0x......)` literal with its 1-based line.
+fn color_literals(content: &str) -> Vec<(usize, u32)> {
+    let mut found = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        for prefix in ["rgb(0x", "hex(0x"] {
+            for (start, _) in line.match_indices(prefix) {
+                let digits = &line[start + prefix.len()..];
+                let hex: String = digits.chars().take_while(char::is_ascii_hexdigit).collect();
+                if hex.len() != 6 {
+                    continue;
+                }
+                if let Ok(value) = u32::from_str_radix(&hex, 16) {
+                    found.push((index + 1, value));
+                }
+            }
+        }
+    }
+    found
+}
@@ -1,26 +1,62 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 use colored::Colorize;
 
 use crate::config::Config;
 use crate::registry::Registry;
 
-pub async fn run(verbose: bool) -> Result<()> {
-    let registry = Registry::new();
-    let components = registry.list_components();
+pub async fn run(verbose: bool, installed: bool, available: bool) -> Result<()> {
+    if installed && available {
+        bail!("--installed and --available cannot be used together.");
+    }
+
+    let registry = Registry::load(&Config::registry_url()).await;
+
+    // Installed component names, if the project is initialized
+    let installed_names: Vec<String> = if Config::exists() {
+        Config::load()
+            .map(|config| config.components.into_iter().map(|c| c.name).collect())
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    let is_installed = |name: &str| installed_names.iter().any(|n| n == name);
+
+    let components: Vec<_> = registry
+        .list_components()
+        .into_iter()
+        .filter(|component| {
+            if installed {
+                is_installed(&component.name)
+            } else if available {
+                !is_installed(&component.name)
+            } else {
+                true
+            }
+        })
+        .collect();
 
-    println!("{}", "Available components:".cyan().bold());
+    let heading = if installed {
+        "Installed components:"
+    } else {
+        "Available components:"
+    };
+    println!("{}", heading.cyan().bold());
     println!();
 
+    if components.is_empty() {
+        if installed {
+            println!("  No components installed yet.");
+        } else {
+            println!("  Every component is already installed.");
+        }
+    }
+
     for component in components {
         print!("  {} {}", "▸".cyan(), component.name.bold());
 
-        // Show if already installed
-        if Config::exists() {
-            if let Ok(config) = Config::load() {
-                if config.components.iter().any(|c| c.name == component.name) {
-                    print!(" {}", "(installed)".green().dimmed());
-                }
-            }
+        // Show if already installed, unless the list is filtered by it
+        if !installed && !available && is_installed(&component.name) {
+            print!(" {}", "(installed)".green().dimmed());
         }
 
         println!();
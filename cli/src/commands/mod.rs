@@ -3,3 +3,44 @@ pub mod add;
 pub mod list;
 pub mod update;
 pub mod info;
+pub mod remove;
+pub mod deps;
+pub mod eject;
+pub mod preview;
+pub mod lint;
+pub mod undo;
+pub mod diff;
+
+use anyhow::{bail, Result};
+use colored::Colorize;
+use std::path::Path;
+
+use crate::config::Config;
+use crate::registry::{Registry, Severity};
+
+/// Reports registry and config inconsistencies before a command does any
+/// work. Warnings are printed and the command goes ahead; errors mean the
+/// registry can't resolve components, so the command stops.
+pub(crate) fn preflight(
+    registry: &Registry,
+    config: &Config,
+    source_dir: Option<&Path>,
+) -> Result<()> {
+    let issues = registry.check_project(config, source_dir);
+    if issues.is_empty() {
+        return Ok(());
+    }
+
+    for issue in &issues {
+        match issue.severity {
+            Severity::Error => println!("  {} {}", "✗".red(), issue),
+            Severity::Warning => println!("  {} {}", "⚠".yellow(), issue),
+        }
+    }
+    println!();
+
+    if issues.iter().any(|issue| issue.severity == Severity::Error) {
+        bail!("The component registry is inconsistent; fix the errors above and try again.");
+    }
+    Ok(())
+}
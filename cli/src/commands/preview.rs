@@ -0,0 +1,158 @@
+use anyhow::{bail, Result};
+use colored::Colorize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use tracing::debug;
+
+use crate::config::{Config, CONFIG_FILE_NAME};
+use crate::utils::{parse_color, parse_pixels};
+
+/// How often `--watch` checks for saved changes. Matches the polling interval
+/// of the components' `theme::watch_config`.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The color names `Theme::from_config` reads, in the order they're shown.
+const THEME_COLORS: &[&str] = &[
+    "primary",
+    "secondary",
+    "destructive",
+    "success",
+    "warning",
+    "muted",
+    "accent",
+    "background",
+    "foreground",
+    "muted_foreground",
+    "border",
+];
+
+pub async fn run(watch: bool) -> Result<()> {
+    if !Config::exists() {
+        bail!("gpui-ui is not initialized in this directory. Run 'gpui-ui init' first.");
+    }
+
+    let config_path = PathBuf::from(CONFIG_FILE_NAME);
+    print_theme()?;
+
+    if !watch {
+        return Ok(());
+    }
+
+    let component_dir = PathBuf::from(Config::load()?.component_path);
+    println!();
+    println!(
+        "{} {} and {} (Ctrl-C to stop)",
+        "Watching".cyan().bold(),
+        CONFIG_FILE_NAME,
+        component_dir.display()
+    );
+    println!(
+        "Theme changes reach a running app that calls `theme::watch_config` \
+         (from the `hot_reload` feature) without a rebuild. Component code changes need a rebuild: Rust can't \
+         hot-reload it."
+    );
+
+    let mut config_modified = modified(&config_path);
+    let mut sources = snapshot(&component_dir);
+    loop {
+        tokio::time::sleep(POLL_INTERVAL).await;
+
+        let now = modified(&config_path);
+        if now != config_modified {
+            config_modified = now;
+            debug!("{} changed", CONFIG_FILE_NAME);
+            println!();
+            if let Err(err) = print_theme() {
+                println!("  {} {:#}", "✗".red(), err);
+            }
+        }
+
+        let current = snapshot(&component_dir);
+        if current != sources {
+            println!();
+            for path in changed_files(&sources, &current) {
+                println!(
+                    "  {} {} changed; rebuild your app to see it",
+                    "▸".cyan(),
+                    path.display()
+                );
+            }
+            sources = current;
+        }
+    }
+}
+
+/// Prints each theme color as a swatch beside its value, plus the radius,
+/// flagging values the components would ignore.
+fn print_theme() -> Result<()> {
+    let style = Config::load()?.style;
+
+    println!("{}", "Theme:".cyan().bold());
+    for &name in THEME_COLORS {
+        match style.colors.get(name) {
+            Some(value) => match parse_color(value) {
+                Some((r, g, b)) => {
+                    println!("  {} {:<18} {}", "    ".on_truecolor(r, g, b), name, value)
+                }
+                None => println!(
+                    "  {} {:<18} {} {}",
+                    "    ".normal(),
+                    name,
+                    value,
+                    "(not a color; the default is used)".yellow()
+                ),
+            },
+            None => println!("  {} {:<18} {}", "    ".normal(), name, "default".dimmed()),
+        }
+    }
+
+    match Some(style.radius.as_str()).filter(|radius| !radius.trim().is_empty()) {
+        Some(radius) if parse_pixels(radius).is_some() => {
+            println!("  {:<23} {}", "radius", radius)
+        }
+        Some(radius) => println!(
+            "  {:<23} {} {}",
+            "radius",
+            radius,
+            "(not a size; the default is used)".yellow()
+        ),
+        None => println!("  {:<23} {}", "radius", "default".dimmed()),
+    }
+
+    Ok(())
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+/// Modification times of the `.rs` files directly inside `dir`.
+fn snapshot(dir: &Path) -> BTreeMap<PathBuf, Option<SystemTime>> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return BTreeMap::new();
+    };
+    entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .map(|path| {
+            let time = modified(&path);
+            (path, time)
+        })
+        .collect()
+}
+
+/// Files added, removed, or saved between two snapshots.
+fn changed_files<'a>(
+    before: &'a BTreeMap<PathBuf, Option<SystemTime>>,
+    after: &'a BTreeMap<PathBuf, Option<SystemTime>>,
+) -> Vec<&'a PathBuf> {
+    let mut changed: Vec<&PathBuf> = after
+        .iter()
+        .filter(|(path, time)| before.get(*path) != Some(time))
+        .map(|(path, _)| path)
+        .collect();
+    changed.extend(before.keys().filter(|path| !after.contains_key(*path)));
+    changed
+}
@@ -0,0 +1,146 @@
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use std::fs;
+use std::path::PathBuf;
+use tracing::debug;
+
+use crate::config::Config;
+use crate::registry::Registry;
+use crate::utils::undo::Snapshot;
+
+pub async fn run(components: Vec<String>, force: bool, keep_files: bool) -> Result<()> {
+    if components.is_empty() {
+        bail!("Please specify at least one component to remove.");
+    }
+
+    // Check if project is initialized
+    if !Config::exists() {
+        bail!("gpui-ui is not initialized in this directory. Run 'gpui-ui init' first.");
+    }
+
+    let mut config = Config::load()?;
+    let registry = Registry::load(&config.registry_url).await;
+    super::preflight(&registry, &config, None)?;
+    let dest_dir = PathBuf::from(&config.component_path);
+
+    let installed: Vec<String> = config.components.iter().map(|c| c.name.clone()).collect();
+
+    // Keep the requested components that are actually installed, with the
+    // files of theirs still on disk
+    let mut targets: Vec<(String, Vec<PathBuf>)> = Vec::new();
+    for component_name in components {
+        let Ok(component) = registry.get_component(&component_name) else {
+            println!(
+                "  {} Component '{}' not found. Run 'gpui-ui list' to see available components.",
+                "✗".red(),
+                component_name.red()
+            );
+            continue;
+        };
+        if targets.iter().any(|(name, _)| name == &component.name) {
+            continue;
+        }
+
+        let is_listed = installed.contains(&component.name);
+        let present_files: Vec<PathBuf> = component
+            .files
+            .iter()
+            .map(|file| dest_dir.join(file))
+            .filter(|path| path.exists())
+            .collect();
+        debug!(
+            component = %component.name,
+            listed = is_listed,
+            files = ?present_files,
+            "files present on disk"
+        );
+
+        if !is_listed && present_files.is_empty() {
+            println!(
+                "  {} Component '{}' is not installed, skipping",
+                "⚠".yellow(),
+                component.name
+            );
+            continue;
+        }
+        targets.push((component.name.clone(), present_files));
+    }
+
+    if targets.is_empty() {
+        println!("{}", "Nothing to remove.".yellow());
+        return Ok(());
+    }
+
+    // Refuse to pull a dependency out from under installed components.
+    // Components removed together don't block each other.
+    let remaining: Vec<String> = installed
+        .iter()
+        .filter(|name| !targets.iter().any(|(target, _)| target == *name))
+        .cloned()
+        .collect();
+    let blocked: Vec<(&String, Vec<String>)> = targets
+        .iter()
+        .map(|(name, _)| (name, registry.dependents(name, &remaining)))
+        .filter(|(_, dependents)| !dependents.is_empty())
+        .collect();
+    debug!(blocked = ?blocked, "checked dependents");
+    if !blocked.is_empty() {
+        let lines: Vec<String> = blocked
+            .iter()
+            .map(|(name, dependents)| format!("  '{}' is needed by {}", name, dependents.join(", ")))
+            .collect();
+        if !force {
+            bail!(
+                "Cannot remove components other installed components depend on:\n{}\nRemove those first or use --force.",
+                lines.join("\n")
+            );
+        }
+        for (name, dependents) in &blocked {
+            println!(
+                "  {} Removing '{}' even though {} depend on it",
+                "⚠".yellow(),
+                name,
+                dependents.join(", ")
+            );
+        }
+    }
+
+    let names: Vec<&str> = targets.iter().map(|(name, _)| name.as_str()).collect();
+    println!("{} {}", "Removing".cyan().bold(), names.join(", ").bold());
+
+    let operation = if keep_files {
+        format!("remove --keep-files {}", names.join(" "))
+    } else {
+        format!("remove {}", names.join(" "))
+    };
+    let mut snapshot = Snapshot::begin(operation)?;
+    if !keep_files {
+        for (_, present_files) in &targets {
+            for path in present_files {
+                snapshot.stash(path)?;
+            }
+        }
+    }
+    snapshot.commit()?;
+
+    for (name, present_files) in &targets {
+        if keep_files {
+            for path in present_files {
+                println!("  {} Kept {}", "▸".cyan(), path.display());
+            }
+        } else {
+            for path in present_files {
+                fs::remove_file(path).context(format!("Failed to remove {}", path.display()))?;
+                println!("  {} Deleted {}", "✓".green(), path.display());
+            }
+        }
+        config.remove_component(name);
+    }
+    config.save()?;
+
+    println!();
+    println!("{}", "Done!".green().bold());
+    println!("Run {} to bring it back.", "gpui-ui undo".cyan());
+
+    Ok(())
+}
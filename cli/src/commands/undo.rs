@@ -0,0 +1,57 @@
+use anyhow::{bail, Result};
+use colored::Colorize;
+use tracing::debug;
+
+use crate::config::Config;
+use crate::utils::undo;
+
+pub async fn run(list: bool) -> Result<()> {
+    if !Config::exists() {
+        bail!("gpui-ui is not initialized in this directory. Run 'gpui-ui init' first.");
+    }
+
+    let entries = undo::entries()?;
+    if entries.is_empty() {
+        println!("{}", "Nothing to undo.".yellow());
+        return Ok(());
+    }
+
+    if list {
+        println!("{}", "Undoable operations (most recent first):".cyan().bold());
+        for (_, manifest) in &entries {
+            println!(
+                "  {} {} {}",
+                "▸".cyan(),
+                manifest.operation,
+                manifest.created_at.dimmed()
+            );
+        }
+        return Ok(());
+    }
+
+    let (dir, manifest) = &entries[0];
+    debug!(dir = %dir.display(), operation = %manifest.operation, "undoing");
+    println!("{} {}", "Undoing".cyan().bold(), manifest.operation.bold());
+
+    undo::restore(dir, manifest)?;
+    for file in &manifest.files {
+        if file.existed {
+            println!("  {} Restored {}", "✓".green(), file.path.display());
+        } else {
+            println!("  {} Removed {}", "✓".green(), file.path.display());
+        }
+    }
+    println!("  {} Restored gpui-ui.json", "✓".green());
+
+    println!();
+    println!("{}", "Done!".green().bold());
+    if entries.len() > 1 {
+        println!(
+            "{} more operation(s) can be undone. Run {} to see them.",
+            entries.len() - 1,
+            "gpui-ui undo --list".cyan()
+        );
+    }
+
+    Ok(())
+}
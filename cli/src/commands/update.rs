@@ -1,8 +1,10 @@
 use anyhow::{bail, Result};
 use colored::Colorize;
+use tracing::debug;
 
 use crate::config::Config;
-use crate::registry::Registry;
+use crate::registry::{Origin, Registry};
+use crate::utils;
 
 pub async fn run(components: Vec<String>) -> Result<()> {
     // Check if project is initialized
@@ -11,7 +13,13 @@ pub async fn run(components: Vec<String>) -> Result<()> {
     }
 
     let config = Config::load()?;
-    let registry = Registry::new();
+    let registry = Registry::load(&config.registry_url).await;
+    // Fetched registries serve their own files, so only check the bundled ones
+    let source_dir = match registry.origin() {
+        Origin::Bundled => utils::get_component_source_dir().ok(),
+        Origin::Remote { .. } => None,
+    };
+    super::preflight(&registry, &config, source_dir.as_deref())?;
 
     if components.is_empty() {
         // Update all installed components
@@ -28,6 +36,12 @@ pub async fn run(components: Vec<String>) -> Result<()> {
             // Check if component exists in registry
             match registry.get_component(&installed.name) {
                 Ok(component) => {
+                    debug!(
+                        component = %installed.name,
+                        installed = %installed.version,
+                        latest = %component.version,
+                        "comparing versions"
+                    );
                     if component.version == installed.version {
                         println!(
                             "  {} {} is already up to date (v{})",
@@ -86,6 +100,12 @@ pub async fn run(components: Vec<String>) -> Result<()> {
             // Check if exists in registry
             match registry.get_component(&component_name) {
                 Ok(component) => {
+                    debug!(
+                        component = %component_name,
+                        installed = %installed.version,
+                        latest = %component.version,
+                        "comparing versions"
+                    );
                     if component.version == installed.version {
                         println!(
                             "  {} {} is already up to date (v{})",
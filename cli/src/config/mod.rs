@@ -1,7 +1,9 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use tracing::debug;
 
 pub const CONFIG_FILE_NAME: &str = "gpui-ui.json";
 
@@ -16,6 +18,11 @@ pub struct Config {
     #[serde(default = "default_gpui_version")]
     pub gpui_version: String,
 
+    /// Where the component registry is fetched from. Component files are
+    /// served from `components/src/` beside it.
+    #[serde(default = "default_registry_url")]
+    pub registry_url: String,
+
     #[serde(default)]
     pub style: StyleConfig,
 
@@ -23,13 +30,18 @@ pub struct Config {
     pub components: Vec<InstalledComponent>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StyleConfig {
     #[serde(default)]
     pub colors: ColorConfig,
 
     #[serde(default = "default_radius")]
     pub radius: String,
+
+    /// How long component transitions take. Left out, the components' 150ms
+    /// default is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub animation_duration_ms: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -48,6 +60,47 @@ pub struct ColorConfig {
 
     #[serde(default = "default_accent")]
     pub accent: String,
+
+    // The rest of the theme's colors. `init` doesn't write them, so they are
+    // only saved back when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub foreground: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub muted_foreground: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub border: Option<String>,
+}
+
+impl ColorConfig {
+    /// The color configured under `name`, as written in gpui-ui.json, or
+    /// `None` for an unset or unknown name.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        match name {
+            "primary" => Some(&self.primary),
+            "secondary" => Some(&self.secondary),
+            "destructive" => Some(&self.destructive),
+            "muted" => Some(&self.muted),
+            "accent" => Some(&self.accent),
+            "success" => self.success.as_deref(),
+            "warning" => self.warning.as_deref(),
+            "background" => self.background.as_deref(),
+            "foreground" => self.foreground.as_deref(),
+            "muted_foreground" => self.muted_foreground.as_deref(),
+            "border" => self.border.as_deref(),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,6 +108,11 @@ pub struct InstalledComponent {
     pub name: String,
     pub version: String,
     pub installed_at: String,
+
+    /// Checksums of the component's files as copied, keyed by file name, so
+    /// `lint` can tell which ones have been edited since.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub checksums: BTreeMap<String, String>,
 }
 
 // Default functions
@@ -70,6 +128,10 @@ fn default_gpui_version() -> String {
     "0.2.1".to_string()
 }
 
+fn default_registry_url() -> String {
+    crate::registry::DEFAULT_REGISTRY_URL.to_string()
+}
+
 fn default_radius() -> String {
     "px(4.0)".to_string()
 }
@@ -94,6 +156,16 @@ fn default_accent() -> String {
     "rgb(0xf0f9ff)".to_string()
 }
 
+impl Default for StyleConfig {
+    fn default() -> Self {
+        Self {
+            colors: ColorConfig::default(),
+            radius: default_radius(),
+            animation_duration_ms: None,
+        }
+    }
+}
+
 impl Default for ColorConfig {
     fn default() -> Self {
         Self {
@@ -102,6 +174,12 @@ impl Default for ColorConfig {
             destructive: default_destructive(),
             muted: default_muted(),
             accent: default_accent(),
+            success: None,
+            warning: None,
+            background: None,
+            foreground: None,
+            muted_foreground: None,
+            border: None,
         }
     }
 }
@@ -112,13 +190,20 @@ impl Config {
             component_path: default_component_path(),
             utils_path: default_utils_path(),
             gpui_version: default_gpui_version(),
+            registry_url: default_registry_url(),
             style: StyleConfig::default(),
             components: Vec::new(),
         }
     }
 
     pub fn load() -> Result<Self> {
-        let config_path = Self::get_config_path()?;
+        Self::load_from(&Self::current_dir()?)
+    }
+
+    /// Loads `gpui-ui.json` from the project at `dir`.
+    pub fn load_from(dir: &Path) -> Result<Self> {
+        let config_path = dir.join(CONFIG_FILE_NAME);
+        debug!(path = %config_path.display(), "loading config");
         let content = fs::read_to_string(&config_path)
             .context(format!("Failed to read config file at {:?}", config_path))?;
         let config: Config = serde_json::from_str(&content)
@@ -127,7 +212,13 @@ impl Config {
     }
 
     pub fn save(&self) -> Result<()> {
-        let config_path = Self::get_config_path()?;
+        self.save_to(&Self::current_dir()?)
+    }
+
+    /// Writes `gpui-ui.json` into the project at `dir`.
+    pub fn save_to(&self, dir: &Path) -> Result<()> {
+        let config_path = dir.join(CONFIG_FILE_NAME);
+        debug!(path = %config_path.display(), "saving config");
         let content = serde_json::to_string_pretty(self)
             .context("Failed to serialize config")?;
         fs::write(&config_path, content)
@@ -135,6 +226,22 @@ impl Config {
         Ok(())
     }
 
+    /// Deletes `gpui-ui.json` from the project at `dir`.
+    pub fn delete_from(dir: &Path) -> Result<()> {
+        let config_path = dir.join(CONFIG_FILE_NAME);
+        debug!(path = %config_path.display(), "deleting config");
+        fs::remove_file(&config_path)
+            .context(format!("Failed to delete config file {:?}", config_path))
+    }
+
+    /// The registry URL set in gpui-ui.json, or the default one outside an
+    /// initialized project.
+    pub fn registry_url() -> String {
+        Self::load()
+            .map(|config| config.registry_url)
+            .unwrap_or_else(|_| default_registry_url())
+    }
+
     pub fn exists() -> bool {
         Self::get_config_path()
             .map(|path| path.exists())
@@ -142,9 +249,11 @@ impl Config {
     }
 
     fn get_config_path() -> Result<PathBuf> {
-        let current_dir = std::env::current_dir()
-            .context("Failed to get current directory")?;
-        Ok(current_dir.join(CONFIG_FILE_NAME))
+        Ok(Self::current_dir()?.join(CONFIG_FILE_NAME))
+    }
+
+    fn current_dir() -> Result<PathBuf> {
+        std::env::current_dir().context("Failed to get current directory")
     }
 
     pub fn add_component(&mut self, name: String, version: String) {
@@ -153,8 +262,16 @@ impl Config {
             name,
             version,
             installed_at,
+            checksums: BTreeMap::new(),
         });
     }
+
+    /// Drops a component from the installed list. Returns whether it was listed.
+    pub fn remove_component(&mut self, name: &str) -> bool {
+        let before = self.components.len();
+        self.components.retain(|c| c.name != name);
+        self.components.len() != before
+    }
 }
 
 impl Default for Config {
@@ -162,3 +279,45 @@ impl Default for Config {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_keeps_theme_keys_init_doesnt_write() {
+        let dir = std::env::temp_dir().join(format!("gpui-ui-config-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let written = r##"{
+            "style": {
+                "colors": {
+                    "primary": "rgb(0x8b5cf6)",
+                    "success": "rgb(0x16a34a)",
+                    "warning": "#d97706",
+                    "background": "rgb(0xfafafa)",
+                    "foreground": "rgb(0x111827)",
+                    "muted_foreground": "rgb(0x6b7280)",
+                    "border": "rgb(0xe5e7eb)"
+                },
+                "radius": "px(6.0)",
+                "animation_duration_ms": 200
+            }
+        }"##;
+        fs::write(dir.join(CONFIG_FILE_NAME), written).unwrap();
+
+        let mut config = Config::load_from(&dir).unwrap();
+        config.add_component("button".to_string(), "0.1.0".to_string());
+        config.save_to(&dir).unwrap();
+
+        let saved: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(dir.join(CONFIG_FILE_NAME)).unwrap()).unwrap();
+        let written: serde_json::Value = serde_json::from_str(written).unwrap();
+        for (name, value) in written["style"]["colors"].as_object().unwrap() {
+            assert_eq!(&saved["style"]["colors"][name], value, "colors.{}", name);
+        }
+        assert_eq!(saved["style"]["animation_duration_ms"], 200);
+        assert_eq!(saved["style"]["radius"], "px(6.0)");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
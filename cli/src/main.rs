@@ -4,13 +4,19 @@ mod registry;
 mod utils;
 
 use anyhow::Result;
-use clap::{Parser, Subcommand};
+use clap::{ArgAction, Parser, Subcommand};
+use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "gpui-ui")]
 #[command(about = "A CLI tool for managing GPUI UI components", long_about = None)]
 #[command(version)]
 struct Cli {
+    /// Log what the command is doing: resolved dependencies, files considered,
+    /// and why any were skipped. Repeat (-vv) for more detail.
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -31,12 +37,30 @@ enum Commands {
         /// Overwrite existing files
         #[arg(short, long)]
         force: bool,
+
+        /// Copy component files from this directory (e.g. a fork's
+        /// components/src) instead of the bundled sources
+        #[arg(long, value_name = "DIR")]
+        from: Option<PathBuf>,
+
+        /// With --force, overwrite locally edited files without asking
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Copy the files as written instead of applying the colors and
+        /// radius from gpui-ui.json
+        #[arg(long)]
+        no_theme: bool,
     },
-    /// List all available components
+    /// List all available components (with -v, show details)
     List {
-        /// Show detailed component information
-        #[arg(short, long)]
-        verbose: bool,
+        /// Only show components installed in this project
+        #[arg(long, conflicts_with = "available")]
+        installed: bool,
+
+        /// Only show components not yet installed
+        #[arg(long)]
+        available: bool,
     },
     /// Update components to the latest version
     Update {
@@ -47,28 +71,147 @@ enum Commands {
     Info {
         /// Component name
         component: String,
+
+        /// Also print a usage snippet
+        #[arg(long)]
+        examples: bool,
+    },
+    /// Show the dependency tree of a component
+    Deps {
+        /// Component name
+        component: String,
+
+        /// Print a Graphviz DOT graph instead of a tree
+        #[arg(long)]
+        dot: bool,
+    },
+    /// Show the theme from gpui-ui.json with color swatches
+    Preview {
+        /// Keep running and show theme and component changes as files are saved
+        #[arg(long)]
+        watch: bool,
+    },
+    /// Restore the files and config changed by the last `remove` or `add --force`
+    Undo {
+        /// List the operations that can be undone instead of undoing one
+        #[arg(long)]
+        list: bool,
+    },
+    /// Show how an installed component's files differ from the registry
+    Diff {
+        /// Component name
+        component: String,
+
+        /// Compare against the files as written, for components added with
+        /// `add --no-theme`
+        #[arg(long)]
+        no_theme: bool,
+    },
+    /// Check installed components for local edits, available updates, and
+    /// colors that no longer follow the theme
+    Lint,
+    /// Stop tracking installed components, leaving their files as your own
+    Eject {
+        /// Delete gpui-ui.json instead of just clearing its component list
+        #[arg(long)]
+        delete_config: bool,
+
+        /// Skip confirmation prompts
+        #[arg(short, long)]
+        yes: bool,
+    },
+    /// Remove a component from your project
+    Remove {
+        /// Component name(s) to remove
+        components: Vec<String>,
+
+        /// Remove even if installed components depend on it
+        #[arg(short, long)]
+        force: bool,
+
+        /// Update gpui-ui.json but leave the component files on disk
+        #[arg(long)]
+        keep_files: bool,
     },
 }
 
+/// Sends logs to stderr, keeping stdout for the command's own output. Quiet
+/// (warnings only) unless `-v` is given.
+fn init_logging(verbosity: u8) {
+    let level = match verbosity {
+        0 => tracing::Level::WARN,
+        1 => tracing::Level::DEBUG,
+        _ => tracing::Level::TRACE,
+    };
+
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_target(false)
+        .without_time()
+        .with_writer(std::io::stderr)
+        .init();
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
+    init_logging(cli.verbose);
 
     match cli.command {
         Commands::Init { yes } => {
             commands::init::run(yes).await?;
         }
-        Commands::Add { components, force } => {
-            commands::add::run(components, force).await?;
+        Commands::Add {
+            components,
+            force,
+            from,
+            yes,
+            no_theme,
+        } => {
+            commands::add::run(components, force, from, yes, no_theme).await?;
         }
-        Commands::List { verbose } => {
-            commands::list::run(verbose).await?;
+        Commands::List {
+            installed,
+            available,
+        } => {
+            commands::list::run(cli.verbose > 0, installed, available).await?;
         }
         Commands::Update { components } => {
             commands::update::run(components).await?;
         }
-        Commands::Info { component } => {
-            commands::info::run(component).await?;
+        Commands::Info {
+            component,
+            examples,
+        } => {
+            commands::info::run(component, examples).await?;
+        }
+        Commands::Deps { component, dot } => {
+            commands::deps::run(component, dot).await?;
+        }
+        Commands::Preview { watch } => {
+            commands::preview::run(watch).await?;
+        }
+        Commands::Undo { list } => {
+            commands::undo::run(list).await?;
+        }
+        Commands::Diff {
+            component,
+            no_theme,
+        } => {
+            commands::diff::run(component, no_theme).await?;
+        }
+        Commands::Lint => {
+            commands::lint::run().await?;
+        }
+        Commands::Eject { delete_config, yes } => {
+            commands::eject::run(delete_config, yes).await?;
+        }
+        Commands::Remove {
+            components,
+            force,
+            keep_files,
+        } => {
+            commands::remove::run(components, force, keep_files).await?;
         }
     }
 
@@ -1,6 +1,15 @@
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
+use std::path::{Component, Path};
+use tracing::trace;
+
+use crate::config::Config;
+
+mod remote;
+
+pub use remote::{fetch_sources, DEFAULT_REGISTRY_URL};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ComponentInfo {
@@ -9,10 +18,113 @@ pub struct ComponentInfo {
     pub version: String,
     pub files: Vec<String>,
     pub dependencies: Vec<String>,
+    /// Crates the component's files use beyond `gpui`, as Cargo.toml
+    /// dependency lines. `add` lists them for the user to add.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub crates: Vec<String>,
+    /// A short usage snippet shown by `info --examples`.
+    #[serde(default)]
+    pub example: Option<String>,
+}
+
+/// Support modules installed as dependencies rather than picked directly.
+const UTILITY_COMPONENTS: &[&str] = &[
+    "traits",
+    "prelude",
+    "announce",
+    "focus_visible",
+    "overlay",
+    "truncate",
+    "accessibility",
+    "breakpoint",
+    "keybindings",
+];
+
+/// A component and what it depends on, as resolved by
+/// `Registry::dependency_tree`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DependencyNode {
+    pub name: String,
+    pub dependencies: Vec<DependencyNode>,
+}
+
+/// How much an `Issue` matters. Errors make the registry unusable; warnings
+/// flag a project that has drifted from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// An inconsistency found by `Registry::validate` or `Registry::check_project`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    pub severity: Severity,
+    pub component: String,
+    pub message: String,
+}
+
+impl Issue {
+    fn error(component: &str, message: String) -> Self {
+        Self {
+            severity: Severity::Error,
+            component: component.to_string(),
+            message,
+        }
+    }
+
+    fn warning(component: &str, message: String) -> Self {
+        Self {
+            severity: Severity::Warning,
+            component: component.to_string(),
+            message,
+        }
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.component, self.message)
+    }
+}
+
+/// Parses a dotted version like `0.1.0` for ordering.
+pub(crate) fn parse_version(version: &str) -> Option<Vec<u64>> {
+    version
+        .trim()
+        .trim_start_matches('v')
+        .split('.')
+        .map(|part| part.parse().ok())
+        .collect()
+}
+
+/// Fails unless `file` is a plain relative path like `ui/button.rs`. File
+/// names come from the registry, which may be fetched, and are joined onto
+/// local directories, so `..`, absolute paths, and prefixes are refused.
+pub fn check_file_path(file: &str) -> Result<()> {
+    let path = Path::new(file);
+    let plain = path.components().next().is_some()
+        && path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+    if !plain {
+        bail!("'{}' is not a plain relative path", file);
+    }
+    Ok(())
+}
+
+/// Where a `Registry` came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Origin {
+    /// The components compiled into the CLI.
+    Bundled,
+    /// A `registry.json` fetched from `url`, now or by an earlier command.
+    Remote { url: String },
 }
 
 pub struct Registry {
     components: HashMap<String, ComponentInfo>,
+    origin: Origin,
 }
 
 impl Registry {
@@ -27,7 +139,22 @@ impl Registry {
                 description: "A customizable button component with multiple variants".to_string(),
                 version: "0.1.0".to_string(),
                 files: vec!["button.rs".to_string()],
-                dependencies: vec!["traits".to_string()],
+                dependencies: vec![
+                    "prelude".to_string(),
+                    "traits".to_string(),
+                    "tooltip".to_string(),
+                    "focus_visible".to_string(),
+                    "truncate".to_string(),
+                    "accessibility".to_string(),
+                ],
+                crates: Vec::new(),
+                example: Some(
+                    r#"Button::new("save", "Save")
+    .variant(ButtonVariant::Default)
+    .size(ButtonSize::Medium)
+    .on_click(cx.listener(|this, _, _, cx| this.save(cx)))"#
+                        .to_string(),
+                ),
             },
         );
 
@@ -39,7 +166,25 @@ impl Registry {
                 description: "Text input with validation support".to_string(),
                 version: "0.1.0".to_string(),
                 files: vec!["input.rs".to_string()],
-                dependencies: vec!["traits".to_string()],
+                dependencies: vec![
+                    "prelude".to_string(),
+                    "traits".to_string(),
+                    "tooltip".to_string(),
+                    "focus_visible".to_string(),
+                    "accessibility".to_string(),
+                ],
+                crates: vec![
+                    r#"unicode-segmentation = "1.12""#.to_string(),
+                ],
+                example: Some(
+                    r#"let email = cx.new(|cx| {
+    Input::new("email", cx)
+        .label("Email")
+        .placeholder("you@example.com")
+        .size(InputSize::Medium)
+});"#
+                        .to_string(),
+                ),
             },
         );
 
@@ -51,7 +196,16 @@ impl Registry {
                 description: "Card container with header, content, and footer".to_string(),
                 version: "0.1.0".to_string(),
                 files: vec!["card.rs".to_string()],
-                dependencies: vec![],
+                dependencies: vec!["prelude".to_string(), "collapsible".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"Card::new()
+    .variant(CardVariant::Elevated)
+    .header(CardHeader::new().title("Profile").description("Your public details"))
+    .content(CardContent::new().child(div().child("Card body")))
+    .footer(CardFooter::new().child(Button::new("edit", "Edit")))"#
+                        .to_string(),
+                ),
             },
         );
 
@@ -63,7 +217,18 @@ impl Registry {
                 description: "Modal dialog with overlay".to_string(),
                 version: "0.1.0".to_string(),
                 files: vec!["dialog.rs".to_string()],
-                dependencies: vec![],
+                dependencies: vec!["prelude".to_string(), "overlay".to_string(), "keybindings".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"Dialog::new()
+    .id("confirm-delete")
+    .size(DialogSize::Small)
+    .open(self.confirm_open)
+    .on_dismiss(cx.listener(|this, _, _, cx| this.close_confirm(cx)))
+    .header(DialogHeader::new().title("Delete project?"))
+    .footer(DialogFooter::new().child(Button::new("delete", "Delete")))"#
+                        .to_string(),
+                ),
             },
         );
 
@@ -75,7 +240,23 @@ impl Registry {
                 description: "Checkbox input component".to_string(),
                 version: "0.1.0".to_string(),
                 files: vec!["checkbox.rs".to_string()],
-                dependencies: vec!["traits".to_string()],
+                dependencies: vec![
+                    "prelude".to_string(),
+                    "traits".to_string(),
+                    "tooltip".to_string(),
+                    "focus_visible".to_string(),
+                    "accessibility".to_string(),
+                ],
+                crates: Vec::new(),
+                example: Some(
+                    r#"Checkbox::checked("terms", self.accepted)
+    .label("I accept the terms")
+    .on_click(cx.listener(|this, state: &ToggleState, _, cx| {
+        this.accepted = state.selected();
+        cx.notify();
+    }))"#
+                        .to_string(),
+                ),
             },
         );
 
@@ -87,7 +268,703 @@ impl Registry {
                 description: "Badge component for labels and tags".to_string(),
                 version: "0.1.0".to_string(),
                 files: vec!["badge.rs".to_string()],
+                dependencies: vec!["prelude".to_string(), "truncate".to_string(), "accessibility".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"Badge::new("New")
+    .variant(BadgeVariant::Primary)
+    .size(BadgeSize::Small)"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Toggle button component
+        components.insert(
+            "toggle_button".to_string(),
+            ComponentInfo {
+                name: "toggle_button".to_string(),
+                description: "Button that reflects a pressed/selected state".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["toggle_button.rs".to_string()],
+                dependencies: vec![
+                    "prelude".to_string(),
+                    "button".to_string(),
+                    "traits".to_string(),
+                    "accessibility".to_string(),
+                ],
+                crates: Vec::new(),
+                example: Some(
+                    r#"ToggleButton::new("bold", "B")
+    .toggle_state(ToggleState::from(self.bold))
+    .on_click(cx.listener(|this, state: &ToggleState, _, cx| {
+        this.bold = state.selected();
+        cx.notify();
+    }))"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Rating component
+        components.insert(
+            "rating".to_string(),
+            ComponentInfo {
+                name: "rating".to_string(),
+                description: "Star rating with hover preview and half-star support".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["rating.rs".to_string()],
+                dependencies: vec!["prelude".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"Rating::new("review", self.rating)
+    .max(5)
+    .allow_half(true)
+    .on_change(cx.listener(|this, value: &f32, _, cx| {
+        this.rating = *value;
+        cx.notify();
+    }))"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Kbd component
+        components.insert(
+            "kbd".to_string(),
+            ComponentInfo {
+                name: "kbd".to_string(),
+                description: "Keyboard shortcut display rendered as key caps".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["kbd.rs".to_string()],
+                dependencies: vec!["prelude".to_string()],
+                crates: Vec::new(),
+                example: Some(r#"Kbd::new("Cmd+Shift+P")"#.to_string()),
+            },
+        );
+
+        // Announce (utility)
+        components.insert(
+            "announce".to_string(),
+            ComponentInfo {
+                name: "announce".to_string(),
+                description: "Live-region announcements for screen readers".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["announce.rs".to_string()],
+                dependencies: vec!["prelude".to_string()],
+                crates: Vec::new(),
+                example: Some(r#"announce("Settings saved", Politeness::Polite, cx);"#.to_string()),
+            },
+        );
+
+        // Focus-visible tracking (utility)
+        components.insert(
+            "focus_visible".to_string(),
+            ComponentInfo {
+                name: "focus_visible".to_string(),
+                description: "Keyboard-only focus ring tracking".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["focus_visible.rs".to_string()],
+                dependencies: vec!["prelude".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"Button::new("save", "Save").track_focus(&self.save_focus)"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Text truncation (utility)
+        components.insert(
+            "truncate".to_string(),
+            ComponentInfo {
+                name: "truncate".to_string(),
+                description: "Truncates text with an ellipsis at a measured width".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["truncate.rs".to_string()],
+                dependencies: vec!["prelude".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"let label = truncate_in_window_font(file_name, px(160.0), px(14.0), window);"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Accessible names and roles (utility)
+        components.insert(
+            "accessibility".to_string(),
+            ComponentInfo {
+                name: "accessibility".to_string(),
+                description: "Accessible names and roles for interactive components".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["accessibility.rs".to_string()],
+                dependencies: vec!["prelude".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"Button::new("close", "✕").accessible_name("Close dialog")"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Window width buckets (utility)
+        components.insert(
+            "breakpoint".to_string(),
+            ComponentInfo {
+                name: "breakpoint".to_string(),
+                description: "Window width buckets for responsive layouts".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["breakpoint.rs".to_string()],
                 dependencies: vec![],
+                crates: Vec::new(),
+                example: Some(
+                    r#"let columns = match Breakpoint::current(window) {
+    Breakpoint::Sm => 1,
+    Breakpoint::Md => 2,
+    Breakpoint::Lg => 3,
+};"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Keyboard shortcuts (utility)
+        components.insert(
+            "keybindings".to_string(),
+            ComponentInfo {
+                name: "keybindings".to_string(),
+                description: "Shared, rebindable keyboard shortcuts".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["keybindings.rs".to_string()],
+                dependencies: vec!["prelude".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"keybindings::rebind("ctrl-w", Dismiss, Some(keybindings::DIALOG_CONTEXT), cx);"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Tooltip component
+        components.insert(
+            "tooltip".to_string(),
+            ComponentInfo {
+                name: "tooltip".to_string(),
+                description: "Floating hover label".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["tooltip.rs".to_string()],
+                dependencies: vec!["prelude".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"div()
+    .id("save")
+    .tooltip(Tooltip::new("Save").meta("Cmd+S").builder())"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Input group component
+        components.insert(
+            "input_group".to_string(),
+            ComponentInfo {
+                name: "input_group".to_string(),
+                description: "Input combined with attached buttons".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["input_group.rs".to_string()],
+                dependencies: vec!["prelude".to_string(), "input".to_string(), "button".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"InputGroup::new(self.search.clone())
+    .trailing(Button::new("go", "Go").on_click(cx.listener(|this, _, _, cx| this.search(cx))))"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Resizable component
+        components.insert(
+            "resizable".to_string(),
+            ComponentInfo {
+                name: "resizable".to_string(),
+                description: "Panels separated by draggable resize handles".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["resizable.rs".to_string()],
+                dependencies: vec!["prelude".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"ResizablePanelGroup::new("workspace")
+    .orientation(ResizeOrientation::Horizontal)
+    .sizes(self.sizes.clone())
+    .panel(ResizablePanel::new(sidebar).min_size(0.15))
+    .panel(ResizablePanel::new(editor))"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // OTP input component
+        components.insert(
+            "otp_input".to_string(),
+            ComponentInfo {
+                name: "otp_input".to_string(),
+                description: "Segmented one-time-code / PIN entry".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["otp_input.rs".to_string()],
+                dependencies: vec!["prelude".to_string(), "input".to_string(), "keybindings".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"let code = cx.new(|cx| OtpInput::new("code", 6, cx).numeric(true));"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Aspect ratio component
+        components.insert(
+            "aspect_ratio".to_string(),
+            ComponentInfo {
+                name: "aspect_ratio".to_string(),
+                description: "Fixed aspect-ratio container for media and embeds".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["aspect_ratio.rs".to_string()],
+                dependencies: vec!["prelude".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"AspectRatio::new(16.0 / 9.0).child(img("assets/cover.png").size_full())"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Collapsible component
+        components.insert(
+            "collapsible".to_string(),
+            ComponentInfo {
+                name: "collapsible".to_string(),
+                description: "Controlled disclosure with a trigger and animated content".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["collapsible.rs".to_string()],
+                dependencies: vec!["prelude".to_string(), "traits".to_string(), "tooltip".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"Collapsible::new("advanced", self.advanced_open)
+    .trigger(div().child("Advanced settings"))
+    .child(advanced_settings)
+    .on_toggle(cx.listener(|this, open: &bool, _, cx| {
+        this.advanced_open = *open;
+        cx.notify();
+    }))"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Separator component
+        components.insert(
+            "separator".to_string(),
+            ComponentInfo {
+                name: "separator".to_string(),
+                description: "Horizontal or vertical divider line".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["separator.rs".to_string()],
+                dependencies: vec!["prelude".to_string()],
+                crates: Vec::new(),
+                example: Some(r#"Separator::horizontal()"#.to_string()),
+            },
+        );
+
+        // Toolbar component
+        components.insert(
+            "toolbar".to_string(),
+            ComponentInfo {
+                name: "toolbar".to_string(),
+                description: "Grouped toolbar with alignment regions and an overflow menu".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["toolbar.rs".to_string()],
+                dependencies: vec![
+                    "prelude".to_string(),
+                    "button".to_string(),
+                    "overlay".to_string(),
+                    "separator".to_string(),
+                    "breakpoint".to_string(),
+                ],
+                crates: Vec::new(),
+                example: Some(
+                    r#"Toolbar::new("editor-toolbar")
+    .group(ToolbarGroup::new().child(Button::new("bold", "B").variant(ButtonVariant::Ghost)))
+    .group_in(ToolbarAlign::End, ToolbarGroup::new().child(Button::new("share", "Share")))"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // List component
+        components.insert(
+            "list".to_string(),
+            ComponentInfo {
+                name: "list".to_string(),
+                description: "Virtualized list that only renders visible rows".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["list.rs".to_string()],
+                dependencies: vec!["prelude".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"List::new(
+    "todos",
+    self.todos.len(),
+    cx.processor(|this, index: usize, _, cx| this.render_row(index, cx).into_any_element()),
+)
+.height(px(240.0))"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Overlay layer (utility)
+        components.insert(
+            "overlay".to_string(),
+            ComponentInfo {
+                name: "overlay".to_string(),
+                description: "Shared layer for dialogs, popovers, and menus".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["overlay.rs".to_string()],
+                dependencies: vec!["prelude".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"Overlay::popover("menu")
+    .on_outside_click(cx.listener(|this, _, _, cx| this.close_menu(cx)))
+    .child(menu)"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Banner component
+        components.insert(
+            "banner".to_string(),
+            ComponentInfo {
+                name: "banner".to_string(),
+                description: "Full-width announcement bar with an action and dismiss button".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["banner.rs".to_string()],
+                dependencies: vec!["prelude".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"Banner::new("offline", "You're offline.")
+    .variant(BannerVariant::Warning)
+    .open(self.offline)"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Copy button component
+        components.insert(
+            "copy_button".to_string(),
+            ComponentInfo {
+                name: "copy_button".to_string(),
+                description: "Button that copies a value to the clipboard with a confirmation".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["copy_button.rs".to_string()],
+                dependencies: vec!["prelude".to_string(), "button".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"CopyButton::new("copy-token", self.token.clone()).label("Copy")"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // File input component
+        components.insert(
+            "file_input".to_string(),
+            ComponentInfo {
+                name: "file_input".to_string(),
+                description: "Drop zone and file picker with accepted extensions".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["file_input.rs".to_string()],
+                dependencies: vec!["prelude".to_string(), "badge".to_string(), "focus_visible".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"FileInput::new("attachments")
+    .accept(["png", "jpg"])
+    .multiple(true)
+    .files(self.attachments.clone())
+    .on_select(cx.listener(|this, paths: &Vec<PathBuf>, _, cx| {
+        this.attachments.extend(paths.iter().cloned());
+        cx.notify();
+    }))"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Tabs component
+        components.insert(
+            "tabs".to_string(),
+            ComponentInfo {
+                name: "tabs".to_string(),
+                description: "Tabs with keyboard navigation and scrolling tab list".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["tabs.rs".to_string()],
+                dependencies: vec!["prelude".to_string(), "focus_visible".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"Tabs::new("settings")
+    .selected(self.tab.clone())
+    .tab(Tab::new("general", "General").child(general))
+    .tab(Tab::new("account", "Account").child(account))
+    .on_change(cx.listener(|this, id: &SharedString, _, cx| {
+        this.tab = id.clone();
+        cx.notify();
+    }))"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Carousel component
+        components.insert(
+            "carousel".to_string(),
+            ComponentInfo {
+                name: "carousel".to_string(),
+                description: "Slide carousel with navigation, dots, and auto-advance".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["carousel.rs".to_string()],
+                dependencies: vec!["prelude".to_string(), "button".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"Carousel::new("tour")
+    .slides(slides)
+    .active(self.slide)
+    .r#loop(true)
+    .on_change(cx.listener(|this, index: &usize, _, cx| {
+        this.slide = *index;
+        cx.notify();
+    }))"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Sidebar component
+        components.insert(
+            "sidebar".to_string(),
+            ComponentInfo {
+                name: "sidebar".to_string(),
+                description: "Collapsible navigation rail with grouped sections".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["sidebar.rs".to_string()],
+                dependencies: vec!["prelude".to_string(), "tooltip".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"Sidebar::new("nav")
+    .collapsed(self.collapsed)
+    .item(SidebarItem::new("inbox", "✉", "Inbox").selected(true))
+    .item(SidebarItem::new("settings", "⚙", "Settings"))"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Date range picker component
+        components.insert(
+            "date_range_picker".to_string(),
+            ComponentInfo {
+                name: "date_range_picker".to_string(),
+                description: "Calendar popover for picking a start and end date".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["date_range_picker.rs".to_string()],
+                dependencies: vec!["prelude".to_string(), "button".to_string(), "overlay".to_string()],
+                crates: vec![
+                    r#"chrono = "0.4""#.to_string(),
+                ],
+                example: Some(
+                    r#"DateRangePicker::new("stay")
+    .value(self.stay)
+    .on_change(cx.listener(|this, range: &(NaiveDate, NaiveDate), _, cx| {
+        this.stay = Some(*range);
+        cx.notify();
+    }))"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Chip component
+        components.insert(
+            "chip".to_string(),
+            ComponentInfo {
+                name: "chip".to_string(),
+                description: "Toggleable filter pill with icon and count".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["chip.rs".to_string()],
+                dependencies: vec!["prelude".to_string(), "accessibility".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"Chip::new("filter-open", "Open")
+    .count(12)
+    .selected(self.show_open)
+    .on_click(cx.listener(|this, selected: &bool, _, cx| {
+        this.show_open = *selected;
+        cx.notify();
+    }))"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Image component
+        components.insert(
+            "image".to_string(),
+            ComponentInfo {
+                name: "image".to_string(),
+                description: "Image with a loading placeholder, error fallback, and object fit".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["image.rs".to_string()],
+                dependencies: vec!["prelude".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"Image::new("avatar", "https://example.com/avatar.png")
+    .size(px(48.0))
+    .rounded(px(9999.0))
+    .fallback(div().child("AL"))"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Alert component
+        components.insert(
+            "alert".to_string(),
+            ComponentInfo {
+                name: "alert".to_string(),
+                description: "Inline alert with keyboard dismissal and optional auto-dismiss".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["alert.rs".to_string()],
+                dependencies: vec!["prelude".to_string(), "focus_visible".to_string(), "announce".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"Alert::new("saved", "Changes saved")
+    .variant(AlertVariant::Success)
+    .auto_dismiss(Duration::from_secs(5))
+    .on_dismiss(cx.listener(|this, _, _, cx| this.hide_saved(cx)))"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Toggle group component
+        components.insert(
+            "toggle_group".to_string(),
+            ComponentInfo {
+                name: "toggle_group".to_string(),
+                description: "Connected row of independent multi-select toggles".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["toggle_group.rs".to_string()],
+                dependencies: vec!["prelude".to_string(), "toggle_button".to_string(), "focus_visible".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"ToggleGroup::new("formatting")
+    .item(ToggleGroupItem::new("B").selected(self.bold))
+    .item(ToggleGroupItem::new("I").selected(self.italic))
+    .on_change(cx.listener(|this, selected: &[bool], _, cx| {
+        (this.bold, this.italic) = (selected[0], selected[1]);
+        cx.notify();
+    }))"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Tag input component
+        components.insert(
+            "tag_input".to_string(),
+            ComponentInfo {
+                name: "tag_input".to_string(),
+                description: "Tag entry with fuzzy-matched suggestions".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["tag_input.rs".to_string()],
+                dependencies: vec!["prelude".to_string(), "overlay".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"let tags = cx.new(|cx| {
+    TagInput::new("labels", cx)
+        .suggestions(["bug", "feature", "docs", "performance"])
+        .on_change(|tags, _window, _cx| println!("{tags:?}"))
+});"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Link component
+        components.insert(
+            "link".to_string(),
+            ComponentInfo {
+                name: "link".to_string(),
+                description: "Text link that opens a URL or navigates in the app".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["link.rs".to_string()],
+                dependencies: vec!["prelude".to_string(), "accessibility".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"Link::new("docs", "Read the docs")
+    .href("https://example.com/docs")"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Card grid component
+        components.insert(
+            "card_grid".to_string(),
+            ComponentInfo {
+                name: "card_grid".to_string(),
+                description: "Responsive column grid with optional masonry layout".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["card_grid.rs".to_string()],
+                dependencies: vec!["prelude".to_string(), "breakpoint".to_string()],
+                crates: Vec::new(),
+                example: Some(
+                    r#"CardGrid::new()
+    .columns(1, 2, 4)
+    .masonry(true)
+    .children(self.stats.iter().map(|stat| Card::new().child(stat.label.clone())))"#
+                        .to_string(),
+                ),
+            },
+        );
+
+        // Select component
+        components.insert(
+            "select".to_string(),
+            ComponentInfo {
+                name: "select".to_string(),
+                description: "Dropdown for picking one value from a list of options".to_string(),
+                version: "0.1.0".to_string(),
+                files: vec!["select.rs".to_string()],
+                dependencies: vec![
+                    "prelude".to_string(),
+                    "traits".to_string(),
+                    "overlay".to_string(),
+                    "focus_visible".to_string(),
+                    "accessibility".to_string(),
+                ],
+                crates: Vec::new(),
+                example: Some(
+                    r#"Select::new("fruit")
+    .option("apple", "Apple")
+    .option("banana", "Banana")
+    .value(self.fruit.clone())
+    .on_change(cx.listener(|this, value: &SharedString, _, cx| {
+        this.fruit = Some(value.clone());
+        cx.notify();
+    }))"#
+                        .to_string(),
+                ),
             },
         );
 
@@ -100,6 +977,8 @@ impl Registry {
                 version: "0.1.0".to_string(),
                 files: vec!["traits.rs".to_string()],
                 dependencies: vec![],
+                crates: Vec::new(),
+                example: None,
             },
         );
 
@@ -110,12 +989,30 @@ impl Registry {
                 name: "prelude".to_string(),
                 description: "Common imports and utilities".to_string(),
                 version: "0.1.0".to_string(),
-                files: vec!["prelude.rs".to_string()],
-                dependencies: vec![],
+                files: vec![
+                    "prelude.rs".to_string(),
+                    "colors.rs".to_string(),
+                    "size.rs".to_string(),
+                    "theme.rs".to_string(),
+                ],
+                // Re-exported from prelude.rs
+                dependencies: vec!["breakpoint".to_string(), "traits".to_string()],
+                crates: vec![
+                    r#"serde = { version = "1.0", features = ["derive"] }"#.to_string(),
+                    r#"serde_json = "1.0""#.to_string(),
+                ],
+                example: None,
             },
         );
 
-        Self { components }
+        Self {
+            components,
+            origin: Origin::Bundled,
+        }
+    }
+
+    pub fn origin(&self) -> &Origin {
+        &self.origin
     }
 
     pub fn get_component(&self, name: &str) -> Result<&ComponentInfo> {
@@ -128,7 +1025,7 @@ impl Registry {
         let mut components: Vec<&ComponentInfo> = self
             .components
             .values()
-            .filter(|c| c.name != "traits" && c.name != "prelude")
+            .filter(|c| !UTILITY_COMPONENTS.contains(&c.name.as_str()))
             .collect();
         components.sort_by(|a, b| a.name.cmp(&b.name));
         components
@@ -163,6 +1060,188 @@ impl Registry {
         Ok(resolved)
     }
 
+    /// Resolves `component_name` like `resolve_dependencies`, but keeps the
+    /// hierarchy. Shared dependencies appear under every component that needs
+    /// them.
+    pub fn dependency_tree(&self, component_name: &str) -> Result<DependencyNode> {
+        self.dependency_subtree(component_name, &mut Vec::new())
+    }
+
+    fn dependency_subtree(&self, name: &str, path: &mut Vec<String>) -> Result<DependencyNode> {
+        let component = self.get_component(name)?;
+        if path.iter().any(|ancestor| ancestor == name) {
+            anyhow::bail!("Dependency cycle: {} -> {}", path.join(" -> "), name);
+        }
+
+        path.push(name.to_string());
+        let dependencies = component
+            .dependencies
+            .iter()
+            .map(|dep| self.dependency_subtree(dep, path))
+            .collect::<Result<Vec<_>>>()?;
+        path.pop();
+
+        Ok(DependencyNode {
+            name: name.to_string(),
+            dependencies,
+        })
+    }
+
+    /// Returns the installed components that need `component_name`, directly
+    /// or through another dependency, sorted by name. Removing a component
+    /// is only safe when this is empty.
+    pub fn dependents(&self, component_name: &str, installed: &[String]) -> Vec<String> {
+        let mut dependents: Vec<String> = installed
+            .iter()
+            .filter(|name| name.as_str() != component_name)
+            .filter(|name| {
+                self.resolve_dependencies(name)
+                    .map(|deps| deps.iter().any(|dep| dep == component_name))
+                    .unwrap_or(false)
+            })
+            .cloned()
+            .collect();
+        dependents.sort();
+        dependents.dedup();
+        dependents
+    }
+
+    /// Resolves several components at once, returning each required component
+    /// exactly once with dependencies ahead of the components that need them.
+    pub fn resolve_all(&self, component_names: &[String]) -> Result<Vec<String>> {
+        let mut resolved: Vec<String> = Vec::new();
+
+        for name in component_names {
+            let deps = self.resolve_dependencies(name)?;
+            trace!(component = %name, dependencies = ?deps, "resolved component");
+            for dep in deps {
+                if !resolved.contains(&dep) {
+                    resolved.push(dep);
+                }
+            }
+        }
+
+        Ok(resolved)
+    }
+
+    /// Checks the registry on its own: every dependency must exist, there
+    /// must be no dependency cycles, every component must list a file, and
+    /// every file must be a plain relative path. Issues come back sorted by
+    /// component.
+    pub fn validate(&self) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        for (key, component) in &self.components {
+            if key != &component.name {
+                issues.push(Issue::error(
+                    key,
+                    format!("registered under '{}' but named '{}'", key, component.name),
+                ));
+            }
+            if component.files.is_empty() {
+                issues.push(Issue::warning(key, "lists no files".to_string()));
+            }
+            for file in &component.files {
+                if check_file_path(file).is_err() {
+                    issues.push(Issue::error(
+                        key,
+                        format!("lists file '{}', which is not a plain relative path", file),
+                    ));
+                }
+            }
+
+            let unknown: Vec<&String> = component
+                .dependencies
+                .iter()
+                .filter(|dep| !self.components.contains_key(dep.as_str()))
+                .collect();
+            for dep in &unknown {
+                issues.push(Issue::error(
+                    key,
+                    format!("depends on '{}', which is not in the registry", dep),
+                ));
+            }
+            // Cycles are only meaningful once every dependency resolves
+            if unknown.is_empty() {
+                if let Err(err) = self.dependency_tree(key) {
+                    issues.push(Issue::error(key, err.to_string()));
+                }
+            }
+        }
+        issues.sort_by(|a, b| a.component.cmp(&b.component).then(a.message.cmp(&b.message)));
+        issues
+    }
+
+    /// Checks a project against the registry: installed components the
+    /// registry no longer has, installs newer than the registry's version,
+    /// installed files the registry stopped listing, and, given a source
+    /// directory, files of installed components and their dependencies
+    /// missing from it. Includes `validate`'s issues.
+    pub fn check_project(&self, config: &Config, source_dir: Option<&Path>) -> Vec<Issue> {
+        let mut issues = self.validate();
+
+        for installed in &config.components {
+            let Ok(component) = self.get_component(&installed.name) else {
+                issues.push(Issue::warning(
+                    &installed.name,
+                    "installed but no longer in the registry; it won't be updated".to_string(),
+                ));
+                continue;
+            };
+
+            if let (Some(ours), Some(theirs)) = (
+                parse_version(&installed.version),
+                parse_version(&component.version),
+            ) {
+                if ours > theirs {
+                    issues.push(Issue::warning(
+                        &installed.name,
+                        format!(
+                            "installed v{} is newer than the registry's v{}; was it added from another registry?",
+                            installed.version, component.version
+                        ),
+                    ));
+                }
+            }
+
+            // `add` fingerprints the files of a component's dependencies too
+            let files: Vec<&String> = self
+                .resolve_dependencies(&installed.name)
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|name| self.components.get(name))
+                .flat_map(|dependency| &dependency.files)
+                .collect();
+            for file in installed.checksums.keys() {
+                if !files.contains(&file) {
+                    issues.push(Issue::warning(
+                        &installed.name,
+                        format!("installed file {} is no longer part of the component", file),
+                    ));
+                }
+            }
+        }
+
+        if let Some(source_dir) = source_dir {
+            let installed: Vec<String> = config
+                .components
+                .iter()
+                .map(|c| c.name.clone())
+                .filter(|name| self.components.contains_key(name))
+                .collect();
+            for name in self.resolve_all(&installed).unwrap_or_default() {
+                for file in &self.components[&name].files {
+                    if !source_dir.join(file).is_file() {
+                        issues.push(Issue::warning(
+                            &name,
+                            format!("source file {} is missing from {}", file, source_dir.display()),
+                        ));
+                    }
+                }
+            }
+        }
+
+        issues
+    }
 }
 
 impl Default for Registry {
@@ -175,6 +1254,159 @@ impl Default for Registry {
 mod tests {
     use super::*;
 
+    fn component(name: &str, files: &[&str], dependencies: &[&str]) -> ComponentInfo {
+        ComponentInfo {
+            name: name.to_string(),
+            description: String::new(),
+            version: "0.1.0".to_string(),
+            files: files.iter().map(|f| f.to_string()).collect(),
+            dependencies: dependencies.iter().map(|d| d.to_string()).collect(),
+            crates: Vec::new(),
+            example: None,
+        }
+    }
+
+    fn registry_of(components: Vec<ComponentInfo>) -> Registry {
+        Registry {
+            components: components
+                .into_iter()
+                .map(|component| (component.name.clone(), component))
+                .collect(),
+            origin: Origin::Bundled,
+        }
+    }
+
+    #[test]
+    fn test_bundled_registry_is_valid() {
+        assert_eq!(Registry::new().validate(), Vec::new());
+    }
+
+    /// `registry.json` at the repository root is what `DEFAULT_REGISTRY_URL`
+    /// serves, so it has to match the bundled registry.
+    #[test]
+    fn test_published_registry_matches_bundled() {
+        let bundled = Registry::new();
+        let sorted: std::collections::BTreeMap<_, _> = bundled.components.iter().collect();
+        let expected = serde_json::to_string_pretty(&sorted).unwrap() + "\n";
+
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../registry.json");
+        if std::env::var_os("UPDATE_REGISTRY_JSON").is_some() {
+            std::fs::write(&path, &expected).unwrap();
+        }
+        let published = std::fs::read_to_string(&path).unwrap_or_default();
+        assert!(
+            published == expected,
+            "registry.json is out of date; run `UPDATE_REGISTRY_JSON=1 cargo test -p gpui-ui`"
+        );
+    }
+
+    #[test]
+    fn test_validate_reports_unknown_dependencies_and_cycles() {
+        let registry = registry_of(vec![
+            component("a", &["a.rs"], &["b"]),
+            component("b", &["b.rs"], &["a"]),
+            component("c", &[], &["missing"]),
+        ]);
+        let issues = registry.validate();
+
+        assert!(issues
+            .iter()
+            .any(|issue| issue.component == "a" && issue.message.contains("cycle")));
+        assert!(issues.iter().any(|issue| issue.component == "c"
+            && issue.severity == Severity::Error
+            && issue.message.contains("'missing'")));
+        assert!(issues
+            .iter()
+            .any(|issue| issue.component == "c" && issue.severity == Severity::Warning));
+    }
+
+    #[test]
+    fn test_check_file_path() {
+        assert!(check_file_path("button.rs").is_ok());
+        assert!(check_file_path("ui/button.rs").is_ok());
+        for file in ["", "../main.rs", "ui/../../main.rs", "./button.rs", "/etc/passwd"] {
+            assert!(check_file_path(file).is_err(), "{:?} was accepted", file);
+        }
+
+        let registry = registry_of(vec![component("a", &["../../.bashrc"], &[])]);
+        assert!(registry
+            .validate()
+            .iter()
+            .any(|issue| issue.severity == Severity::Error && issue.message.contains(".bashrc")));
+    }
+
+    #[test]
+    fn test_check_project_reports_orphans_and_newer_installs() {
+        let registry = registry_of(vec![component("a", &["a.rs"], &[])]);
+        let mut config = Config::new();
+        config.add_component("a".to_string(), "0.2.0".to_string());
+        config.add_component("gone".to_string(), "0.1.0".to_string());
+        config.components[0]
+            .checksums
+            .insert("old.rs".to_string(), String::new());
+
+        let issues = registry.check_project(&config, None);
+        let messages: Vec<String> = issues.iter().map(|issue| issue.to_string()).collect();
+        assert_eq!(issues.len(), 3, "{:?}", messages);
+        assert!(messages.iter().any(|m| m.starts_with("gone: installed but no longer")));
+        assert!(messages.iter().any(|m| m.contains("v0.2.0 is newer than the registry's v0.1.0")));
+        assert!(messages.iter().any(|m| m.contains("old.rs is no longer part")));
+    }
+
+    #[test]
+    fn test_check_project_accepts_dependency_checksums() {
+        let registry = registry_of(vec![
+            component("a", &["a.rs"], &["b"]),
+            component("b", &["b.rs"], &[]),
+        ]);
+        let mut config = Config::new();
+        config.add_component("a".to_string(), "0.1.0".to_string());
+        for file in ["a.rs", "b.rs"] {
+            config.components[0]
+                .checksums
+                .insert(file.to_string(), String::new());
+        }
+
+        assert_eq!(registry.check_project(&config, None), vec![]);
+    }
+
+    /// The modules `source` names as `crate::<module>`.
+    fn crate_modules(source: &str) -> Vec<&str> {
+        source
+            .match_indices("crate::")
+            .map(|(start, prefix)| {
+                let rest = &source[start + prefix.len()..];
+                let end = rest
+                    .find(|ch: char| !ch.is_alphanumeric() && ch != '_')
+                    .unwrap_or(rest.len());
+                &rest[..end]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_dependencies_cover_crate_imports() {
+        let registry = Registry::new();
+        let source_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../components/src");
+        for component in registry.components.values() {
+            let mut installed = Vec::new();
+            for name in registry.resolve_dependencies(&component.name).unwrap() {
+                installed.extend(registry.components[&name].files.iter().cloned());
+            }
+            for file in &component.files {
+                let source = std::fs::read_to_string(source_dir.join(file)).unwrap();
+                for module in crate_modules(&source) {
+                    assert!(
+                        installed.contains(&format!("{}.rs", module)),
+                        "{} uses crate::{}, which its dependencies don't install",
+                        file,
+                        module
+                    );
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_resolve_dependencies() {
         let registry = Registry::new();
@@ -187,6 +1419,42 @@ mod tests {
         assert!(traits_idx < button_idx);
     }
 
+    #[test]
+    fn test_resolve_all_deduplicates_shared_dependencies() {
+        let registry = Registry::new();
+        let names = vec!["button".to_string(), "checkbox".to_string()];
+        let resolved = registry.resolve_all(&names).unwrap();
+        assert_eq!(resolved.iter().filter(|d| *d == "traits").count(), 1);
+        assert_eq!(resolved.iter().filter(|d| *d == "tooltip").count(), 1);
+        let traits_idx = resolved.iter().position(|d| d == "traits").unwrap();
+        let checkbox_idx = resolved.iter().position(|d| d == "checkbox").unwrap();
+        assert!(traits_idx < checkbox_idx);
+    }
+
+    #[test]
+    fn test_dependents_direct_and_transitive() {
+        let registry = Registry::new();
+        let installed = vec!["button".to_string(), "input_group".to_string()];
+
+        // input_group needs button directly
+        assert_eq!(registry.dependents("button", &installed), vec!["input_group"]);
+        // and traits through button and input
+        assert_eq!(
+            registry.dependents("traits", &installed),
+            vec!["button", "input_group"]
+        );
+    }
+
+    #[test]
+    fn test_dependents_ignores_uninstalled_and_self() {
+        let registry = Registry::new();
+        let installed = vec!["button".to_string()];
+
+        assert!(registry.dependents("button", &installed).is_empty());
+        assert!(registry.dependents("input", &installed).is_empty());
+        assert!(registry.dependents("traits", &[]).is_empty());
+    }
+
     #[test]
     fn test_list_components() {
         let registry = Registry::new();
@@ -195,5 +1463,23 @@ mod tests {
         assert!(!components.iter().any(|c| c.name == "traits"));
         assert!(!components.iter().any(|c| c.name == "prelude"));
     }
-}
+    #[test]
+    fn test_listed_components_have_examples() {
+        let registry = Registry::new();
+        for component in registry.list_components() {
+            assert!(component.example.is_some(), "{} has no example", component.name);
+        }
+    }
+    #[test]
+    fn test_dependency_tree_keeps_hierarchy() {
+        let registry = Registry::new();
+        let tree = registry.dependency_tree("input_group").unwrap();
+        assert_eq!(tree.name, "input_group");
 
+        // button and input each list their own dependencies
+        let button = tree.dependencies.iter().find(|d| d.name == "button").unwrap();
+        let input = tree.dependencies.iter().find(|d| d.name == "input").unwrap();
+        assert!(button.dependencies.iter().any(|d| d.name == "traits"));
+        assert!(input.dependencies.iter().any(|d| d.name == "traits"));
+    }
+}
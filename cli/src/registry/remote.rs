@@ -0,0 +1,223 @@
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::{debug, warn};
+
+use super::{check_file_path, ComponentInfo, Origin, Registry};
+use crate::utils;
+
+/// The registry published from the repository's main branch.
+pub const DEFAULT_REGISTRY_URL: &str =
+    "https://raw.githubusercontent.com/AhegaoBurger/gpui-ui/main/registry.json";
+
+/// How long a fetched registry is used before it is fetched again.
+const CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A fetched registry as saved in the cache directory.
+#[derive(Serialize, Deserialize)]
+struct CachedRegistry {
+    url: String,
+    fetched_at: String,
+    components: HashMap<String, ComponentInfo>,
+}
+
+impl Registry {
+    /// Fetches the `registry.json` at `url` and caches it for later commands.
+    pub async fn from_url(url: &str) -> Result<Self> {
+        debug!(url, "fetching registry");
+        let components: HashMap<String, ComponentInfo> = client()?
+            .get(url)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .context(format!("Failed to fetch {}", url))?
+            .json()
+            .await
+            .context(format!("Failed to parse the registry at {}", url))?;
+
+        // A registry that can't be cached is still usable this time
+        if let Err(err) = write_cache(url, &components) {
+            debug!(error = %err, "could not cache registry");
+        }
+
+        Ok(Self {
+            components,
+            origin: Origin::Remote {
+                url: url.to_string(),
+            },
+        })
+    }
+
+    /// The registry at `url`, read from the cache when it was fetched within
+    /// the last hour. If fetching fails, an older cached copy is used, and
+    /// failing that the registry bundled with the CLI.
+    pub async fn load(url: &str) -> Self {
+        let cached = match read_cache(url) {
+            Some((cache, age)) if age < CACHE_TTL => {
+                debug!(url, fetched_at = %cache.fetched_at, "using cached registry");
+                return Self::from_cache(cache);
+            }
+            cached => cached.map(|(cache, _)| cache),
+        };
+
+        match Self::from_url(url).await {
+            Ok(registry) => registry,
+            Err(err) => match cached {
+                Some(cache) => {
+                    warn!("{:#}; using the copy cached at {}", err, cache.fetched_at);
+                    Self::from_cache(cache)
+                }
+                None => {
+                    warn!("{:#}; using the registry bundled with the CLI", err);
+                    Self::new()
+                }
+            },
+        }
+    }
+
+    fn from_cache(cache: CachedRegistry) -> Self {
+        Self {
+            components: cache.components,
+            origin: Origin::Remote { url: cache.url },
+        }
+    }
+}
+
+/// Where the component files of the registry at `registry_url` are served:
+/// `components/src/` in the same directory.
+pub fn sources_url(registry_url: &str) -> String {
+    match registry_url.rsplit_once('/') {
+        Some((dir, _)) => format!("{}/components/src/", dir),
+        None => "components/src/".to_string(),
+    }
+}
+
+/// Downloads `files` from beside the registry at `registry_url` into the
+/// cache and returns the directory holding them. A file that can't be
+/// fetched is taken from an earlier download when there is one. Nothing is
+/// fetched if any file isn't a plain relative path.
+pub async fn fetch_sources(registry_url: &str, files: &[String]) -> Result<PathBuf> {
+    for file in files {
+        check_file_path(file)
+            .context(format!("The registry at {} lists an unsafe file", registry_url))?;
+    }
+    let dir = cache_dir()?.join("sources").join(utils::checksum(registry_url.as_bytes()));
+    fs::create_dir_all(&dir).context(format!("Failed to create {}", dir.display()))?;
+
+    let client = client()?;
+    let base = sources_url(registry_url);
+    for file in files {
+        let url = format!("{}{}", base, file);
+        let path = dir.join(file);
+        debug!(url = %url, dest = %path.display(), "fetching component file");
+
+        let fetched = async {
+            client
+                .get(&url)
+                .send()
+                .await?
+                .error_for_status()?
+                .text()
+                .await
+        }
+        .await;
+        match fetched {
+            Ok(content) => save_source(&dir, file, &content)?,
+            Err(err) if path.is_file() => {
+                debug!(url = %url, error = %err, "fetch failed, using cached copy");
+            }
+            Err(err) => bail!("Failed to fetch {}: {}", url, err),
+        }
+    }
+    Ok(dir)
+}
+
+/// Writes a fetched `file` under `dir`, creating the directories a nested
+/// path such as `ui/button.rs` needs.
+fn save_source(dir: &Path, file: &str, content: &str) -> Result<()> {
+    let path = dir.join(file);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).context(format!("Failed to create {}", parent.display()))?;
+    }
+    fs::write(&path, content).context(format!("Failed to write {}", path.display()))
+}
+
+fn client() -> Result<reqwest::Client> {
+    reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .user_agent(concat!("gpui-ui/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .context("Failed to create HTTP client")
+}
+
+fn cache_dir() -> Result<PathBuf> {
+    Ok(dirs::cache_dir()
+        .context("Could not find a cache directory")?
+        .join("gpui-ui"))
+}
+
+fn cache_file(url: &str) -> Result<PathBuf> {
+    Ok(cache_dir()?
+        .join("registries")
+        .join(format!("{}.json", utils::checksum(url.as_bytes()))))
+}
+
+/// The cached registry for `url` and how long ago it was fetched, if any.
+fn read_cache(url: &str) -> Option<(CachedRegistry, Duration)> {
+    let content = fs::read_to_string(cache_file(url).ok()?).ok()?;
+    let cache: CachedRegistry = serde_json::from_str(&content).ok()?;
+    if cache.url != url {
+        return None;
+    }
+    let fetched_at = chrono::DateTime::parse_from_rfc3339(&cache.fetched_at).ok()?;
+    // A timestamp in the future counts as stale
+    let age = (chrono::Utc::now() - fetched_at.to_utc())
+        .to_std()
+        .unwrap_or(Duration::MAX);
+    Some((cache, age))
+}
+
+fn write_cache(url: &str, components: &HashMap<String, ComponentInfo>) -> Result<()> {
+    let path = cache_file(url)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).context(format!("Failed to create {}", parent.display()))?;
+    }
+    let cache = CachedRegistry {
+        url: url.to_string(),
+        fetched_at: chrono::Utc::now().to_rfc3339(),
+        components: components.clone(),
+    };
+    let content = serde_json::to_string(&cache).context("Failed to serialize registry")?;
+    fs::write(&path, content).context(format!("Failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sources_url() {
+        assert_eq!(
+            sources_url(DEFAULT_REGISTRY_URL),
+            "https://raw.githubusercontent.com/AhegaoBurger/gpui-ui/main/components/src/"
+        );
+        assert_eq!(
+            sources_url("https://example.com/ui/registry.json"),
+            "https://example.com/ui/components/src/"
+        );
+    }
+
+    #[test]
+    fn test_save_source_creates_nested_directories() {
+        let dir = std::env::temp_dir().join(format!("gpui-ui-sources-{}", std::process::id()));
+        save_source(&dir, "ui/forms/button.rs", "// button").unwrap();
+        let saved = fs::read_to_string(dir.join("ui/forms/button.rs"));
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(saved.unwrap(), "// button");
+    }
+}
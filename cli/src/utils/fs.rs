@@ -1,6 +1,7 @@
 use anyhow::{Context, Result};
 use std::fs;
 use std::path::{Path, PathBuf};
+use tracing::{debug, trace};
 
 /// Get the root directory of the gpui-ui project (where components/ is located)
 pub fn get_project_root() -> Result<PathBuf> {
@@ -15,6 +16,7 @@ pub fn get_project_root() -> Result<PathBuf> {
     // Go up until we find the components directory or hit the root
     loop {
         let components_path = path.join("components");
+        trace!(path = %components_path.display(), "looking for components directory");
         if components_path.exists() && components_path.is_dir() {
             return Ok(path.to_path_buf());
         }
@@ -41,8 +43,8 @@ pub fn get_project_root() -> Result<PathBuf> {
     anyhow::bail!("Could not find gpui-ui project root (looking for components/ directory)")
 }
 
-/// Copy a file from source to destination, creating parent directories if needed
-pub fn copy_file(source: &Path, dest: &Path, force: bool) -> Result<()> {
+/// Write `content` to `dest`, creating parent directories if needed
+pub fn write_file(dest: &Path, content: &str, force: bool) -> Result<()> {
     // Check if destination exists
     if dest.exists() && !force {
         anyhow::bail!(
@@ -57,12 +59,8 @@ pub fn copy_file(source: &Path, dest: &Path, force: bool) -> Result<()> {
             .context(format!("Failed to create directory {}", parent.display()))?;
     }
 
-    // Copy the file
-    fs::copy(source, dest).context(format!(
-        "Failed to copy {} to {}",
-        source.display(),
-        dest.display()
-    ))?;
+    debug!(dest = %dest.display(), bytes = content.len(), "writing file");
+    fs::write(dest, content).context(format!("Failed to write {}", dest.display()))?;
 
     Ok(())
 }
@@ -78,6 +76,18 @@ pub fn file_exists(path: &Path) -> bool {
     path.exists() && path.is_file()
 }
 
+/// A short fingerprint of a file's contents, used to tell whether an installed
+/// component has been edited since it was copied. 64-bit FNV-1a as hex; this
+/// detects changes, it doesn't guard against tampering.
+pub fn checksum(content: &[u8]) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in content {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{:016x}", hash)
+}
+
 /// Get the component source directory
 pub fn get_component_source_dir() -> Result<PathBuf> {
     let root = get_project_root()?;
@@ -96,5 +106,11 @@ mod tests {
             assert!(root.join("components").exists());
         }
     }
-}
 
+    #[test]
+    fn test_checksum() {
+        assert_eq!(checksum(b""), "cbf29ce484222325");
+        assert_eq!(checksum(b"a"), "af63dc4c8601ec8c");
+        assert_ne!(checksum(b"rgb(0x3b82f6)"), checksum(b"rgb(0x3b82f7)"));
+    }
+}
@@ -1,5 +1,10 @@
 // Utility functions for CLI operations
 
 pub mod fs;
+pub mod style;
+pub mod template;
+pub mod undo;
 
-pub use fs::{copy_file, get_component_source_dir};
+pub use fs::{checksum, get_component_source_dir, write_file};
+pub use style::{parse_color, parse_pixels};
+pub use template::ThemeTemplate;
@@ -0,0 +1,28 @@
+// Parsing the values written in gpui-ui.json's `style` section.
+
+/// Parses `rgb(0x3b82f6)`, `0x3b82f6`, or `#3b82f6`, the same forms the
+/// components' theme accepts.
+pub fn parse_color(text: &str) -> Option<(u8, u8, u8)> {
+    let text = text.trim();
+    let text = text
+        .strip_prefix("rgb(")
+        .and_then(|inner| inner.strip_suffix(')'))
+        .unwrap_or(text)
+        .trim();
+    let hex = text.strip_prefix("0x").or_else(|| text.strip_prefix('#'))?;
+    if hex.len() != 6 {
+        return None;
+    }
+    let value = u32::from_str_radix(hex, 16).ok()?;
+    Some(((value >> 16) as u8, (value >> 8) as u8, value as u8))
+}
+
+/// Parses `px(4.0)` or a bare number of pixels.
+pub fn parse_pixels(text: &str) -> Option<f32> {
+    let text = text.trim();
+    let text = text
+        .strip_prefix("px(")
+        .and_then(|inner| inner.strip_suffix(')'))
+        .unwrap_or(text);
+    text.trim().parse::<f32>().ok().filter(|pixels| pixels.is_finite())
+}
@@ -0,0 +1,373 @@
+use anyhow::{bail, Result};
+use std::path::Path;
+
+use crate::config::StyleConfig;
+use crate::utils::{parse_color, parse_pixels};
+
+/// The component file holding the palette and default radius. Components
+/// name its constants rather than repeating the values, so it's the only
+/// file the theme is written into.
+const PALETTE_FILE: &str = "colors.rs";
+
+/// The constant in `PALETTE_FILE` holding the corner radius.
+const RADIUS_CONSTANT: &str = "RADIUS";
+
+/// Writes the colors and radius configured in gpui-ui.json's `style` section
+/// into copied component sources.
+///
+/// Only `colors.rs` is rewritten: each configured color replaces the
+/// `hex(0x......)` value of the constant it names, and the radius the value
+/// of `RADIUS`. Constants are matched by name, so two sharing a default
+/// value stay apart. Comments, string literals, and items marked
+/// `#[cfg(test)]` are left as written.
+pub struct ThemeTemplate {
+    /// Constant names and the color written into each.
+    colors: Vec<(&'static str, u32)>,
+    /// The radius as Rust source, e.g. `px(6.0)`.
+    radius: Option<String>,
+}
+
+impl ThemeTemplate {
+    /// Reads the configured colors and radius. A value that can't be written
+    /// into Rust source is an error, rather than a copied file that doesn't
+    /// compile.
+    pub fn from_style(style: &StyleConfig) -> Result<Self> {
+        let configured = [
+            ("primary", "PRIMARY", &style.colors.primary),
+            ("secondary", "SECONDARY", &style.colors.secondary),
+            ("destructive", "DESTRUCTIVE", &style.colors.destructive),
+            ("muted", "MUTED", &style.colors.muted),
+            ("accent", "ACCENT", &style.colors.accent),
+        ];
+
+        let mut colors = Vec::new();
+        for (name, constant, text) in configured {
+            let Some(value) = parse_theme_color(text) else {
+                bail!(
+                    "style.colors.{} in gpui-ui.json is {:?}, which isn't a color. Use rgb(0xRRGGBB) or hsla(h, s, l, 1.0)",
+                    name,
+                    text
+                );
+            };
+            colors.push((constant, value));
+        }
+
+        // Configs written before the radius had a default left it empty
+        let radius = match style.radius.trim() {
+            "" => None,
+            text => {
+                let Some(pixels) = parse_pixels(text) else {
+                    bail!(
+                        "style.radius in gpui-ui.json is {:?}, which isn't a size. Use px(4.0)",
+                        style.radius
+                    );
+                };
+                Some(format!("px({:?})", pixels))
+            }
+        };
+
+        Ok(Self { colors, radius })
+    }
+
+    /// A template that leaves sources as written, for `--no-theme`.
+    pub fn verbatim() -> Self {
+        Self {
+            colors: Vec::new(),
+            radius: None,
+        }
+    }
+
+    /// Whether applying the template changes nothing in any file.
+    pub fn is_identity(&self) -> bool {
+        self.colors.is_empty() && self.radius.is_none()
+    }
+
+    /// `source`, the contents of the component file `file`, with the
+    /// configured theme written in.
+    pub fn apply(&self, file: &str, source: &str) -> String {
+        let is_palette = Path::new(file)
+            .file_name()
+            .is_some_and(|name| name == PALETTE_FILE);
+        if self.is_identity() || !is_palette {
+            return source.to_string();
+        }
+
+        let mut out = String::with_capacity(source.len());
+        let mut rest = source;
+        while !rest.is_empty() {
+            let skipped = if rest.starts_with("#[cfg(test)]") {
+                cfg_test_item_len(rest)
+            } else {
+                skip_non_code(rest)
+            };
+            if skipped > 0 {
+                out.push_str(&rest[..skipped]);
+                rest = &rest[skipped..];
+                continue;
+            }
+
+            if let Some((len, replacement)) = self.substitute(rest, &out) {
+                out.push_str(&replacement);
+                rest = &rest[len..];
+                continue;
+            }
+
+            let ch = rest.chars().next().expect("rest is not empty");
+            out.push(ch);
+            rest = &rest[ch.len_utf8()..];
+        }
+        out
+    }
+
+    /// A themed replacement for the constant declared at the start of
+    /// `rest`, and the length of the text it replaces. `before` is the code
+    /// written so far.
+    fn substitute(&self, rest: &str, before: &str) -> Option<(usize, String)> {
+        // Part of a longer name, e.g. `MY_const`
+        if before
+            .chars()
+            .next_back()
+            .is_some_and(|ch| ch.is_alphanumeric() || ch == '_')
+        {
+            return None;
+        }
+
+        for (constant, themed) in &self.colors {
+            let prefix = format!("const {}: Rgba = hex(0x", constant);
+            let Some(digits) = rest.strip_prefix(&prefix) else {
+                continue;
+            };
+            let hex = digits.get(..6)?;
+            if !hex.chars().all(|ch| ch.is_ascii_hexdigit()) || !digits[6..].starts_with(')') {
+                return None;
+            }
+            return Some((prefix.len() + 6, format!("{}{:06x}", prefix, themed)));
+        }
+
+        if let Some(radius) = &self.radius {
+            let prefix = format!("const {}: Pixels = ", RADIUS_CONSTANT);
+            let value = rest.strip_prefix(&prefix)?;
+            let end = value.find(';')?;
+            if !value[..end].starts_with("px(") {
+                return None;
+            }
+            return Some((prefix.len() + end, format!("{}{}", prefix, radius)));
+        }
+        None
+    }
+}
+
+/// The length of the `#[cfg(test)]` attribute at the start of `text` and
+/// the item it applies to, up to the `;` or closing brace that ends it.
+fn cfg_test_item_len(text: &str) -> usize {
+    let mut depth = 0;
+    let mut i = "#[cfg(test)]".len();
+    while i < text.len() {
+        let skipped = skip_non_code(&text[i..]);
+        if skipped > 0 {
+            i += skipped;
+            continue;
+        }
+        let ch = text[i..].chars().next().expect("i is within text");
+        i += ch.len_utf8();
+        match ch {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return i;
+                }
+            }
+            ';' if depth == 0 => return i,
+            _ => {}
+        }
+    }
+    text.len()
+}
+
+/// The length of the comment, string, or char literal at the start of
+/// `text`, or 0 if it starts with code.
+fn skip_non_code(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    if text.starts_with("//") {
+        return text.find('\n').unwrap_or(text.len());
+    }
+    if text.starts_with("/*") {
+        // Block comments nest
+        let mut depth = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i..].starts_with(b"/*") {
+                depth += 1;
+                i += 2;
+            } else if bytes[i..].starts_with(b"*/") {
+                depth -= 1;
+                i += 2;
+                if depth == 0 {
+                    return i;
+                }
+            } else {
+                i += 1;
+            }
+        }
+        return text.len();
+    }
+    if let Some(raw) = text.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        if raw[hashes..].starts_with('"') {
+            let closing = format!("\"{}", "#".repeat(hashes));
+            let body = 1 + hashes + 1;
+            return match text[body..].find(&closing) {
+                Some(end) => body + end + closing.len(),
+                None => text.len(),
+            };
+        }
+    }
+    if text.starts_with('"') {
+        let mut i = 1;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'\\' => i += 2,
+                b'"' => return i + 1,
+                _ => i += 1,
+            }
+        }
+        return text.len();
+    }
+    if let Some(quoted) = text.strip_prefix('\'') {
+        // A char literal such as '"' or '\'', not a lifetime
+        return match quoted.chars().next() {
+            Some('\\') => quoted[1..].find('\'').map_or(0, |end| end + 3),
+            Some(ch) if quoted[ch.len_utf8()..].starts_with('\'') => ch.len_utf8() + 2,
+            _ => 0,
+        };
+    }
+    0
+}
+
+/// Parses the forms gpui-ui.json colors are written in: `rgb(0x3b82f6)`,
+/// `0x3b82f6`, `#3b82f6`, or an opaque `hsla(h, s, l, 1.0)` with each part
+/// from 0 to 1 as gpui takes them.
+fn parse_theme_color(text: &str) -> Option<u32> {
+    if let Some((r, g, b)) = parse_color(text) {
+        return Some((u32::from(r) << 16) | (u32::from(g) << 8) | u32::from(b));
+    }
+
+    let inner = text.trim().strip_prefix("hsla(")?.strip_suffix(')')?;
+    let parts: Vec<f32> = inner
+        .split(',')
+        .map(|part| part.trim().parse::<f32>().ok())
+        .collect::<Option<_>>()?;
+    let [h, s, l, a] = parts[..] else {
+        return None;
+    };
+    if ![h, s, l].iter().all(|part| (0.0..=1.0).contains(part)) || a != 1.0 {
+        return None;
+    }
+    let (r, g, b) = hsl_to_rgb(h, s, l);
+    Some((r << 16) | (g << 8) | b)
+}
+
+fn hsl_to_rgb(h: f32, s: f32, l: f32) -> (u32, u32, u32) {
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let h = (h * 6.0) % 6.0;
+    let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let m = l - c / 2.0;
+    let channel = |value: f32| ((value + m) * 255.0).round() as u32;
+    (channel(r), channel(g), channel(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The palette as it ships, which the default style must leave alone.
+    const COLORS_RS: &str = include_str!("../../../components/src/colors.rs");
+
+    fn template(primary: &str, radius: &str) -> ThemeTemplate {
+        let mut style = StyleConfig::default();
+        style.colors.primary = primary.to_string();
+        style.radius = radius.to_string();
+        ThemeTemplate::from_style(&style).unwrap()
+    }
+
+    #[test]
+    fn test_default_style_matches_the_palette() {
+        let template = ThemeTemplate::from_style(&StyleConfig::default()).unwrap();
+        assert_eq!(template.apply("colors.rs", COLORS_RS), COLORS_RS);
+    }
+
+    #[test]
+    fn test_apply_rewrites_constants_by_name() {
+        let mut style = StyleConfig::default();
+        style.colors.secondary = "#112233".to_string();
+        let themed = ThemeTemplate::from_style(&style)
+            .unwrap()
+            .apply("colors.rs", COLORS_RS);
+        assert!(themed.contains("pub const SECONDARY: Rgba = hex(0x112233);"));
+        // Shares secondary's default value but isn't configurable
+        assert!(themed.contains("pub const MUTED_FOREGROUND: Rgba = hex(0x64748b);"));
+    }
+
+    #[test]
+    fn test_apply_only_rewrites_the_palette() {
+        let source = "const PRIMARY: Rgba = hex(0x3b82f6);\n.text_color(rgb(0x3b82f6))\n";
+        let template = template("rgb(0x8b5cf6)", "px(4.0)");
+        assert_eq!(template.apply("button.rs", source), source);
+        assert_eq!(
+            template.apply("ui/colors.rs", source),
+            "const PRIMARY: Rgba = hex(0x8b5cf6);\n.text_color(rgb(0x3b82f6))\n"
+        );
+    }
+
+    #[test]
+    fn test_apply_rewrites_code_only() {
+        let source = "// const PRIMARY: Rgba = hex(0x3b82f6);\nconst S: &str = \"const PRIMARY: Rgba = hex(0x3b82f6)\";\nlet q = '\"'; pub const PRIMARY: Rgba = hex(0x3b82f6);\n";
+        assert_eq!(
+            template("rgb(0x8b5cf6)", "px(4.0)").apply("colors.rs", source),
+            "// const PRIMARY: Rgba = hex(0x3b82f6);\nconst S: &str = \"const PRIMARY: Rgba = hex(0x3b82f6)\";\nlet q = '\"'; pub const PRIMARY: Rgba = hex(0x8b5cf6);\n"
+        );
+    }
+
+    #[test]
+    fn test_apply_rewrites_the_radius() {
+        let source = "pub const RADIUS: Pixels = px(4.0);\nblur_radius: px(4.0),\n";
+        assert_eq!(
+            template("rgb(0x3b82f6)", "6").apply("colors.rs", source),
+            "pub const RADIUS: Pixels = px(6.0);\nblur_radius: px(4.0),\n"
+        );
+    }
+
+    #[test]
+    fn test_apply_skips_only_test_items() {
+        let source = "#[cfg(test)]\nmod tests { const PRIMARY: Rgba = hex(0x3b82f6); }\npub const PRIMARY: Rgba = hex(0x3b82f6);\n#[cfg(test)]\nconst PRIMARY: Rgba = hex(0x3b82f6);\n";
+        assert_eq!(
+            template("#8b5cf6", "px(4.0)").apply("colors.rs", source),
+            "#[cfg(test)]\nmod tests { const PRIMARY: Rgba = hex(0x3b82f6); }\npub const PRIMARY: Rgba = hex(0x8b5cf6);\n#[cfg(test)]\nconst PRIMARY: Rgba = hex(0x3b82f6);\n"
+        );
+    }
+
+    #[test]
+    fn test_hsla_colors() {
+        assert_eq!(parse_theme_color("hsla(0.0, 1.0, 0.5, 1.0)"), Some(0xff0000));
+        assert_eq!(parse_theme_color("hsla(0.5, 1.0, 0.5, 1.0)"), Some(0x00ffff));
+        assert_eq!(parse_theme_color("hsla(0.5, 1.0, 0.5, 0.5)"), None);
+        assert_eq!(parse_theme_color("blue"), None);
+    }
+
+    #[test]
+    fn test_invalid_color_is_an_error() {
+        let mut style = StyleConfig::default();
+        style.colors.destructive = "rgb(0xnope)".to_string();
+        let err = ThemeTemplate::from_style(&style).err().unwrap();
+        assert!(err.to_string().contains("style.colors.destructive"));
+    }
+}
@@ -0,0 +1,309 @@
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::debug;
+
+use crate::config::CONFIG_FILE_NAME;
+
+/// Where destructive commands stash what they are about to change, relative
+/// to the project root.
+pub const UNDO_DIR: &str = ".gpui-ui/undo";
+
+/// How many operations are kept. Older ones are dropped as new ones arrive.
+pub const MAX_ENTRIES: usize = 5;
+
+const MANIFEST_FILE: &str = "manifest.json";
+const CONFIG_STASH: &str = "config.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Manifest {
+    /// The command as the user would describe it, e.g. `remove button`.
+    pub operation: String,
+    pub created_at: String,
+    pub files: Vec<StashedFile>,
+}
+
+/// A file the operation touched. `existed` is false for files it created,
+/// which undo deletes instead of restoring.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StashedFile {
+    pub path: PathBuf,
+    pub existed: bool,
+}
+
+/// Files and config saved before a destructive operation. Nothing is
+/// recorded as undoable until `commit`, so a command that bails early
+/// leaves no entry behind: dropping an uncommitted snapshot deletes what it
+/// saved.
+pub struct Snapshot {
+    dir: PathBuf,
+    manifest: Manifest,
+    committed: bool,
+}
+
+impl Snapshot {
+    /// Starts a snapshot and saves the current gpui-ui.json into it.
+    pub fn begin(operation: impl Into<String>) -> Result<Self> {
+        Self::begin_in(Path::new(""), operation)
+    }
+
+    /// Starts a snapshot for the project at `root`.
+    fn begin_in(root: &Path, operation: impl Into<String>) -> Result<Self> {
+        let now = chrono::Utc::now();
+        let undo_dir = root.join(UNDO_DIR);
+        fs::create_dir_all(&undo_dir)
+            .context(format!("Failed to create {}", undo_dir.display()))?;
+
+        // Two operations within a millisecond still get their own entries
+        let stamp = now.format("%Y%m%dT%H%M%S%.3f").to_string();
+        let mut dir = undo_dir.join(&stamp);
+        let mut attempt = 0;
+        loop {
+            match fs::create_dir(&dir) {
+                Ok(()) => break,
+                Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => {
+                    attempt += 1;
+                    dir = undo_dir.join(format!("{}-{}", stamp, attempt));
+                }
+                Err(err) => {
+                    return Err(err).context(format!("Failed to create {}", dir.display()))
+                }
+            }
+        }
+
+        let operation = operation.into();
+        let snapshot = Self {
+            dir,
+            manifest: Manifest {
+                operation,
+                created_at: now.to_rfc3339(),
+                files: Vec::new(),
+            },
+            committed: false,
+        };
+        fs::copy(root.join(CONFIG_FILE_NAME), snapshot.dir.join(CONFIG_STASH))
+            .context(format!("Failed to save {} for undo", CONFIG_FILE_NAME))?;
+        debug!(
+            operation = %snapshot.manifest.operation,
+            dir = %snapshot.dir.display(),
+            "started undo snapshot"
+        );
+        Ok(snapshot)
+    }
+
+    /// Saves `path` as it is now, before the operation changes or deletes it.
+    /// Stashing a path twice keeps the first copy.
+    pub fn stash(&mut self, path: &Path) -> Result<()> {
+        if self.manifest.files.iter().any(|file| file.path == path) {
+            return Ok(());
+        }
+
+        let existed = path.is_file();
+        if existed {
+            let stash = stash_file(&self.dir, self.manifest.files.len());
+            fs::copy(path, &stash)
+                .context(format!("Failed to save {} for undo", path.display()))?;
+        }
+        debug!(path = %path.display(), existed, "stashed for undo");
+        self.manifest.files.push(StashedFile {
+            path: path.to_path_buf(),
+            existed,
+        });
+        Ok(())
+    }
+
+    /// Records the snapshot as the most recent undoable operation and drops
+    /// the oldest beyond `MAX_ENTRIES`.
+    pub fn commit(mut self) -> Result<()> {
+        let content = serde_json::to_string_pretty(&self.manifest)
+            .context("Failed to serialize undo manifest")?;
+        fs::write(self.dir.join(MANIFEST_FILE), content)
+            .context("Failed to write undo manifest")?;
+        self.committed = true;
+
+        // The entry sits in `<root>/.gpui-ui/undo/<timestamp>`
+        let undo_dir = self.dir.parent().unwrap_or(Path::new(UNDO_DIR));
+        let entries = entries_at(undo_dir)?;
+        for (dir, _) in entries.iter().skip(MAX_ENTRIES) {
+            debug!(dir = %dir.display(), "dropping old undo entry");
+            fs::remove_dir_all(dir).context(format!("Failed to remove {}", dir.display()))?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Snapshot {
+    fn drop(&mut self) {
+        if self.committed {
+            return;
+        }
+        debug!(dir = %self.dir.display(), "discarding uncommitted undo snapshot");
+        if let Err(err) = fs::remove_dir_all(&self.dir) {
+            debug!(dir = %self.dir.display(), error = %err, "could not discard undo snapshot");
+        }
+    }
+}
+
+/// Where the `index`th stashed file of the entry at `dir` is kept.
+fn stash_file(dir: &Path, index: usize) -> PathBuf {
+    dir.join(format!("file-{}", index))
+}
+
+/// Committed operations, most recent first.
+pub fn entries() -> Result<Vec<(PathBuf, Manifest)>> {
+    entries_at(Path::new(UNDO_DIR))
+}
+
+/// Committed operations in `undo_dir`, most recent first.
+fn entries_at(undo_dir: &Path) -> Result<Vec<(PathBuf, Manifest)>> {
+    let Ok(read_dir) = fs::read_dir(undo_dir) else {
+        return Ok(Vec::new());
+    };
+
+    let mut entries = Vec::new();
+    for entry in read_dir {
+        let dir = entry?.path();
+        // Snapshots that were never committed have no manifest
+        let Ok(content) = fs::read_to_string(dir.join(MANIFEST_FILE)) else {
+            continue;
+        };
+        let manifest: Manifest = serde_json::from_str(&content)
+            .context(format!("Failed to parse undo manifest in {}", dir.display()))?;
+        entries.push((dir, manifest));
+    }
+    // Directory names are timestamps, so they sort chronologically
+    entries.sort_by(|(a, _), (b, _)| b.cmp(a));
+    Ok(entries)
+}
+
+/// Puts back the files and config saved in the entry at `dir`, then deletes
+/// the entry.
+pub fn restore(dir: &Path, manifest: &Manifest) -> Result<()> {
+    restore_in(Path::new(""), dir, manifest)
+}
+
+/// Restores the entry at `dir` into the project at `root`.
+fn restore_in(root: &Path, dir: &Path, manifest: &Manifest) -> Result<()> {
+    let config = dir.join(CONFIG_STASH);
+    if !config.is_file() {
+        bail!("Undo entry {} has no saved config", dir.display());
+    }
+
+    for (index, file) in manifest.files.iter().enumerate() {
+        if file.existed {
+            if let Some(parent) = file.path.parent() {
+                fs::create_dir_all(parent)
+                    .context(format!("Failed to create directory {}", parent.display()))?;
+            }
+            fs::copy(stash_file(dir, index), &file.path)
+                .context(format!("Failed to restore {}", file.path.display()))?;
+        } else if file.path.exists() {
+            fs::remove_file(&file.path)
+                .context(format!("Failed to remove {}", file.path.display()))?;
+        }
+    }
+
+    fs::copy(&config, root.join(CONFIG_FILE_NAME))
+        .context(format!("Failed to restore {}", CONFIG_FILE_NAME))?;
+
+    fs::remove_dir_all(dir).context(format!("Failed to remove {}", dir.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An empty project with a gpui-ui.json, unique to the test.
+    fn project(name: &str) -> PathBuf {
+        let root = std::env::temp_dir()
+            .join(format!("gpui-ui-undo-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join(CONFIG_FILE_NAME), r#"{"components":[]}"#).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_restore_undoes_an_operation() {
+        let root = project("restore");
+        let edited = root.join("src/button.rs");
+        let created = root.join("src/traits.rs");
+        fs::write(&edited, "// original").unwrap();
+
+        let mut snapshot = Snapshot::begin_in(&root, "add --force button").unwrap();
+        snapshot.stash(&edited).unwrap();
+        snapshot.stash(&created).unwrap();
+        fs::write(&edited, "// overwritten").unwrap();
+        fs::write(&created, "// new").unwrap();
+        fs::write(root.join(CONFIG_FILE_NAME), r#"{"components":["button"]}"#).unwrap();
+        snapshot.commit().unwrap();
+
+        let entries = entries_at(&root.join(UNDO_DIR)).unwrap();
+        assert_eq!(entries.len(), 1);
+        let (dir, manifest) = &entries[0];
+        assert_eq!(manifest.operation, "add --force button");
+        restore_in(&root, dir, manifest).unwrap();
+
+        assert_eq!(fs::read_to_string(&edited).unwrap(), "// original");
+        assert!(!created.exists());
+        assert_eq!(
+            fs::read_to_string(root.join(CONFIG_FILE_NAME)).unwrap(),
+            r#"{"components":[]}"#
+        );
+        assert!(!dir.exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_commit_keeps_the_latest_entries() {
+        let root = project("prune");
+        for index in 0..MAX_ENTRIES + 2 {
+            Snapshot::begin_in(&root, format!("remove c{}", index))
+                .unwrap()
+                .commit()
+                .unwrap();
+        }
+
+        let operations: Vec<String> = entries_at(&root.join(UNDO_DIR))
+            .unwrap()
+            .into_iter()
+            .map(|(_, manifest)| manifest.operation)
+            .collect();
+        let expected: Vec<String> = (2..MAX_ENTRIES + 2)
+            .rev()
+            .map(|index| format!("remove c{}", index))
+            .collect();
+        assert_eq!(operations, expected);
+        assert_eq!(fs::read_dir(root.join(UNDO_DIR)).unwrap().count(), MAX_ENTRIES);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_uncommitted_snapshots_are_not_entries() {
+        let root = project("uncommitted");
+        let undo_dir = root.join(UNDO_DIR);
+
+        // Dropped without commit, as when a command bails part way
+        let mut snapshot = Snapshot::begin_in(&root, "remove button").unwrap();
+        snapshot.stash(&root.join(CONFIG_FILE_NAME)).unwrap();
+        drop(snapshot);
+        assert_eq!(fs::read_dir(&undo_dir).unwrap().count(), 0);
+
+        // Left behind by a process that was killed before committing
+        fs::create_dir_all(undo_dir.join("20260101T000000.000")).unwrap();
+        Snapshot::begin_in(&root, "add --force input")
+            .unwrap()
+            .commit()
+            .unwrap();
+
+        let entries = entries_at(&undo_dir).unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].1.operation, "add --force input");
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
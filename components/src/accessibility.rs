@@ -0,0 +1,173 @@
+// Accessible names and roles for interactive components.
+//
+// GPUI does not expose the platform accessibility tree yet, so each
+// interactive component records what it would expose (a role, a name, and
+// its state) in a global `AccessibilityTree` when it renders, keyed by its
+// element id. Nodes live in their element's state, so an element that stops
+// rendering drops out of the tree with it. Apps bridge these to the platform
+// screen reader (or assert on them in tests) until GPUI gains native
+// support, as with `announce`.
+
+use crate::prelude::*;
+use std::collections::HashMap;
+
+/// What kind of control an element is, as assistive technology names it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    Button,
+    /// A button that stays pressed, like `ToggleButton` or `Chip`.
+    ToggleButton,
+    Checkbox,
+    TextInput,
+    Link,
+    /// A control that opens a list to pick a value from, like `Select`.
+    ComboBox,
+}
+
+/// Everything an element exposes to assistive technology.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccessibleNode {
+    pub role: Role,
+    pub name: SharedString,
+    pub disabled: bool,
+    /// Checked or pressed state, for roles that have one.
+    pub toggle_state: Option<ToggleState>,
+}
+
+/// Global index of the nodes of rendered elements, by element id.
+#[derive(Default)]
+pub struct AccessibilityTree {
+    nodes: HashMap<ElementId, WeakEntity<AccessibleNode>>,
+}
+
+impl Global for AccessibilityTree {}
+
+impl AccessibilityTree {
+    /// Returns what the element with `id` exposed when it last rendered, or
+    /// `None` once it is no longer rendered.
+    pub fn get(id: &ElementId, cx: &App) -> Option<AccessibleNode> {
+        let node = cx.try_global::<Self>()?.nodes.get(id)?.upgrade()?;
+        Some(node.read(cx).clone())
+    }
+
+    /// Every node of an element that is still rendered.
+    pub fn nodes(cx: &App) -> Vec<(ElementId, AccessibleNode)> {
+        cx.try_global::<Self>()
+            .into_iter()
+            .flat_map(|tree| tree.nodes.iter())
+            .filter_map(|(id, node)| Some((id.clone(), node.upgrade()?.read(cx).clone())))
+            .collect()
+    }
+}
+
+/// Records the node for `id`, replacing whatever it exposed last frame. Call
+/// it while rendering the element.
+// Only components call this, so with the `accessibility` feature enabled on
+// its own nothing does
+#[allow(dead_code)]
+pub(crate) fn expose(id: &ElementId, node: AccessibleNode, window: &mut Window, cx: &mut App) {
+    let state = window.use_keyed_state(id.clone(), cx, |_, _| node.clone());
+    state.update(cx, |current, _| *current = node);
+
+    let tree = cx.default_global::<AccessibilityTree>();
+    if !tree.nodes.contains_key(id) {
+        // A new element; forget the ones that have stopped rendering
+        tree.nodes.retain(|_, node| node.upgrade().is_some());
+    }
+    tree.nodes.insert(id.clone(), state.downgrade());
+}
+
+/// Picks an element's accessible name: an explicit name wins, then the
+/// visible label, then the tooltip. A label made only of symbols, such as
+/// "✕" on an icon button, says nothing to a screen reader and is skipped.
+pub fn accessible_name(
+    explicit: Option<&SharedString>,
+    label: &str,
+    tooltip: Option<&SharedString>,
+) -> SharedString {
+    if let Some(name) = explicit {
+        return name.clone();
+    }
+    if label.chars().any(char::is_alphanumeric) {
+        return label.trim().to_string().into();
+    }
+    tooltip
+        .cloned()
+        .unwrap_or_else(|| label.trim().to_string().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{accessible_name, expose, AccessibilityTree, AccessibleNode, Role};
+    use gpui::{div, prelude::*, App, ElementId, SharedString, TestAppContext, Window};
+
+    #[test]
+    fn test_accessible_name_prefers_explicit_then_label() {
+        let explicit: SharedString = "Close dialog".into();
+        let tooltip: SharedString = "Close".into();
+
+        assert_eq!(accessible_name(Some(&explicit), "Save", Some(&tooltip)), "Close dialog");
+        assert_eq!(accessible_name(None, " Save ", Some(&tooltip)), "Save");
+    }
+
+    #[test]
+    fn test_icon_only_label_falls_back_to_tooltip() {
+        let tooltip: SharedString = "Close".into();
+
+        assert_eq!(accessible_name(None, "✕", Some(&tooltip)), "Close");
+        assert_eq!(accessible_name(None, "✕", None), "✕");
+    }
+
+    #[derive(IntoElement)]
+    struct Named(&'static str);
+
+    impl RenderOnce for Named {
+        fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+            let id = ElementId::Name(self.0.into());
+            let node = AccessibleNode {
+                role: Role::Button,
+                name: self.0.into(),
+                disabled: false,
+                toggle_state: None,
+            };
+            expose(&id, node, window, cx);
+            div().id(id)
+        }
+    }
+
+    struct Row {
+        names: Vec<&'static str>,
+    }
+
+    impl Render for Row {
+        fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+            div().children(self.names.iter().map(|name| Named(name)))
+        }
+    }
+
+    #[gpui::test]
+    fn elements_that_stop_rendering_leave_the_tree(cx: &mut TestAppContext) {
+        let (row, cx) = cx.add_window_view(|_, _| Row {
+            names: vec!["save", "close"],
+        });
+        cx.run_until_parked();
+        cx.update(|_, cx| {
+            let close = AccessibilityTree::get(&"close".into(), cx).expect("close exposed");
+            assert_eq!(close.name, "close");
+        });
+
+        row.update(cx, |row, cx| {
+            row.names.pop();
+            cx.notify();
+        });
+        cx.run_until_parked();
+        cx.update(|_, cx| {
+            assert_eq!(AccessibilityTree::get(&"close".into(), cx), None);
+            let names: Vec<SharedString> = AccessibilityTree::nodes(cx)
+                .into_iter()
+                .map(|(_, node)| node.name)
+                .collect();
+            assert_eq!(names, ["save"]);
+        });
+    }
+}
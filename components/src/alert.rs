@@ -0,0 +1,304 @@
+use crate::announce::{announce, Politeness};
+use crate::focus_visible::{self, InputModality};
+use crate::prelude::*;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+type DismissHandler = Rc<dyn Fn(&DismissReason, &mut Window, &mut App) + 'static>;
+
+/// Alert variant determines the accent color and icon
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AlertVariant {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+/// What dismissed the alert.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DismissReason {
+    /// The dismiss button was clicked, or Enter pressed on it.
+    Button,
+    /// Escape was pressed while focus was inside the alert.
+    Escape,
+    /// The `auto_dismiss` timeout ran out.
+    Timeout,
+}
+
+/// Dismiss-button focus and the auto-dismiss countdown, kept across frames.
+struct AlertState {
+    focus_handle: FocusHandle,
+    /// Time left before auto-dismissing, or `None` once it has fired.
+    remaining: Option<Duration>,
+    /// The running countdown and when it started. Dropping the task cancels it.
+    timer: Option<(Instant, Task<()>)>,
+    hovered: bool,
+}
+
+/// An inline message box, such as a form error or a "Changes saved" notice,
+/// shown within the page content.
+///
+/// The alert is controlled: the parent stops rendering it when `on_dismiss`
+/// is called, whether from the dismiss button, Escape, or the auto-dismiss
+/// timer.
+#[derive(IntoElement)]
+pub struct Alert {
+    id: ElementId,
+    variant: AlertVariant,
+    title: SharedString,
+    description: Option<SharedString>,
+    auto_dismiss: Option<Duration>,
+    on_dismiss: Option<DismissHandler>,
+}
+
+impl Alert {
+    pub fn new(id: impl Into<ElementId>, title: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            variant: AlertVariant::default(),
+            title: title.into(),
+            description: None,
+            auto_dismiss: None,
+            on_dismiss: None,
+        }
+    }
+
+    pub fn variant(mut self, variant: AlertVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Secondary text shown under the title.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Calls `on_dismiss` once the alert has been shown for `timeout`. The
+    /// countdown pauses while the pointer is over the alert. Only the value
+    /// passed when the alert first renders is used.
+    pub fn auto_dismiss(mut self, timeout: Duration) -> Self {
+        self.auto_dismiss = Some(timeout);
+        self
+    }
+
+    /// Shows a dismiss button. Escape anywhere in the alert, or Enter on the
+    /// focused button, dismisses it too.
+    pub fn on_dismiss(
+        mut self,
+        handler: impl Fn(&DismissReason, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_dismiss = Some(Rc::new(handler));
+        self
+    }
+
+    fn get_accent_color(&self, theme: &Theme) -> Rgba {
+        match self.variant {
+            AlertVariant::Info => theme.primary,
+            AlertVariant::Success => theme.success,
+            AlertVariant::Warning => theme.warning,
+            AlertVariant::Error => theme.destructive,
+        }
+    }
+
+    /// Errors and warnings interrupt the screen reader; the rest wait.
+    fn get_politeness(&self) -> Politeness {
+        match self.variant {
+            AlertVariant::Warning | AlertVariant::Error => Politeness::Assertive,
+            AlertVariant::Info | AlertVariant::Success => Politeness::Polite,
+        }
+    }
+
+    fn get_icon(&self) -> &'static str {
+        match self.variant {
+            AlertVariant::Info => "ℹ",
+            AlertVariant::Success => "✓",
+            AlertVariant::Warning => "⚠",
+            AlertVariant::Error => "✕",
+        }
+    }
+}
+
+impl RenderOnce for Alert {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        focus_visible::init(cx);
+
+        let theme = cx.theme();
+        let preset = ComponentSize::Medium.preset(cx);
+        let accent = self.get_accent_color(&theme);
+        let icon = self.get_icon();
+
+        let auto_dismiss = self.auto_dismiss;
+        let message: SharedString = match &self.description {
+            Some(description) => format!("{}. {}", self.title, description).into(),
+            None => self.title.clone(),
+        };
+        let politeness = self.get_politeness();
+        // Runs once, when the alert first appears
+        let state = window.use_keyed_state(self.id.clone(), cx, move |_, cx| {
+            announce(message, politeness, cx);
+            AlertState {
+                focus_handle: cx.focus_handle(),
+                remaining: auto_dismiss,
+                timer: None,
+                hovered: false,
+            }
+        });
+        let focus_handle = state.read(cx).focus_handle.clone();
+
+        // Start (or resume) the countdown unless it's running, paused, or done
+        if let Some(on_dismiss) = self.on_dismiss.clone() {
+            let pending = {
+                let state = state.read(cx);
+                state
+                    .remaining
+                    .filter(|_| state.timer.is_none() && !state.hovered)
+            };
+            if let Some(remaining) = pending {
+                let timer_state = state.clone();
+                let task = window.spawn(cx, async move |cx| {
+                    cx.background_executor().timer(remaining).await;
+                    cx.update(|window, cx| {
+                        timer_state.update(cx, |state, _| state.remaining = None);
+                        on_dismiss(&DismissReason::Timeout, window, cx);
+                    })
+                    .ok();
+                });
+                state.update(cx, |state, _| state.timer = Some((Instant::now(), task)));
+            }
+        }
+
+        let show_ring = focus_visible::is_focus_visible(&focus_handle, window, cx);
+        let hover_state = state.clone();
+
+        div()
+            .id(self.id)
+            .w_full()
+            .flex()
+            .items_start()
+            .gap_3()
+            .p(preset.padding)
+            .rounded(theme.radius)
+            .border_1()
+            .border_color(accent)
+            .bg(Hsla::from(accent).opacity(0.08))
+            .text_size(preset.text_size)
+            .on_hover(move |hovered, _, cx| {
+                hover_state.update(cx, |state, cx| {
+                    state.hovered = *hovered;
+                    if *hovered {
+                        // Bank the time already shown and cancel the countdown
+                        if let Some((started, _)) = state.timer.take() {
+                            state.remaining = state
+                                .remaining
+                                .map(|remaining| remaining.saturating_sub(started.elapsed()));
+                        }
+                    }
+                    cx.notify();
+                });
+            })
+            .child(div().flex_none().text_color(accent).child(icon))
+            .child(
+                div()
+                    .flex_1()
+                    .min_w_0()
+                    .flex()
+                    .flex_col()
+                    .gap_1()
+                    .child(
+                        div()
+                            .font_weight(FontWeight::MEDIUM)
+                            .text_color(theme.foreground)
+                            .child(self.title),
+                    )
+                    .when_some(self.description, |content, description| {
+                        content.child(div().text_color(theme.muted_foreground).child(description))
+                    }),
+            )
+            .when_some(self.on_dismiss, |alert, on_dismiss| {
+                let on_escape = on_dismiss.clone();
+                let on_enter = on_dismiss.clone();
+                alert
+                    .on_key_down(move |event, window, cx| {
+                        if event.keystroke.key == "escape" {
+                            cx.stop_propagation();
+                            on_escape(&DismissReason::Escape, window, cx);
+                        }
+                    })
+                    .child(
+                        div()
+                            .id("dismiss")
+                            .flex_none()
+                            .px_1()
+                            .rounded(theme.radius)
+                            .text_color(theme.muted_foreground)
+                            .cursor_pointer()
+                            .hover(|style| style.bg(theme.muted).text_color(theme.foreground))
+                            .when(show_ring, |button| {
+                                button.shadow(focus_visible::focus_ring(theme.primary))
+                            })
+                            .track_focus(&focus_handle)
+                            .on_key_down(move |event, window, cx| {
+                                if event.keystroke.key == "enter" {
+                                    cx.stop_propagation();
+                                    on_enter(&DismissReason::Button, window, cx);
+                                }
+                            })
+                            .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                                focus_visible::set_modality(InputModality::Pointer, cx);
+                            })
+                            .on_click(move |_, window, cx| {
+                                on_dismiss(&DismissReason::Button, window, cx)
+                            })
+                            .child("✕"),
+                    )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Alert, AlertVariant};
+    use crate::announce::{on_announce, Announcement, Politeness};
+    use gpui::{div, prelude::*, TestAppContext, Window};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    struct Form;
+
+    impl Render for Form {
+        fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+            div().child(
+                Alert::new("error", "Couldn't save")
+                    .variant(AlertVariant::Error)
+                    .description("The name is already taken."),
+            )
+        }
+    }
+
+    #[gpui::test]
+    fn announces_once_when_shown(cx: &mut TestAppContext) {
+        let heard: Rc<RefCell<Vec<Announcement>>> = Rc::default();
+        let listener = heard.clone();
+        cx.update(|cx| {
+            on_announce(cx, move |announcement, _| {
+                listener.borrow_mut().push(announcement.clone())
+            })
+        });
+
+        let (view, cx) = cx.add_window_view(|_, _| Form);
+        cx.run_until_parked();
+        view.update(cx, |_, cx| cx.notify());
+        cx.run_until_parked();
+
+        assert_eq!(
+            *heard.borrow(),
+            [Announcement {
+                message: "Couldn't save. The name is already taken.".into(),
+                politeness: Politeness::Assertive,
+            }]
+        );
+    }
+}
@@ -0,0 +1,71 @@
+// Live-region announcements for screen readers.
+//
+// GPUI does not expose the platform accessibility tree yet, so announcements
+// are collected in a global `LiveRegion` and forwarded to any listeners
+// registered with `on_announce`. Apps bridge these to the platform screen
+// reader (or log them in tests) until GPUI gains native live-region support.
+
+use crate::prelude::*;
+use std::rc::Rc;
+
+/// How urgently assistive technology should read an announcement.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Politeness {
+    /// Read once the user is idle, without interrupting current speech.
+    /// Use for status updates such as "Saved" or a toast appearing.
+    #[default]
+    Polite,
+    /// Interrupt current speech immediately. Reserve for errors and
+    /// time-sensitive alerts.
+    Assertive,
+}
+
+/// A message posted to the live region.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Announcement {
+    pub message: SharedString,
+    pub politeness: Politeness,
+}
+
+type AnnounceListener = Rc<dyn Fn(&Announcement, &mut App)>;
+
+/// Global collection of announcements and the listeners that relay them.
+#[derive(Default)]
+pub struct LiveRegion {
+    last: Option<Announcement>,
+    listeners: Vec<AnnounceListener>,
+}
+
+impl Global for LiveRegion {}
+
+impl LiveRegion {
+    /// Returns the most recent announcement, if any.
+    pub fn last(cx: &App) -> Option<&Announcement> {
+        cx.try_global::<Self>()
+            .and_then(|region| region.last.as_ref())
+    }
+}
+
+/// Posts a message to the live region so screen readers announce it.
+pub fn announce(message: impl Into<SharedString>, politeness: Politeness, cx: &mut App) {
+    let announcement = Announcement {
+        message: message.into(),
+        politeness,
+    };
+
+    let region = cx.default_global::<LiveRegion>();
+    region.last = Some(announcement.clone());
+    let listeners = region.listeners.clone();
+
+    for listener in listeners {
+        listener(&announcement, cx);
+    }
+}
+
+/// Registers a listener that receives every announcement, e.g. to forward it
+/// to the platform screen reader.
+pub fn on_announce(cx: &mut App, listener: impl Fn(&Announcement, &mut App) + 'static) {
+    cx.default_global::<LiveRegion>()
+        .listeners
+        .push(Rc::new(listener));
+}
@@ -0,0 +1,41 @@
+use crate::prelude::*;
+
+/// Sizes a single child to a fixed width:height ratio. The container takes
+/// the available width and layout derives the height from it, so media and
+/// embeds keep their shape as the window resizes. Overflow is clipped.
+#[derive(IntoElement)]
+pub struct AspectRatio {
+    ratio: f32,
+    child: Option<AnyElement>,
+}
+
+impl AspectRatio {
+    /// Creates a container with the given width / height ratio, e.g. `16.0 / 9.0`.
+    pub fn new(ratio: f32) -> Self {
+        Self {
+            ratio: if ratio > 0.0 { ratio } else { 1.0 },
+            child: None,
+        }
+    }
+
+    /// A 1:1 container.
+    pub fn square() -> Self {
+        Self::new(1.0)
+    }
+
+    pub fn child(mut self, child: impl IntoElement) -> Self {
+        self.child = Some(child.into_any_element());
+        self
+    }
+}
+
+impl RenderOnce for AspectRatio {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let mut container = div().relative().w_full().overflow_hidden();
+        container.style().aspect_ratio = Some(self.ratio);
+
+        container.when_some(self.child, |container, child| {
+            container.child(div().absolute().inset_0().size_full().child(child))
+        })
+    }
+}
@@ -1,4 +1,6 @@
+use crate::accessibility::{self, AccessibleNode, Role};
 use crate::prelude::*;
+use crate::truncate::truncate_to_width;
 
 /// Badge variant determines the visual style
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -12,33 +14,78 @@ pub enum BadgeVariant {
     Outline,
 }
 
-/// Badge size options
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub enum BadgeSize {
-    Small,
-    Medium,
-    Large,
+/// Badge size options, drawn from the shared `SizeScale`
+pub type BadgeSize = ComponentSize;
+
+/// Counts above this show as "99+" unless a badge sets its own `max_count`.
+pub const DEFAULT_MAX_COUNT: usize = 99;
+
+/// Formats a notification count, capping it as "{max}+" once it passes `max`.
+pub fn format_count(count: usize, max: usize) -> String {
+    if count > max {
+        format!("{max}+")
+    } else {
+        count.to_string()
+    }
 }
 
 /// A badge component for labels, tags, and status indicators
 #[derive(IntoElement)]
 pub struct Badge {
+    id: Option<ElementId>,
     variant: BadgeVariant,
     size: BadgeSize,
     label: SharedString,
     dot: bool,
+    max_width: Option<Pixels>,
+    truncate: bool,
+    count: Option<usize>,
+    max_count: usize,
+    closable: bool,
+    on_close: Option<Box<dyn Fn(&mut Window, &mut App) + 'static>>,
 }
 
 impl Badge {
     pub fn new(label: impl Into<SharedString>) -> Self {
         Self {
+            id: None,
             variant: BadgeVariant::Default,
             size: BadgeSize::Medium,
             label: label.into(),
             dot: false,
+            max_width: None,
+            truncate: false,
+            count: None,
+            max_count: DEFAULT_MAX_COUNT,
+            closable: false,
+            on_close: None,
         }
     }
 
+    /// Gives the badge an element id. Closable badges need one that is
+    /// unique among their siblings, such as the tag they show. Debug builds
+    /// assert that a closable badge has one; release builds fall back to the
+    /// label, so two closable badges with the same label would share state.
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// A badge showing a number, such as unread notifications. Counts above
+    /// `max_count` (99 by default) show as "99+".
+    pub fn count(count: usize) -> Self {
+        Self {
+            count: Some(count),
+            ..Self::new(count.to_string())
+        }
+    }
+
+    /// The largest count shown in full before switching to "{max}+".
+    pub fn max_count(mut self, max_count: usize) -> Self {
+        self.max_count = max_count;
+        self
+    }
+
     pub fn variant(mut self, variant: BadgeVariant) -> Self {
         self.variant = variant;
         self
@@ -54,58 +101,81 @@ impl Badge {
         self
     }
 
-    fn get_padding(&self) -> (Pixels, Pixels) {
-        match self.size {
-            BadgeSize::Small => (px(4.0), px(2.0)),
-            BadgeSize::Medium => (px(6.0), px(3.0)),
-            BadgeSize::Large => (px(8.0), px(4.0)),
-        }
+    /// Caps the badge width. Labels longer than this wrap onto multiple lines
+    /// instead of stretching the badge.
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.max_width = Some(max_width.into());
+        self
     }
 
-    fn get_text_size(&self) -> Pixels {
-        match self.size {
-            BadgeSize::Small => px(11.0),
-            BadgeSize::Medium => px(12.0),
-            BadgeSize::Large => px(14.0),
-        }
+    /// Shows a "×" after the label that calls `on_close` when clicked, for
+    /// removable tags and filters.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Called when the "×" of a closable badge is clicked. The badge doesn't
+    /// hide itself; remove it from the list it was rendered from.
+    pub fn on_close(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_close = Some(Box::new(handler));
+        self
+    }
+
+    /// With `max_width`, keeps the label on one line and cuts it with "…" at
+    /// the measured width instead of wrapping.
+    pub fn truncate(mut self, truncate: bool) -> Self {
+        self.truncate = truncate;
+        self
+    }
+
+    fn get_padding(&self, preset: &SizePreset) -> (Pixels, Pixels) {
+        (preset.padding / 2.0, preset.padding / 4.0)
+    }
+
+    // Badges sit alongside text, so they run one step below the control text size.
+    fn get_text_size(&self, preset: &SizePreset) -> Pixels {
+        preset.text_size - px(2.0)
     }
 
-    fn get_background_color(&self) -> Rgba {
+    fn get_background_color(&self, theme: &Theme) -> Rgba {
         match self.variant {
-            BadgeVariant::Default => rgb(0xf1f5f9),      // muted
-            BadgeVariant::Primary => rgb(0x3b82f6),      // primary
-            BadgeVariant::Secondary => rgb(0x64748b),    // secondary
-            BadgeVariant::Success => rgb(0x22c55e),      // green
-            BadgeVariant::Warning => rgb(0xf59e0b),      // amber
-            BadgeVariant::Error => rgb(0xef4444),        // red
-            BadgeVariant::Outline => rgb(0xffffff),      // white
+            BadgeVariant::Default => theme.muted,
+            BadgeVariant::Primary => theme.primary,
+            BadgeVariant::Secondary => theme.secondary,
+            BadgeVariant::Success => theme.success,
+            BadgeVariant::Warning => theme.warning,
+            BadgeVariant::Error => theme.destructive,
+            BadgeVariant::Outline => theme.background,
         }
     }
 
-    fn get_text_color(&self) -> Rgba {
+    fn get_text_color(&self, theme: &Theme) -> Rgba {
         match self.variant {
-            BadgeVariant::Default => rgb(0x0f172a),      // dark
+            BadgeVariant::Default => theme.foreground,
             BadgeVariant::Primary | BadgeVariant::Secondary | 
-            BadgeVariant::Success | BadgeVariant::Error => rgb(0xffffff),
-            BadgeVariant::Warning => rgb(0x78350f),      // dark amber
-            BadgeVariant::Outline => rgb(0x0f172a),      // dark
+            BadgeVariant::Success | BadgeVariant::Error => colors::ON_PRIMARY,
+            BadgeVariant::Warning => colors::ON_WARNING,
+            BadgeVariant::Outline => theme.foreground,
         }
     }
 
-    fn get_border_color(&self) -> Option<Rgba> {
+    fn get_border_color(&self, theme: &Theme) -> Option<Rgba> {
         match self.variant {
-            BadgeVariant::Outline => Some(rgb(0xe2e8f0)),
+            BadgeVariant::Outline => Some(theme.border),
             _ => None,
         }
     }
 }
 
 impl RenderOnce for Badge {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
-        let (px_padding, py_padding) = self.get_padding();
-        let bg_color = self.get_background_color();
-        let text_color = self.get_text_color();
-        let border_color = self.get_border_color();
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let preset = self.size.preset(cx);
+        let theme = cx.theme();
+        let (px_padding, py_padding) = self.get_padding(&preset);
+        let bg_color = self.get_background_color(&theme);
+        let text_color = self.get_text_color(&theme);
+        let border_color = self.get_border_color(&theme);
 
         let mut badge = div()
             .flex()
@@ -115,9 +185,10 @@ impl RenderOnce for Badge {
             .py(py_padding)
             .bg(bg_color)
             .text_color(text_color)
-            .text_size(self.get_text_size())
+            .text_size(self.get_text_size(&preset))
             .rounded(px(9999.0)) // fully rounded
-            .font_weight(FontWeight::MEDIUM);
+            .font_weight(FontWeight::MEDIUM)
+            .when_some(self.max_width, |badge, max_width| badge.max_w(max_width));
 
         if let Some(border) = border_color {
             badge = badge.border_1().border_color(border);
@@ -132,8 +203,154 @@ impl RenderOnce for Badge {
             );
         }
 
-        badge = badge.child(self.label.clone());
+        let label: SharedString = match self.count {
+            Some(count) => format_count(count, self.max_count).into(),
+            None => self.label.clone(),
+        };
+        let label = match self.max_width.filter(|_| self.truncate) {
+            Some(max_width) => {
+                // Whatever the padding, border, and dot leave for the label
+                let mut width = max_width - px_padding * 2.0;
+                if border_color.is_some() {
+                    width -= px(2.0);
+                }
+                if self.dot {
+                    width -= px(10.0); // dot plus gap
+                }
+                if self.closable {
+                    width -= self.get_text_size(&preset) + px(4.0); // glyph plus gap
+                }
+                let mut font = window.text_style().font();
+                font.weight = FontWeight::MEDIUM;
+                let text_size = self.get_text_size(&preset);
+                truncate_to_width(label, width, &font, text_size, window)
+            }
+            None => label,
+        };
+
+        let wraps = self.max_width.is_some() && !self.truncate;
+        badge = badge.child(
+            div()
+                .min_w_0()
+                .when(wraps, |label| label.whitespace_normal())
+                .when(!wraps, |label| label.whitespace_nowrap())
+                .child(label),
+        );
 
+        if !self.closable {
+            return badge.into_any_element();
+        }
+
+        debug_assert!(
+            self.id.is_some(),
+            "closable badge {:?} needs a unique .id()",
+            self.label
+        );
+        // The "×" needs a stateful parent to scope its id
+        let id = self
+            .id
+            .unwrap_or_else(|| ElementId::Name(self.label.clone()));
+        // The "×" is the button, not the whole badge
+        accessibility::expose(
+            &ElementId::NamedChild(Box::new(id.clone()), "close".into()),
+            AccessibleNode {
+                role: Role::Button,
+                name: format!("Remove {}", self.label).into(),
+                disabled: false,
+                toggle_state: None,
+            },
+            window,
+            cx,
+        );
+        let on_close = self.on_close;
         badge
+            .id(id)
+            .child(
+                div()
+                    .id("close")
+                    .flex_none()
+                    .text_color(text_color)
+                    .opacity(0.7)
+                    .cursor_pointer()
+                    .hover(|style| style.opacity(1.0))
+                    .on_click(move |_, window, cx| {
+                        // Closing a tag shouldn't also activate whatever holds it
+                        cx.stop_propagation();
+                        if let Some(on_close) = &on_close {
+                            on_close(window, cx);
+                        }
+                    })
+                    .child("×"),
+            )
+            .into_any_element()
+    }
+}
+
+/// Pins a badge to the top-right corner of another element, like an unread
+/// count on an icon button. Build one with `with_badge`.
+///
+/// The badge is drawn outside the element's bounds, so the element itself
+/// must not clip its overflow. A count badge at zero is hidden.
+#[derive(IntoElement)]
+pub struct BadgeAnchor {
+    child: AnyElement,
+    badge: Badge,
+    offset: Pixels,
+}
+
+/// Wraps `child` so `badge` sits over its top-right corner.
+pub fn with_badge(child: impl IntoElement, badge: Badge) -> BadgeAnchor {
+    BadgeAnchor {
+        child: child.into_any_element(),
+        badge,
+        offset: px(6.0),
+    }
+}
+
+impl BadgeAnchor {
+    /// How far the badge overhangs the corner, up and to the right.
+    /// Defaults to 6px.
+    pub fn offset(mut self, offset: impl Into<Pixels>) -> Self {
+        self.offset = offset.into();
+        self
+    }
+}
+
+impl RenderOnce for BadgeAnchor {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = cx.theme();
+        let hidden = self.badge.count == Some(0);
+
+        div()
+            .relative()
+            .flex_none()
+            .child(self.child)
+            .when(!hidden, |anchor| {
+                anchor.child(
+                    // A ring in the background color separates the badge
+                    // from whatever it overlaps.
+                    div()
+                        .absolute()
+                        .top(-self.offset)
+                        .right(-self.offset)
+                        .p(px(2.0))
+                        .rounded(px(9999.0))
+                        .bg(theme.background)
+                        .child(self.badge),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_count;
+
+    #[test]
+    fn test_format_count() {
+        assert_eq!(format_count(0, 99), "0");
+        assert_eq!(format_count(99, 99), "99");
+        assert_eq!(format_count(100, 99), "99+");
+        assert_eq!(format_count(1000, 999), "999+");
     }
 }
@@ -0,0 +1,138 @@
+use crate::prelude::*;
+
+/// Banner variant determines the color of the bar
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum BannerVariant {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+/// A full-width announcement bar, usually pinned to the top of a window,
+/// for messages like "You're offline" or "A new version is available".
+///
+/// Unlike an inline alert it runs edge to edge and has no rounding. The
+/// banner is controlled: it renders while `open` is true and asks the parent
+/// to close it through `on_dismiss`.
+#[derive(IntoElement)]
+pub struct Banner {
+    id: ElementId,
+    open: bool,
+    variant: BannerVariant,
+    message: SharedString,
+    action: Option<(SharedString, Box<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>)>,
+    on_dismiss: Option<Box<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>>,
+}
+
+impl Banner {
+    pub fn new(id: impl Into<ElementId>, message: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            open: true,
+            variant: BannerVariant::default(),
+            message: message.into(),
+            action: None,
+            on_dismiss: None,
+        }
+    }
+
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    pub fn variant(mut self, variant: BannerVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Adds an underlined link after the message, e.g. "Update now".
+    pub fn action(
+        mut self,
+        label: impl Into<SharedString>,
+        handler: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.action = Some((label.into(), Box::new(handler)));
+        self
+    }
+
+    /// Shows a dismiss button at the end of the bar.
+    pub fn on_dismiss(
+        mut self,
+        handler: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_dismiss = Some(Box::new(handler));
+        self
+    }
+
+    fn get_background_color(&self) -> Rgba {
+        match self.variant {
+            BannerVariant::Info => colors::PRIMARY,
+            BannerVariant::Success => colors::SUCCESS,
+            BannerVariant::Warning => colors::WARNING,
+            BannerVariant::Error => colors::DESTRUCTIVE,
+        }
+    }
+
+    fn get_text_color(&self) -> Rgba {
+        match self.variant {
+            BannerVariant::Warning => colors::ON_WARNING,
+            _ => colors::ON_PRIMARY,
+        }
+    }
+}
+
+impl RenderOnce for Banner {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        if !self.open {
+            return div().into_any_element();
+        }
+
+        let preset = ComponentSize::Medium.preset(cx);
+        let text_color = self.get_text_color();
+        let background = self.get_background_color();
+
+        div()
+            .id(self.id)
+            .w_full()
+            .flex()
+            .items_center()
+            .gap_3()
+            .px(preset.padding)
+            .py(preset.padding / 2.0)
+            .bg(background)
+            .text_color(text_color)
+            .text_size(preset.text_size)
+            .child(div().min_w_0().child(self.message))
+            .when_some(self.action, |banner, (label, handler)| {
+                banner.child(
+                    div()
+                        .id("action")
+                        .flex_none()
+                        .font_weight(FontWeight::SEMIBOLD)
+                        .underline()
+                        .cursor_pointer()
+                        .hover(|style| style.opacity(0.8))
+                        .on_click(handler)
+                        .child(label),
+                )
+            })
+            .when_some(self.on_dismiss, |banner, handler| {
+                banner.child(
+                    div()
+                        .id("dismiss")
+                        .flex_none()
+                        .ml_auto()
+                        .px_1()
+                        .rounded(colors::RADIUS)
+                        .cursor_pointer()
+                        .hover(|style| style.bg(hsla(0.0, 0.0, 1.0, 0.2)))
+                        .on_click(handler)
+                        .child("✕"),
+                )
+            })
+            .into_any_element()
+    }
+}
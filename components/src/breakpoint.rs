@@ -0,0 +1,57 @@
+// Window width buckets for responsive layouts.
+//
+// Read `Breakpoint::current(window)` during render and branch on it. GPUI
+// re-renders on resize, so layouts built this way adapt as the window changes.
+
+use gpui::{px, Pixels, Window};
+
+/// Windows narrower than this are `Sm`.
+pub const MD_MIN_WIDTH: Pixels = px(640.0);
+/// Windows at least this wide are `Lg`.
+pub const LG_MIN_WIDTH: Pixels = px(1024.0);
+
+/// How wide the window is, from narrowest to widest. Ordered, so
+/// `breakpoint < Breakpoint::Md` reads as "narrower than medium".
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Breakpoint {
+    /// Under 640px, such as a window snapped to a third of the screen.
+    Sm,
+    /// 640px up to 1024px.
+    Md,
+    /// 1024px and wider.
+    Lg,
+}
+
+impl Breakpoint {
+    /// The bucket a window of this width falls in.
+    pub fn from_width(width: Pixels) -> Self {
+        if width < MD_MIN_WIDTH {
+            Self::Sm
+        } else if width < LG_MIN_WIDTH {
+            Self::Md
+        } else {
+            Self::Lg
+        }
+    }
+
+    /// The bucket the window's current width falls in.
+    pub fn current(window: &Window) -> Self {
+        Self::from_width(window.viewport_size().width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Breakpoint;
+    use gpui::px;
+
+    #[test]
+    fn buckets_widths_at_their_lower_bound() {
+        assert_eq!(Breakpoint::from_width(px(320.0)), Breakpoint::Sm);
+        assert_eq!(Breakpoint::from_width(px(639.0)), Breakpoint::Sm);
+        assert_eq!(Breakpoint::from_width(px(640.0)), Breakpoint::Md);
+        assert_eq!(Breakpoint::from_width(px(1023.0)), Breakpoint::Md);
+        assert_eq!(Breakpoint::from_width(px(1024.0)), Breakpoint::Lg);
+        assert!(Breakpoint::Sm < Breakpoint::Md);
+    }
+}
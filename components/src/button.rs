@@ -1,4 +1,12 @@
+use crate::accessibility::{self, AccessibleNode, Role};
+use crate::focus_visible::{self, InputModality};
 use crate::prelude::*;
+use crate::tooltip::Tooltip;
+use crate::truncate::truncate_in_window_font;
+use std::time::Duration;
+
+/// Frames of the spinner shown while a button is loading.
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
 
 /// Button variant determines the visual style
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -10,13 +18,8 @@ pub enum ButtonVariant {
     Link,
 }
 
-/// Button size options
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub enum ButtonSize {
-    Small,
-    Medium,
-    Large,
-}
+/// Button size options, drawn from the shared `SizeScale`
+pub type ButtonSize = ComponentSize;
 
 /// A customizable button component
 #[derive(IntoElement)]
@@ -25,8 +28,21 @@ pub struct Button {
     variant: ButtonVariant,
     size: ButtonSize,
     disabled: bool,
+    disabled_reason: Option<SharedString>,
     label: SharedString,
+    icon_left: Option<AnyElement>,
+    icon_right: Option<AnyElement>,
+    loading: bool,
+    text_align: TextAlign,
+    max_width: Option<Pixels>,
+    truncate: bool,
+    rounded: bool,
+    tooltip: Option<Tooltip>,
+    accessible_name: Option<SharedString>,
+    pressed: Option<ToggleState>,
+    focus_handle: Option<FocusHandle>,
     on_click: Option<Box<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>>,
+    on_hover: Option<Box<dyn Fn(bool, &mut Window, &mut App) + 'static>>,
 }
 
 impl Button {
@@ -36,11 +52,37 @@ impl Button {
             variant: ButtonVariant::Default,
             size: ButtonSize::Medium,
             disabled: false,
+            disabled_reason: None,
             label: label.into(),
+            icon_left: None,
+            icon_right: None,
+            loading: false,
+            text_align: TextAlign::Center,
+            max_width: None,
+            truncate: false,
+            rounded: true,
+            tooltip: None,
+            accessible_name: None,
+            pressed: None,
+            focus_handle: None,
             on_click: None,
+            on_hover: None,
         }
     }
 
+    /// An icon-only button with no label. It renders square, and should be
+    /// given an `accessible_name` or `tooltip` so assistive tech can name it.
+    pub fn icon(id: impl Into<ElementId>, icon: impl IntoElement) -> Self {
+        Self::new(id, "").icon_left(icon)
+    }
+
+    /// Replaces the id given to `new`, e.g. for a button built by a shared
+    /// helper that each caller needs to tell apart.
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = id.into();
+        self
+    }
+
     pub fn variant(mut self, variant: ButtonVariant) -> Self {
         self.variant = variant;
         self
@@ -56,6 +98,80 @@ impl Button {
         self
     }
 
+    /// Shows an icon before the label.
+    pub fn icon_left(mut self, icon: impl IntoElement) -> Self {
+        self.icon_left = Some(icon.into_any_element());
+        self
+    }
+
+    /// Shows an icon after the label.
+    pub fn icon_right(mut self, icon: impl IntoElement) -> Self {
+        self.icon_right = Some(icon.into_any_element());
+        self
+    }
+
+    /// Shows a spinner in place of the left icon, e.g. while the action the
+    /// button started is running. A loading button ignores clicks.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Sets the horizontal alignment of the label within the button.
+    pub fn text_align(mut self, text_align: TextAlign) -> Self {
+        self.text_align = text_align;
+        self
+    }
+
+    /// Caps the button width. Labels longer than this wrap onto multiple lines
+    /// instead of forcing the button wider.
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.max_width = Some(max_width.into());
+        self
+    }
+
+    /// With `max_width`, keeps the label on one line and cuts it with "…" at
+    /// the measured width instead of wrapping. The full label is shown in a
+    /// tooltip unless one is set.
+    pub fn truncate(mut self, truncate: bool) -> Self {
+        self.truncate = truncate;
+        self
+    }
+
+    /// Rounds the button corners. Grouped controls such as `InputGroup` turn
+    /// this off so adjacent edges meet flush.
+    pub fn rounded(mut self, rounded: bool) -> Self {
+        self.rounded = rounded;
+        self
+    }
+
+    /// Makes the button focusable with the given handle. A focus ring shows
+    /// when focus arrives by keyboard, not when the button is clicked.
+    pub fn track_focus(mut self, handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(handle.clone());
+        self
+    }
+
+    /// Shows a tooltip while the enabled button is hovered.
+    pub fn tooltip(mut self, text: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(Tooltip::new(text));
+        self
+    }
+
+    /// Like `tooltip`, but takes a configured `Tooltip` for per-button delay,
+    /// placement, or a secondary line.
+    pub fn tooltip_with(mut self, tooltip: Tooltip) -> Self {
+        self.tooltip = Some(tooltip);
+        self
+    }
+
+    /// Exposes the button as a toggle in the given state, for wrappers
+    /// such as `ToggleButton`.
+    pub fn pressed(mut self, state: ToggleState) -> Self {
+        self.pressed = Some(state);
+        self
+    }
+
     pub fn on_click(
         mut self,
         handler: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
@@ -64,43 +180,35 @@ impl Button {
         self
     }
 
-    fn get_padding(&self) -> Pixels {
-        match self.size {
-            ButtonSize::Small => px(8.0),
-            ButtonSize::Medium => px(12.0),
-            ButtonSize::Large => px(16.0),
-        }
-    }
-
-    fn get_background_color(&self) -> Rgba {
+    fn get_background_color(&self, theme: &Theme) -> Rgba {
         if self.disabled {
-            return rgb(0xf1f5f9); // muted
+            return theme.muted;
         }
 
         match self.variant {
-            ButtonVariant::Default => rgb(0x3b82f6),      // primary
-            ButtonVariant::Destructive => rgb(0xef4444),  // destructive
-            ButtonVariant::Outline => rgb(0xffffff),      // white
+            ButtonVariant::Default => theme.primary,
+            ButtonVariant::Destructive => theme.destructive,
+            ButtonVariant::Outline => theme.background,
             ButtonVariant::Ghost => rgba(0x00000000),     // fully transparent
             ButtonVariant::Link => rgba(0x00000000),      // fully transparent
         }
     }
 
-    fn get_text_color(&self) -> Rgba {
+    fn get_text_color(&self, theme: &Theme) -> Rgba {
         if self.disabled {
-            return rgb(0x94a3b8); // gray
+            return colors::DISABLED_FOREGROUND;
         }
 
         match self.variant {
-            ButtonVariant::Default | ButtonVariant::Destructive => rgb(0xffffff),
-            ButtonVariant::Outline | ButtonVariant::Ghost => rgb(0x0f172a),
-            ButtonVariant::Link => rgb(0x3b82f6),
+            ButtonVariant::Default | ButtonVariant::Destructive => colors::ON_PRIMARY,
+            ButtonVariant::Outline | ButtonVariant::Ghost => theme.foreground,
+            ButtonVariant::Link => theme.primary,
         }
     }
 
-    fn get_border_color(&self) -> Option<Rgba> {
+    fn get_border_color(&self, theme: &Theme) -> Option<Rgba> {
         match self.variant {
-            ButtonVariant::Outline => Some(rgb(0xe2e8f0)),
+            ButtonVariant::Outline => Some(theme.border),
             _ => None,
         }
     }
@@ -115,55 +223,205 @@ impl Clickable for Button {
     }
 }
 
+impl Hoverable for Button {
+    fn on_hover(mut self, handler: impl Fn(bool, &mut Window, &mut App) + 'static) -> Self {
+        self.on_hover = Some(Box::new(handler));
+        self
+    }
+}
+
 impl Disableable for Button {
     fn disabled(self, disabled: bool) -> Self {
         Self { disabled, ..self }
     }
+
+    fn disabled_reason(mut self, reason: impl Into<SharedString>) -> Self {
+        self.disabled_reason = Some(reason.into());
+        self
+    }
+}
+
+impl Accessible for Button {
+    fn accessible_name(mut self, name: impl Into<SharedString>) -> Self {
+        self.accessible_name = Some(name.into());
+        self
+    }
 }
 
 impl RenderOnce for Button {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
-        let padding = self.get_padding();
-        let bg_color = self.get_background_color();
-        let text_color = self.get_text_color();
-        let border_color = self.get_border_color();
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        focus_visible::init(cx);
+        let preset = self.size.preset(cx);
+        let theme = cx.theme();
+        let bg_color = self.get_background_color(&theme);
+        let text_color = self.get_text_color(&theme);
+        let border_color = self.get_border_color(&theme);
+        let text_align = self.text_align;
+        // Loading buttons keep their colors but take no clicks
+        let inert = self.disabled || self.loading;
+        let icon_only = self.label.is_empty();
+
+        let name = accessibility::accessible_name(
+            self.accessible_name.as_ref(),
+            &self.label,
+            self.tooltip.as_ref().map(Tooltip::title),
+        );
+        accessibility::expose(
+            &self.id,
+            AccessibleNode {
+                role: if self.pressed.is_some() {
+                    Role::ToggleButton
+                } else {
+                    Role::Button
+                },
+                name,
+                disabled: inert,
+                toggle_state: self.pressed,
+            },
+            window,
+            cx,
+        );
+
+        let truncate_width = self.max_width.filter(|_| self.truncate).map(|max_width| {
+            let border = if border_color.is_some() { px(2.0) } else { px(0.0) };
+            let icons = [self.loading || self.icon_left.is_some(), self.icon_right.is_some()];
+            let icon_width = preset.text_size + preset.padding;
+            let icon_count = icons.iter().filter(|shown| **shown).count() as f32;
+            max_width - preset.padding * 4.0 - border - icon_width * icon_count
+        });
+        let mut tooltip = self.tooltip;
+        let label_text = match truncate_width {
+            Some(width) => {
+                let truncated =
+                    truncate_in_window_font(self.label.clone(), width, preset.text_size, window);
+                if truncated != self.label && tooltip.is_none() {
+                    tooltip = Some(Tooltip::new(self.label.clone()));
+                }
+                truncated
+            }
+            None => self.label.clone(),
+        };
+
+        let label = div()
+            .min_w_0()
+            .map(|label| match text_align {
+                TextAlign::Left => label.text_left(),
+                TextAlign::Center => label.text_center(),
+                TextAlign::Right => label.text_right(),
+            })
+            .map(|label| match self.max_width {
+                Some(_) if !self.truncate => label.whitespace_normal(),
+                _ => label.whitespace_nowrap(),
+            })
+            .child(label_text);
+
+        let icon_left = if self.loading {
+            Some(
+                div()
+                    .flex_none()
+                    .with_animation(
+                        "spinner",
+                        Animation::new(Duration::from_millis(800)).repeat(),
+                        |spinner, delta| {
+                            let frame = (delta * SPINNER_FRAMES.len() as f32) as usize;
+                            spinner.child(SPINNER_FRAMES[frame.min(SPINNER_FRAMES.len() - 1)])
+                        },
+                    )
+                    .into_any_element(),
+            )
+        } else {
+            self.icon_left
+        };
 
         let mut button = div()
             .id(self.id)
             .flex()
             .items_center()
-            .justify_center()
-            .px(padding * 2.0)
-            .py(padding)
+            .gap(preset.padding)
+            .map(|button| match text_align {
+                _ if icon_only => button.justify_center(),
+                TextAlign::Left => button.justify_start(),
+                TextAlign::Center => button.justify_center(),
+                TextAlign::Right => button.justify_end(),
+            })
+            .when_some(self.max_width, |button, max_width| button.max_w(max_width))
+            .min_h(preset.min_height)
+            // Icon-only buttons are as wide as they are tall
+            .map(|button| {
+                if icon_only {
+                    button.min_w(preset.min_height).px(preset.padding / 2.0)
+                } else {
+                    button.px(preset.padding * 2.0)
+                }
+            })
+            .py(preset.padding / 2.0)
             .bg(bg_color)
             .text_color(text_color)
-            .rounded(px(4.0))
-            .child(self.label.clone());
+            .text_size(preset.text_size)
+            .when(self.rounded, |button| button.rounded(preset.radius))
+            .when_some(icon_left, |button, icon| button.child(icon))
+            .when(!icon_only, |button| button.child(label))
+            .when_some(self.icon_right, |button, icon| button.child(icon));
 
         if let Some(border) = border_color {
             button = button.border_1().border_color(border);
         }
 
-        if !self.disabled {
+        if let Some(handle) = &self.focus_handle {
+            let show_ring = !inert && focus_visible::is_focus_visible(handle, window, cx);
+            button = button
+                .track_focus(handle)
+                .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                    focus_visible::set_modality(InputModality::Pointer, cx)
+                })
+                .when(show_ring, |button| {
+                    button.shadow(focus_visible::focus_ring(theme.primary))
+                });
+        }
+
+        if let Some(handler) = self.on_hover {
+            button = button.on_hover(move |hovered, window, cx| handler(*hovered, window, cx));
+        }
+
+        if !inert {
             button = button
                 .cursor_pointer()
                 .hover(|style| {
                     // Ghost and Link buttons get a light background on hover
                     match self.variant {
                         ButtonVariant::Ghost | ButtonVariant::Link => {
-                            style.bg(rgb(0xf1f5f9)) // slate-100
+                            style.bg(theme.muted)
                         }
                         _ => style.opacity(0.9)
                     }
                 });
                 
             if let Some(handler) = self.on_click {
+                // Handled here, so a clickable parent such as a `Card` doesn't
+                // also fire.
                 button = button.on_click(move |event, window, cx| {
                     handler(event, window, cx);
+                    cx.stop_propagation();
                 });
             }
+
+            if let Some(tooltip) = tooltip {
+                button = button.tooltip(tooltip.builder());
+            }
         } else {
-            button = button.cursor_not_allowed();
+            // Swallow clicks so they don't fall through to a clickable parent.
+            button = button
+                .when(self.disabled, |button| button.cursor_not_allowed())
+                .on_click(|_, _, cx| cx.stop_propagation());
+
+            if self.disabled {
+                if let Some(reason) = self.disabled_reason {
+                    button = button.tooltip(Tooltip::text(reason));
+                }
+            } else if let Some(tooltip) = tooltip {
+                // Only loading, so the usual tooltip still applies
+                button = button.tooltip(tooltip.builder());
+            }
         }
 
         button
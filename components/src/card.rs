@@ -1,3 +1,4 @@
+use crate::collapsible::Collapsible;
 use crate::prelude::*;
 
 /// Card variant determines the visual style
@@ -41,7 +42,8 @@ impl Default for CardHeader {
 }
 
 impl RenderOnce for CardHeader {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = cx.theme();
         let mut header = div()
             .flex()
             .flex_col()
@@ -53,7 +55,7 @@ impl RenderOnce for CardHeader {
                 div()
                     .text_xl()
                     .font_weight(FontWeight::SEMIBOLD)
-                    .text_color(rgb(0x0f172a))
+                    .text_color(theme.foreground)
                     .child(title)
             );
         }
@@ -62,7 +64,7 @@ impl RenderOnce for CardHeader {
             header = header.child(
                 div()
                     .text_sm()
-                    .text_color(rgb(0x64748b))
+                    .text_color(theme.muted_foreground)
                     .child(description)
             );
         }
@@ -155,18 +157,36 @@ impl RenderOnce for CardFooter {
 /// A card container component
 #[derive(IntoElement)]
 pub struct Card {
+    id: Option<ElementId>,
     variant: CardVariant,
     children: Vec<AnyElement>,
+    header_index: Option<usize>,
+    collapsible: Option<(ElementId, bool)>,
+    on_toggle: Option<Box<dyn Fn(&bool, &mut Window, &mut App) + 'static>>,
+    on_click: Option<Box<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>>,
+    on_hover: Option<Box<dyn Fn(bool, &mut Window, &mut App) + 'static>>,
 }
 
 impl Card {
     pub fn new() -> Self {
         Self {
+            id: None,
             variant: CardVariant::Outlined,
             children: Vec::new(),
+            header_index: None,
+            collapsible: None,
+            on_toggle: None,
+            on_click: None,
+            on_hover: None,
         }
     }
 
+    /// Gives the card an element id. Required for `on_click` and `on_hover`.
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     pub fn variant(mut self, variant: CardVariant) -> Self {
         self.variant = variant;
         self
@@ -178,6 +198,7 @@ impl Card {
     }
 
     pub fn header(mut self, header: CardHeader) -> Self {
+        self.header_index = Some(self.children.len());
         self.children.push(header.into_any_element());
         self
     }
@@ -192,18 +213,29 @@ impl Card {
         self
     }
 
-    fn get_styles(&self) -> (Rgba, Option<Rgba>) {
+    /// Makes the card collapsible. The element passed to `.header()` becomes
+    /// the trigger; everything else collapses beneath it.
+    pub fn collapsible(mut self, id: impl Into<ElementId>, open: bool) -> Self {
+        self.collapsible = Some((id.into(), open));
+        self
+    }
+
+    /// Called with the requested open state when a collapsible card's header
+    /// is clicked.
+    pub fn on_toggle(mut self, handler: impl Fn(&bool, &mut Window, &mut App) + 'static) -> Self {
+        self.on_toggle = Some(Box::new(handler));
+        self
+    }
+
+    fn get_styles(&self, theme: &Theme) -> (Rgba, Option<Rgba>) {
         match self.variant {
-            CardVariant::Elevated => (
-                rgb(0xffffff),
-                Some(rgb(0xe5e7eb)), // Border to approximate shadow
-            ),
+            CardVariant::Elevated => (theme.background, None),
             CardVariant::Outlined => (
-                rgb(0xffffff),
-                Some(rgb(0xe2e8f0)),
+                theme.background,
+                Some(theme.border),
             ),
             CardVariant::Filled => (
-                rgb(0xf8fafc),
+                colors::SURFACE,
                 None,
             ),
         }
@@ -216,22 +248,75 @@ impl Default for Card {
     }
 }
 
+impl Clickable for Card {
+    /// Makes the whole card clickable. Like hovering, this needs an `.id()`.
+    /// Clicks on buttons, checkboxes, and chips inside the card are handled
+    /// by those controls and don't reach this handler.
+    fn on_click(mut self, handler: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static) -> Self {
+        self.on_click = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Hoverable for Card {
+    /// Hover tracking needs a stateful element, so the handler only fires
+    /// on cards given an `.id()`.
+    fn on_hover(mut self, handler: impl Fn(bool, &mut Window, &mut App) + 'static) -> Self {
+        self.on_hover = Some(Box::new(handler));
+        self
+    }
+}
+
 impl RenderOnce for Card {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
-        let (bg_color, border_color) = self.get_styles();
+    fn render(mut self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let (bg_color, border_color) = self.get_styles(&cx.theme());
 
-        let mut card = div()
+        let card = div()
             .flex()
             .flex_col()
             .bg(bg_color)
             .rounded(px(8.0))
+            // Only clips the children; the card's own shadow still paints
+            // outside its bounds
             .overflow_hidden()
-            .children(self.children);
+            .when(self.variant == CardVariant::Elevated, |card| {
+                card.shadow_md()
+            });
+
+        let mut card = match self.collapsible {
+            Some((id, open)) => {
+                let header = self
+                    .header_index
+                    .map(|index| self.children.remove(index));
+                let mut collapsible = Collapsible::new(id, open)
+                    .children(self.children);
+                if let Some(header) = header {
+                    collapsible = collapsible.trigger(header);
+                }
+                if let Some(on_toggle) = self.on_toggle {
+                    collapsible = collapsible.on_toggle(on_toggle);
+                }
+                card.child(div().pl_4().child(collapsible))
+            }
+            None => card.children(self.children),
+        };
 
         if let Some(border) = border_color {
             card = card.border_1().border_color(border);
         }
 
-        card
+        match self.id {
+            Some(id) => card
+                .id(id)
+                .when_some(self.on_hover, |card, handler| {
+                    card.on_hover(move |hovered, window, cx| handler(*hovered, window, cx))
+                })
+                .when_some(self.on_click, |card, handler| {
+                    card.cursor_pointer()
+                        .on_click(move |event, window, cx| handler(event, window, cx))
+                })
+                .into_any_element(),
+            None => card.into_any_element(),
+        }
     }
 }
@@ -0,0 +1,129 @@
+use crate::prelude::*;
+
+/// Lays out children in equal-width columns whose count follows the window
+/// `Breakpoint`.
+///
+/// By default children fill rows left to right and each row is as tall as
+/// its tallest child. With `masonry`, each column stacks its children
+/// independently so cards of different heights pack without gaps. Any
+/// element can be a child, not only `Card`s.
+#[derive(IntoElement)]
+pub struct CardGrid {
+    sm_columns: usize,
+    md_columns: usize,
+    lg_columns: usize,
+    gap: Pixels,
+    masonry: bool,
+    children: Vec<AnyElement>,
+}
+
+impl CardGrid {
+    pub fn new() -> Self {
+        Self {
+            sm_columns: 1,
+            md_columns: 2,
+            lg_columns: 3,
+            gap: px(16.0),
+            masonry: false,
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the column count for small, medium, and large windows. Defaults
+    /// to 1, 2, and 3. Counts of 0 are treated as 1.
+    pub fn columns(mut self, sm: usize, md: usize, lg: usize) -> Self {
+        self.sm_columns = sm.max(1);
+        self.md_columns = md.max(1);
+        self.lg_columns = lg.max(1);
+        self
+    }
+
+    /// Space between columns and between rows. Defaults to 16px.
+    pub fn gap(mut self, gap: impl Into<Pixels>) -> Self {
+        self.gap = gap.into();
+        self
+    }
+
+    /// Stacks each column independently instead of aligning rows. Children
+    /// are dealt across the columns in order, so reading order still runs
+    /// left to right.
+    pub fn masonry(mut self, masonry: bool) -> Self {
+        self.masonry = masonry;
+        self
+    }
+
+    pub fn child(mut self, child: impl IntoElement) -> Self {
+        self.children.push(child.into_any_element());
+        self
+    }
+
+    pub fn children(mut self, children: impl IntoIterator<Item = impl IntoElement>) -> Self {
+        self.children.extend(children.into_iter().map(|c| c.into_any_element()));
+        self
+    }
+
+    fn column_count(&self, breakpoint: Breakpoint) -> usize {
+        match breakpoint {
+            Breakpoint::Sm => self.sm_columns,
+            Breakpoint::Md => self.md_columns,
+            Breakpoint::Lg => self.lg_columns,
+        }
+    }
+}
+
+impl Default for CardGrid {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Deals `items` across `columns` columns round-robin, for masonry layout.
+fn deal<T>(items: Vec<T>, columns: usize) -> Vec<Vec<T>> {
+    let columns = columns.max(1);
+    let mut dealt: Vec<Vec<T>> = (0..columns).map(|_| Vec::new()).collect();
+    for (index, item) in items.into_iter().enumerate() {
+        dealt[index % columns].push(item);
+    }
+    dealt
+}
+
+impl RenderOnce for CardGrid {
+    fn render(self, window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let columns = self.column_count(Breakpoint::current(window));
+        let gap = self.gap;
+        let cell = || div().flex_1().min_w_0().flex().flex_col();
+
+        if self.masonry {
+            let stacks = deal(self.children, columns)
+                .into_iter()
+                .map(|stack| cell().gap(gap).children(stack));
+            return div().flex().items_start().gap(gap).children(stacks);
+        }
+
+        let mut rows = Vec::new();
+        let mut children = self.children.into_iter().peekable();
+        while children.peek().is_some() {
+            let mut row: Vec<Div> = children
+                .by_ref()
+                .take(columns)
+                .map(|child| cell().child(child))
+                .collect();
+            // Pad the last row so its cells keep the same width as the rest
+            row.resize_with(columns, cell);
+            rows.push(div().flex().gap(gap).children(row));
+        }
+        div().flex().flex_col().gap(gap).children(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::deal;
+
+    #[test]
+    fn masonry_deals_children_round_robin() {
+        assert_eq!(deal(vec![0, 1, 2, 3, 4], 3), vec![vec![0, 3], vec![1, 4], vec![2]]);
+        assert_eq!(deal(vec![0, 1], 3), vec![vec![0], vec![1], vec![]]);
+        assert_eq!(deal(vec![0, 1, 2], 0), vec![vec![0, 1, 2]]);
+    }
+}
@@ -0,0 +1,189 @@
+use crate::button::{Button, ButtonSize, ButtonVariant};
+use crate::prelude::*;
+use std::rc::Rc;
+use std::time::Duration;
+
+type ChangeHandler = Rc<dyn Fn(&usize, &mut Window, &mut App) + 'static>;
+
+/// The pending auto-advance, tagged with the slide it was started on.
+/// Replacing it drops the old task, which cancels it.
+struct CarouselState {
+    timer: Option<(usize, Task<()>)>,
+}
+
+/// Shows one slide at a time with previous/next buttons and dot indicators.
+///
+/// The carousel is controlled: the parent owns the active index and updates
+/// it from `on_change`, which also drives auto-advance.
+#[derive(IntoElement)]
+pub struct Carousel {
+    id: ElementId,
+    slides: Vec<AnyElement>,
+    active: usize,
+    wrap_around: bool,
+    auto_advance: Option<Duration>,
+    on_change: Option<ChangeHandler>,
+}
+
+impl Carousel {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            slides: Vec::new(),
+            active: 0,
+            wrap_around: false,
+            auto_advance: None,
+            on_change: None,
+        }
+    }
+
+    pub fn slide(mut self, slide: impl IntoElement) -> Self {
+        self.slides.push(slide.into_any_element());
+        self
+    }
+
+    pub fn slides(mut self, slides: impl IntoIterator<Item = impl IntoElement>) -> Self {
+        self.slides
+            .extend(slides.into_iter().map(|slide| slide.into_any_element()));
+        self
+    }
+
+    /// The index of the slide to show.
+    pub fn active(mut self, index: usize) -> Self {
+        self.active = index;
+        self
+    }
+
+    /// Wraps from the last slide to the first and back. Off by default, in
+    /// which case the buttons at either end are disabled.
+    pub fn r#loop(mut self, wrap_around: bool) -> Self {
+        self.wrap_around = wrap_around;
+        self
+    }
+
+    /// Moves to the next slide after `interval`. The timer restarts whenever
+    /// the active slide changes, and stops on the last slide unless looping.
+    pub fn auto_advance(mut self, interval: Duration) -> Self {
+        self.auto_advance = Some(interval);
+        self
+    }
+
+    /// Called with the index of the slide to show.
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(&usize, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+}
+
+/// The slide before or after `active`, or `None` at an end when not looping.
+fn step(active: usize, count: usize, forward: bool, wrap_around: bool) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+    match (forward, wrap_around) {
+        (true, _) if active + 1 < count => Some(active + 1),
+        (true, true) => Some(0),
+        (false, _) if active > 0 => Some(active - 1),
+        (false, true) => Some(count - 1),
+        _ => None,
+    }
+}
+
+impl RenderOnce for Carousel {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let count = self.slides.len();
+        let active = self.active.min(count.saturating_sub(1));
+        let previous = step(active, count, false, self.wrap_around);
+        let next = step(active, count, true, self.wrap_around);
+
+        let state =
+            window.use_keyed_state(self.id.clone(), cx, |_, _| CarouselState { timer: None });
+
+        // (Re)start the auto-advance timer when the active slide changes
+        match (self.auto_advance, next, self.on_change.clone()) {
+            (Some(interval), Some(next), Some(on_change)) => {
+                let scheduled = state.read(cx).timer.as_ref().map(|(index, _)| *index);
+                if scheduled != Some(active) {
+                    let task = window.spawn(cx, async move |cx| {
+                        cx.background_executor().timer(interval).await;
+                        cx.update(|window, cx| on_change(&next, window, cx)).ok();
+                    });
+                    state.update(cx, |state, _| state.timer = Some((active, task)));
+                }
+            }
+            _ => state.update(cx, |state, _| state.timer = None),
+        }
+
+        let nav_button = |id: &'static str, label: &'static str, target: Option<usize>| {
+            let on_change = self.on_change.clone();
+            Button::new(id, label)
+                .variant(ButtonVariant::Outline)
+                .size(ButtonSize::Small)
+                .disabled(target.is_none())
+                .on_click(move |_, window, cx| {
+                    if let (Some(target), Some(on_change)) = (target, &on_change) {
+                        on_change(&target, window, cx);
+                    }
+                })
+        };
+
+        let dots = (0..count).map(|index| {
+            let on_change = self.on_change.clone();
+            div()
+                .id(("dot", index))
+                .size(px(6.0))
+                .rounded(px(9999.0))
+                .bg(if index == active {
+                    colors::FOREGROUND
+                } else {
+                    colors::BORDER
+                })
+                .when(index != active, |dot| {
+                    dot.cursor_pointer()
+                        .hover(|style| style.bg(colors::MUTED_FOREGROUND))
+                        .on_click(move |_, window, cx| {
+                            if let Some(on_change) = &on_change {
+                                on_change(&index, window, cx);
+                            }
+                        })
+                })
+        });
+
+        let slide = self.slides.into_iter().nth(active);
+
+        div()
+            .id(self.id)
+            .flex()
+            .flex_col()
+            .gap_3()
+            .w_full()
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(nav_button("previous", "‹", previous))
+                    .child(div().flex_1().min_w_0().overflow_hidden().children(slide))
+                    .child(nav_button("next", "›", next)),
+            )
+            .child(div().flex().justify_center().gap_2().children(dots))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::step;
+
+    #[test]
+    fn step_stops_at_the_ends_unless_looping() {
+        assert_eq!(step(0, 3, true, false), Some(1));
+        assert_eq!(step(2, 3, true, false), None);
+        assert_eq!(step(2, 3, true, true), Some(0));
+        assert_eq!(step(0, 3, false, false), None);
+        assert_eq!(step(0, 3, false, true), Some(2));
+        assert_eq!(step(0, 0, true, true), None);
+    }
+}
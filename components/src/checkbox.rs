@@ -1,12 +1,10 @@
+use crate::accessibility::{self, AccessibleNode, Role};
+use crate::focus_visible::{self, InputModality};
 use crate::prelude::*;
+use crate::tooltip::Tooltip;
 
-/// Checkbox size options
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub enum CheckboxSize {
-    Small,
-    Medium,
-    Large,
-}
+/// Checkbox size options, drawn from the shared `SizeScale`
+pub type CheckboxSize = ComponentSize;
 
 /// A checkbox input component
 #[derive(IntoElement)]
@@ -15,7 +13,12 @@ pub struct Checkbox {
     state: ToggleState,
     size: CheckboxSize,
     label: Option<SharedString>,
+    description: Option<SharedString>,
+    max_width: Option<Pixels>,
     disabled: bool,
+    disabled_reason: Option<SharedString>,
+    accessible_name: Option<SharedString>,
+    focus_handle: Option<FocusHandle>,
     on_click: Option<Box<dyn Fn(&ToggleState, &mut Window, &mut App) + 'static>>,
 }
 
@@ -26,7 +29,12 @@ impl Checkbox {
             state,
             size: CheckboxSize::Medium,
             label: None,
+            description: None,
+            max_width: None,
             disabled: false,
+            disabled_reason: None,
+            accessible_name: None,
+            focus_handle: None,
             on_click: None,
         }
     }
@@ -45,6 +53,26 @@ impl Checkbox {
         self
     }
 
+    /// Secondary text shown under the label.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Caps the width of the label and description so long text wraps. The
+    /// box stays aligned with the first line.
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.max_width = Some(max_width.into());
+        self
+    }
+
+    /// Makes the checkbox focusable with the given handle. A focus ring shows
+    /// around the box when focus arrives by keyboard.
+    pub fn track_focus(mut self, handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(handle.clone());
+        self
+    }
+
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
@@ -58,51 +86,45 @@ impl Checkbox {
         self
     }
 
-    fn get_box_size(&self) -> Pixels {
-        match self.size {
-            CheckboxSize::Small => px(16.0),
-            CheckboxSize::Medium => px(20.0),
-            CheckboxSize::Large => px(24.0),
-        }
+    fn get_box_size(&self, preset: &SizePreset) -> Pixels {
+        preset.icon_size + px(4.0)
     }
 
-    fn get_icon_size(&self) -> Pixels {
-        match self.size {
-            CheckboxSize::Small => px(10.0),
-            CheckboxSize::Medium => px(12.0),
-            CheckboxSize::Large => px(14.0),
-        }
+    fn get_icon_size(&self, preset: &SizePreset) -> Pixels {
+        preset.text_size - px(2.0)
+    }
+
+    fn get_line_height(&self, preset: &SizePreset) -> Pixels {
+        preset.text_size * 1.5
     }
 
-    fn get_background_color(&self) -> Rgba {
+    fn get_background_color(&self, theme: &Theme) -> Rgba {
         if self.disabled {
-            return rgb(0xf1f5f9);
+            return theme.muted;
         }
 
         match self.state {
-            ToggleState::Unselected => rgb(0xffffff),
-            ToggleState::Selected | ToggleState::Indeterminate => rgb(0x3b82f6),
+            ToggleState::Unselected => theme.background,
+            ToggleState::Selected | ToggleState::Indeterminate => theme.primary,
         }
     }
 
-    fn get_border_color(&self) -> Rgba {
+    fn get_border_color(&self, theme: &Theme) -> Rgba {
         if self.disabled {
-            return rgb(0xe2e8f0);
+            return theme.border;
         }
 
         match self.state {
-            ToggleState::Unselected => rgb(0xd1d5db),
-            ToggleState::Selected | ToggleState::Indeterminate => rgb(0x3b82f6),
+            ToggleState::Unselected => colors::INPUT_BORDER,
+            ToggleState::Selected | ToggleState::Indeterminate => theme.primary,
         }
     }
 
-    fn render_icon(&self) -> Option<Div> {
+    fn render_icon(&self, icon_size: Pixels) -> Option<Div> {
         if self.disabled {
             return None;
         }
 
-        let icon_size = self.get_icon_size();
-
         match self.state {
             ToggleState::Unselected => None,
             ToggleState::Selected => Some(
@@ -112,7 +134,7 @@ impl Checkbox {
                     .items_center()
                     .justify_center()
                     .size_full()
-                    .text_color(rgb(0xffffff))
+                    .text_color(colors::ON_PRIMARY)
                     .font_weight(FontWeight::BOLD)
                     .text_size(icon_size)
                     .child("✓")
@@ -124,7 +146,7 @@ impl Checkbox {
                     .items_center()
                     .justify_center()
                     .size_full()
-                    .text_color(rgb(0xffffff))
+                    .text_color(colors::ON_PRIMARY)
                     .font_weight(FontWeight::BOLD)
                     .text_size(icon_size)
                     .child("−")
@@ -144,13 +166,49 @@ impl Disableable for Checkbox {
     fn disabled(self, disabled: bool) -> Self {
         Self { disabled, ..self }
     }
+
+    fn disabled_reason(mut self, reason: impl Into<SharedString>) -> Self {
+        self.disabled_reason = Some(reason.into());
+        self
+    }
+}
+
+impl Accessible for Checkbox {
+    fn accessible_name(mut self, name: impl Into<SharedString>) -> Self {
+        self.accessible_name = Some(name.into());
+        self
+    }
 }
 
 impl RenderOnce for Checkbox {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
-        let box_size = self.get_box_size();
-        let bg_color = self.get_background_color();
-        let border_color = self.get_border_color();
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        focus_visible::init(cx);
+        accessibility::expose(
+            &self.id,
+            AccessibleNode {
+                role: Role::Checkbox,
+                name: accessibility::accessible_name(
+                    self.accessible_name.as_ref(),
+                    self.label.as_ref().map_or("", |label| label.as_ref()),
+                    None,
+                ),
+                disabled: self.disabled,
+                toggle_state: Some(self.state),
+            },
+            window,
+            cx,
+        );
+        let preset = self.size.preset(cx);
+        let theme = cx.theme();
+        let show_ring = !self.disabled
+            && self
+                .focus_handle
+                .as_ref()
+                .is_some_and(|handle| focus_visible::is_focus_visible(handle, window, cx));
+        let box_size = self.get_box_size(&preset);
+        let icon_size = self.get_icon_size(&preset);
+        let bg_color = self.get_background_color(&theme);
+        let border_color = self.get_border_color(&theme);
 
         let checkbox_box = div()
             .relative()
@@ -161,20 +219,41 @@ impl RenderOnce for Checkbox {
             .bg(bg_color)
             .border_1()
             .border_color(border_color)
-            .rounded(px(4.0))
+            .rounded(preset.radius / 1.5)
             .when(!self.disabled, |div| {
                 div.cursor_pointer()
-                    .hover(|style| style.border_color(rgb(0x94a3b8)))
+                    .hover(|style| style.border_color(colors::HOVER_BORDER))
             })
             .when(self.disabled, |div| div.cursor_not_allowed())
-            .when_some(self.render_icon(), |div, icon| div.child(icon));
+            .when(show_ring, |div| div.shadow(focus_visible::focus_ring(theme.primary)))
+            .when_some(self.render_icon(icon_size), |div, icon| div.child(icon));
+
+        let line_height = self.get_line_height(&preset);
 
+        // Center the box on the first line of text so it stays put when the
+        // label or description wraps.
         let mut container = div()
             .id(self.id)
             .flex()
-            .items_center()
+            .items_start()
             .gap_2()
-            .child(checkbox_box);
+            .line_height(line_height)
+            .child(
+                div()
+                    .flex()
+                    .flex_none()
+                    .items_center()
+                    .h(line_height)
+                    .child(checkbox_box),
+            );
+
+        if let Some(handle) = &self.focus_handle {
+            container = container
+                .track_focus(handle)
+                .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                    focus_visible::set_modality(InputModality::Pointer, cx)
+                });
+        }
 
         // Add click handler to the whole container
         if !self.disabled {
@@ -182,25 +261,92 @@ impl RenderOnce for Checkbox {
                 let new_state = self.state.inverse();
                 container = container.on_click(move |_event, window, cx| {
                     handler(&new_state, window, cx);
+                    cx.stop_propagation();
                 });
             }
         }
 
-        // Add label if present
-        if let Some(label) = self.label {
-            container = container.child(
-                div()
-                    .text_sm()
-                    .text_color(if self.disabled {
-                        rgb(0x94a3b8)
-                    } else {
-                        rgb(0x0f172a)
-                    })
-                    .when(!self.disabled, |div| div.cursor_pointer())
-                    .child(label)
-            );
+        if self.disabled {
+            if let Some(reason) = self.disabled_reason {
+                container = container.tooltip(Tooltip::text(reason));
+            }
+        }
+
+        // Add label and description if present
+        if self.label.is_some() || self.description.is_some() {
+            let mut text = div()
+                .flex()
+                .flex_col()
+                .min_w_0()
+                .when_some(self.max_width, |text, max_width| text.max_w(max_width))
+                .when(!self.disabled, |div| div.cursor_pointer());
+
+            if let Some(label) = self.label {
+                text = text.child(
+                    div()
+                        .text_size(preset.text_size)
+                        .text_color(if self.disabled {
+                            colors::DISABLED_FOREGROUND
+                        } else {
+                            theme.foreground
+                        })
+                        .child(label)
+                );
+            }
+
+            if let Some(description) = self.description {
+                text = text.child(
+                    div()
+                        .debug_selector(|| "description".into())
+                        .text_size(preset.text_size - px(2.0))
+                        .line_height((preset.text_size - px(2.0)) * 1.5)
+                        .text_color(if self.disabled {
+                            colors::DISABLED_FOREGROUND
+                        } else {
+                            theme.muted_foreground
+                        })
+                        .child(description)
+                );
+            }
+
+            container = container.child(text);
         }
 
         container
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Checkbox;
+    use crate::traits::ToggleState;
+    use gpui::{div, prelude::*, px, TestAppContext, Window};
+
+    struct NarrowPanel;
+
+    impl Render for NarrowPanel {
+        fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+            div().w(px(180.0)).child(
+                Checkbox::new("terms", ToggleState::Unselected)
+                    .label("I have read and agree to the terms of service and privacy policy")
+                    .description(
+                        "We may contact you about changes to these terms. You can \
+                         withdraw consent at any time from your account settings, \
+                         and doing so will not affect data already processed.",
+                    )
+                    .max_width(px(150.0)),
+            )
+        }
+    }
+
+    #[gpui::test]
+    fn renders_long_multi_line_description(cx: &mut TestAppContext) {
+        let (_view, cx) = cx.add_window_view(|_, _| NarrowPanel);
+        cx.run_until_parked();
+
+        // Wraps within the max width instead of overflowing on one line
+        let description = cx.debug_bounds("description").expect("description rendered");
+        assert!(description.size.width <= px(150.0));
+        assert!(description.size.height > px(18.0) * 3.0);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::accessibility::{self, AccessibleNode, Role};
+use crate::prelude::*;
+
+/// Chip size options, drawn from the shared `SizeScale`
+pub type ChipSize = ComponentSize;
+
+/// A toggleable filter pill, like "Open" or "Assigned to me" in a filter bar.
+///
+/// Inactive chips are outlined; active ones fill with the theme's primary
+/// color. Unlike `Badge`, a chip is interactive: clicking it reports the
+/// state it should move to.
+#[derive(IntoElement)]
+pub struct Chip {
+    id: ElementId,
+    label: SharedString,
+    icon: Option<SharedString>,
+    count: Option<usize>,
+    selected: bool,
+    disabled: bool,
+    size: ChipSize,
+    accessible_name: Option<SharedString>,
+    on_click: Option<Box<dyn Fn(&bool, &mut Window, &mut App) + 'static>>,
+}
+
+impl Chip {
+    pub fn new(id: impl Into<ElementId>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            icon: None,
+            count: None,
+            selected: false,
+            disabled: false,
+            size: ChipSize::Medium,
+            accessible_name: None,
+            on_click: None,
+        }
+    }
+
+    /// A glyph shown before the label.
+    pub fn icon(mut self, icon: impl Into<SharedString>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// A number shown after the label, such as how many items match.
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn size(mut self, size: ChipSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the click handler. The handler receives the selected state the
+    /// chip should move to.
+    pub fn on_click(mut self, handler: impl Fn(&bool, &mut Window, &mut App) + 'static) -> Self {
+        self.on_click = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Selectable for Chip {
+    fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+}
+
+impl Accessible for Chip {
+    fn accessible_name(mut self, name: impl Into<SharedString>) -> Self {
+        self.accessible_name = Some(name.into());
+        self
+    }
+}
+
+impl RenderOnce for Chip {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        accessibility::expose(
+            &self.id,
+            AccessibleNode {
+                role: Role::ToggleButton,
+                name: accessibility::accessible_name(
+                    self.accessible_name.as_ref(),
+                    &self.label,
+                    None,
+                ),
+                disabled: self.disabled,
+                toggle_state: Some(self.selected.into()),
+            },
+            window,
+            cx,
+        );
+        let theme = cx.theme();
+        let preset = self.size.preset(cx);
+        let (background, foreground, border) = if self.selected {
+            (theme.primary, colors::ON_PRIMARY, theme.primary)
+        } else {
+            (theme.background, theme.foreground, theme.border)
+        };
+        let selected = self.selected;
+
+        div()
+            .id(self.id)
+            .flex()
+            .flex_none()
+            .items_center()
+            .gap_1()
+            .px(preset.padding * 0.75)
+            .py(preset.padding / 4.0)
+            .rounded(px(9999.0))
+            .border_1()
+            .border_color(border)
+            .bg(background)
+            .text_color(foreground)
+            .text_size(preset.text_size - px(1.0))
+            .font_weight(FontWeight::MEDIUM)
+            .when_some(self.icon, |chip, icon| chip.child(icon))
+            .child(self.label)
+            .when_some(self.count, |chip, count| {
+                chip.child(div().opacity(0.7).child(count.to_string()))
+            })
+            .when(self.disabled, |chip| chip.opacity(0.5).cursor_not_allowed())
+            .when(!self.disabled, |chip| {
+                chip.cursor_pointer()
+                    .when(!selected, |chip| chip.hover(|style| style.bg(theme.muted)))
+                    .when(selected, |chip| chip.hover(|style| style.opacity(0.9)))
+                    .when_some(self.on_click, |chip, on_click| {
+                        chip.on_click(move |_, window, cx| {
+                            on_click(&!selected, window, cx);
+                            cx.stop_propagation();
+                        })
+                    })
+            })
+    }
+}
@@ -0,0 +1,237 @@
+use crate::prelude::*;
+use crate::tooltip::Tooltip;
+use std::f32::consts::FRAC_PI_2;
+use std::time::Instant;
+
+const CHEVRON_SIZE: f32 = 12.0;
+
+/// Tracks the last open state and the content's natural height, so toggling
+/// can animate between zero and that height.
+struct CollapsibleState {
+    open: bool,
+    /// When `open` last changed, and how many times it has, which keys each
+    /// transition's animation.
+    changed_at: Option<Instant>,
+    generation: usize,
+    content_height: Option<Pixels>,
+}
+
+/// A controlled disclosure: a trigger row that shows or hides its content.
+/// The parent owns the `open` flag and flips it from `on_toggle`.
+///
+/// The content slides open and closed over the theme's `animation_duration`,
+/// between zero and its measured height, while the chevron turns to point
+/// down. Closed content stays laid out at zero height so its height is known
+/// before the first open.
+#[derive(IntoElement)]
+pub struct Collapsible {
+    id: ElementId,
+    open: bool,
+    disabled: bool,
+    disabled_reason: Option<SharedString>,
+    trigger: Option<AnyElement>,
+    content: Vec<AnyElement>,
+    on_toggle: Option<Box<dyn Fn(&bool, &mut Window, &mut App) + 'static>>,
+}
+
+impl Collapsible {
+    pub fn new(id: impl Into<ElementId>, open: bool) -> Self {
+        Self {
+            id: id.into(),
+            open,
+            disabled: false,
+            disabled_reason: None,
+            trigger: None,
+            content: Vec::new(),
+            on_toggle: None,
+        }
+    }
+
+    /// The always-visible row that toggles the content when clicked.
+    pub fn trigger(mut self, trigger: impl IntoElement) -> Self {
+        self.trigger = Some(trigger.into_any_element());
+        self
+    }
+
+    /// Adds an element to the collapsible content.
+    pub fn child(mut self, child: impl IntoElement) -> Self {
+        self.content.push(child.into_any_element());
+        self
+    }
+
+    pub fn children(mut self, children: impl IntoIterator<Item = impl IntoElement>) -> Self {
+        self.content
+            .extend(children.into_iter().map(|child| child.into_any_element()));
+        self
+    }
+
+    /// Called with the requested open state when the trigger is clicked.
+    pub fn on_toggle(mut self, handler: impl Fn(&bool, &mut Window, &mut App) + 'static) -> Self {
+        self.on_toggle = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Disableable for Collapsible {
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    fn disabled_reason(mut self, reason: impl Into<SharedString>) -> Self {
+        self.disabled_reason = Some(reason.into());
+        self
+    }
+}
+
+/// A chevron pointing right, turned clockwise by `turn` quarter turns: 0
+/// points right and 1 points down.
+fn chevron(turn: f32, color: Rgba) -> impl IntoElement {
+    canvas(
+        |_, _, _| {},
+        move |bounds, _, window, _| {
+            let center = bounds.center();
+            let radius = bounds.size.width.min(bounds.size.height) * 0.3;
+            let (sin, cos) = (turn * FRAC_PI_2).sin_cos();
+            let rotated = |x: f32, y: f32| {
+                point(
+                    center.x + radius * (x * cos - y * sin),
+                    center.y + radius * (x * sin + y * cos),
+                )
+            };
+
+            let mut path = PathBuilder::stroke(px(1.5));
+            path.move_to(rotated(-0.5, -1.0));
+            path.line_to(rotated(0.5, 0.0));
+            path.line_to(rotated(-0.5, 1.0));
+            if let Ok(path) = path.build() {
+                window.paint_path(path, color);
+            }
+        },
+    )
+    .flex_none()
+    .size(px(CHEVRON_SIZE))
+}
+
+impl RenderOnce for Collapsible {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let open = self.open;
+        let duration = cx.theme().animation_duration;
+
+        let state = window.use_keyed_state(self.id.clone(), cx, |_, _| CollapsibleState {
+            open,
+            changed_at: None,
+            generation: 0,
+            content_height: None,
+        });
+        let (changed_at, generation, content_height) = state.update(cx, |state, _| {
+            if state.open != open {
+                state.open = open;
+                state.changed_at = Some(Instant::now());
+                state.generation += 1;
+            }
+            (state.changed_at, state.generation, state.content_height)
+        });
+        let animating = !duration.is_zero()
+            && changed_at.is_some_and(|changed_at| changed_at.elapsed() < duration);
+
+        let chevron_color = if self.disabled {
+            colors::DISABLED_FOREGROUND
+        } else {
+            colors::MUTED_FOREGROUND
+        };
+
+        let trigger = div()
+            .id("trigger")
+            .flex()
+            .items_center()
+            .gap_2()
+            .when(!self.disabled, |trigger| trigger.cursor_pointer())
+            .when(self.disabled, |trigger| trigger.cursor_not_allowed())
+            .child(if animating {
+                div()
+                    .flex_none()
+                    .with_animation(
+                        ("chevron", generation),
+                        Animation::new(duration).with_easing(ease_in_out),
+                        move |icon, delta| {
+                            let turn = if open { delta } else { 1.0 - delta };
+                            icon.child(chevron(turn, chevron_color))
+                        },
+                    )
+                    .into_any_element()
+            } else {
+                chevron(if open { 1.0 } else { 0.0 }, chevron_color).into_any_element()
+            })
+            .child(div().flex_1().children(self.trigger))
+            .when_some(
+                self.on_toggle.filter(|_| !self.disabled),
+                |trigger, on_toggle| {
+                    trigger.on_click(move |_, window, cx| on_toggle(&!open, window, cx))
+                },
+            )
+            .when_some(
+                self.disabled_reason.filter(|_| self.disabled),
+                |trigger, reason| trigger.tooltip(Tooltip::text(reason)),
+            );
+
+        // Records the content's natural height whenever it's laid out.
+        let measure_state = state.clone();
+        let measure = canvas(
+            move |bounds, _, cx| {
+                measure_state.update(cx, |state, _| {
+                    state.content_height = Some(bounds.size.height);
+                })
+            },
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full();
+
+        let content = div()
+            .relative()
+            .flex()
+            .flex_col()
+            .flex_none()
+            .children(self.content)
+            .child(measure);
+
+        let body = match content_height {
+            // Slide between zero and the measured height, clipping the
+            // content to whatever is uncovered so far.
+            Some(height) if animating => div()
+                .flex()
+                .flex_col()
+                .overflow_hidden()
+                .child(content)
+                .with_animation(
+                    ("content", generation),
+                    Animation::new(duration).with_easing(ease_in_out),
+                    move |body, delta| {
+                        let progress = if open { delta } else { 1.0 - delta };
+                        body.h(height * progress)
+                    },
+                )
+                .into_any_element(),
+            _ if open => content.into_any_element(),
+            // Closed content is clipped to nothing but still laid out, which
+            // measures it for the next open.
+            _ => div()
+                .flex()
+                .flex_col()
+                .h(px(0.0))
+                .overflow_hidden()
+                .child(content)
+                .into_any_element(),
+        };
+
+        div()
+            .id(self.id)
+            .flex()
+            .flex_col()
+            .child(trigger)
+            .child(body)
+    }
+}
@@ -0,0 +1,64 @@
+// Named colors for the palette the built-in components draw with, and their
+// default corner radius. App code can use these to match component colors in
+// its own elements. `Theme::default()` is built from these, and components
+// refer to them by name rather than repeating the values, so there is one
+// place to change. Only transparent overlays, such as the dialog backdrop,
+// are written inline. The CLI writes the theme from gpui-ui.json into this file.
+
+use gpui::{px, Pixels, Rgba};
+
+const fn hex(hex: u32) -> Rgba {
+    Rgba {
+        r: ((hex >> 16) & 0xff) as f32 / 255.0,
+        g: ((hex >> 8) & 0xff) as f32 / 255.0,
+        b: (hex & 0xff) as f32 / 255.0,
+        a: 1.0,
+    }
+}
+
+/// Main brand color (blue-500).
+pub const PRIMARY: Rgba = hex(0x3b82f6);
+/// Complementary actions (slate-500).
+pub const SECONDARY: Rgba = hex(0x64748b);
+/// Dangerous actions and errors (red-500).
+pub const DESTRUCTIVE: Rgba = hex(0xef4444);
+/// Positive states (green-500).
+pub const SUCCESS: Rgba = hex(0x22c55e);
+/// Cautionary states (amber-500).
+pub const WARNING: Rgba = hex(0xf59e0b);
+/// Subdued backgrounds and disabled fills (slate-100).
+pub const MUTED: Rgba = hex(0xf1f5f9);
+/// Hover surfaces and subtle highlights (sky-50). Too light to use as a fill
+/// that should stand out.
+pub const ACCENT: Rgba = hex(0xf0f9ff);
+
+/// Default surface color.
+pub const BACKGROUND: Rgba = hex(0xffffff);
+/// Slightly raised surfaces such as filled cards (slate-50).
+pub const SURFACE: Rgba = hex(0xf8fafc);
+/// Primary text color (slate-900).
+pub const FOREGROUND: Rgba = hex(0x0f172a);
+/// Text and icons on filled primary, destructive, and success colors (white).
+pub const ON_PRIMARY: Rgba = hex(0xffffff);
+/// Text on warning fills, dark enough to read on amber (amber-900).
+pub const ON_WARNING: Rgba = hex(0x78350f);
+/// Secondary text such as descriptions (slate-500).
+pub const MUTED_FOREGROUND: Rgba = hex(0x64748b);
+/// Text and icons on disabled controls (slate-400).
+pub const DISABLED_FOREGROUND: Rgba = hex(0x94a3b8);
+/// Links the user has already followed (violet-600).
+pub const VISITED: Rgba = hex(0x7c3aed);
+/// Placeholder text (gray-400).
+pub const PLACEHOLDER: Rgba = hex(0x9ca3af);
+/// Default border color (slate-200).
+pub const BORDER: Rgba = hex(0xe2e8f0);
+/// Highlight behind selected text (blue-200).
+pub const SELECTION: Rgba = hex(0xbfdbfe);
+/// Border color for form controls (gray-300).
+pub const INPUT_BORDER: Rgba = hex(0xd1d5db);
+/// Border color for hovered form controls, and subdued marks such as drag
+/// handles (slate-400).
+pub const HOVER_BORDER: Rgba = hex(0x94a3b8);
+
+/// Default corner radius for controls.
+pub const RADIUS: Pixels = px(4.0);
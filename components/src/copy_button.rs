@@ -0,0 +1,126 @@
+use crate::button::{Button, ButtonSize, ButtonVariant};
+use crate::prelude::*;
+use std::time::Duration;
+
+/// How long the "Copied!" confirmation stays up before the button reverts.
+const COPIED_DURATION: Duration = Duration::from_millis(1500);
+
+const COPY_ICON: &str = "⧉";
+const COPIED_ICON: &str = "✓";
+
+/// Per-button confirmation state. `generation` lets a second click restart
+/// the timer instead of being cut short by the first one.
+struct CopyState {
+    copied: bool,
+    generation: usize,
+}
+
+impl CopyState {
+    fn show_copied(&mut self, cx: &mut Context<Self>) {
+        self.copied = true;
+        self.generation += 1;
+        let generation = self.generation;
+        cx.notify();
+
+        cx.spawn(async move |this, cx| {
+            cx.background_executor().timer(COPIED_DURATION).await;
+            this.update(cx, |state, cx| {
+                if state.generation == generation {
+                    state.copied = false;
+                    cx.notify();
+                }
+            })
+            .ok();
+        })
+        .detach();
+    }
+}
+
+/// A button that writes a value to the clipboard, then briefly swaps its
+/// icon for a checkmark and shows "Copied!".
+#[derive(IntoElement)]
+pub struct CopyButton {
+    id: ElementId,
+    value: SharedString,
+    label: Option<SharedString>,
+    variant: ButtonVariant,
+    size: ButtonSize,
+    on_copy: Option<Box<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+}
+
+impl CopyButton {
+    pub fn new(id: impl Into<ElementId>, value: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            value: value.into(),
+            label: None,
+            variant: ButtonVariant::Ghost,
+            size: ButtonSize::Small,
+            on_copy: None,
+        }
+    }
+
+    /// Text shown next to the icon. Without one the button is icon-only and
+    /// uses a tooltip instead.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn variant(mut self, variant: ButtonVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Called with the value after it has been written to the clipboard.
+    pub fn on_copy(
+        mut self,
+        handler: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_copy = Some(Box::new(handler));
+        self
+    }
+}
+
+impl RenderOnce for CopyButton {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let state = window.use_keyed_state(self.id.clone(), cx, |_, _| CopyState {
+            copied: false,
+            generation: 0,
+        });
+        let copied = state.read(cx).copied;
+
+        let label: SharedString = match (&self.label, copied) {
+            (Some(_), true) => format!("{COPIED_ICON} Copied!").into(),
+            (Some(label), false) => format!("{COPY_ICON} {label}").into(),
+            (None, true) => COPIED_ICON.into(),
+            (None, false) => COPY_ICON.into(),
+        };
+        let icon_only = self.label.is_none();
+
+        let value = self.value;
+        let on_copy = self.on_copy;
+
+        let mut button = Button::new(self.id, label)
+            .variant(self.variant)
+            .size(self.size)
+            .on_click(move |_, window, cx| {
+                cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+                state.update(cx, |state, cx| state.show_copied(cx));
+                if let Some(on_copy) = &on_copy {
+                    on_copy(&value, window, cx);
+                }
+            });
+
+        if icon_only {
+            button = button.tooltip(if copied { "Copied!" } else { "Copy" });
+        }
+
+        button
+    }
+}
@@ -0,0 +1,336 @@
+use crate::button::{Button, ButtonSize, ButtonVariant};
+use crate::overlay::Overlay;
+use crate::prelude::*;
+use chrono::{Datelike, Local, Months, NaiveDate};
+use std::rc::Rc;
+
+const WEEKDAYS: [&str; 7] = ["Su", "Mo", "Tu", "We", "Th", "Fr", "Sa"];
+const DAY_SIZE: f32 = 32.0;
+
+/// A range being picked: the first click sets `start`, the second sets `end`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RangeSelection {
+    pub start: Option<NaiveDate>,
+    pub end: Option<NaiveDate>,
+}
+
+impl RangeSelection {
+    /// Applies a click on `date`.
+    ///
+    /// With nothing picked, or a range already complete, the click starts a
+    /// new range. Otherwise it ends the range, unless `date` is before the
+    /// start, in which case it becomes the new start.
+    pub fn select(self, date: NaiveDate) -> Self {
+        match (self.start, self.end) {
+            (Some(start), None) if date >= start => Self {
+                start: Some(start),
+                end: Some(date),
+            },
+            _ => Self {
+                start: Some(date),
+                end: None,
+            },
+        }
+    }
+
+    /// The finished range, once both ends are picked.
+    pub fn range(&self) -> Option<(NaiveDate, NaiveDate)> {
+        self.start.zip(self.end)
+    }
+
+    fn contains(&self, date: NaiveDate) -> bool {
+        match (self.start, self.end) {
+            (Some(start), Some(end)) => start <= date && date <= end,
+            (Some(start), None) => start == date,
+            _ => false,
+        }
+    }
+}
+
+/// The days of `month` laid out in Sunday-first weeks, with `None` for the
+/// blank cells before the 1st and after the last day.
+pub fn month_grid(month: NaiveDate) -> Vec<Option<NaiveDate>> {
+    let first = month.with_day(1).unwrap_or(month);
+    let next_month = first + Months::new(1);
+    let days = (next_month - first).num_days() as u32;
+    let leading = first.weekday().num_days_from_sunday() as usize;
+
+    let mut cells: Vec<Option<NaiveDate>> = vec![None; leading];
+    cells.extend((0..days).map(|offset| first.with_day(offset + 1)));
+    while cells.len() % 7 != 0 {
+        cells.push(None);
+    }
+    cells
+}
+
+/// Popover state kept across frames.
+struct PickerState {
+    open: bool,
+    /// Any day in the month being shown.
+    month: NaiveDate,
+    /// The in-progress pick while the popover is open.
+    selection: RangeSelection,
+}
+
+/// Picks a start and end date in one calendar popover.
+///
+/// The range is controlled: pass the current one to `value` and update it
+/// from `on_change`, which fires once both ends are chosen.
+#[derive(IntoElement)]
+pub struct DateRangePicker {
+    id: ElementId,
+    value: Option<(NaiveDate, NaiveDate)>,
+    placeholder: SharedString,
+    disabled: bool,
+    on_change: Option<Rc<dyn Fn(&(NaiveDate, NaiveDate), &mut Window, &mut App) + 'static>>,
+}
+
+impl DateRangePicker {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            value: None,
+            placeholder: "Select dates".into(),
+            disabled: false,
+            on_change: None,
+        }
+    }
+
+    pub fn value(mut self, value: Option<(NaiveDate, NaiveDate)>) -> Self {
+        self.value = value;
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Called with `(start, end)` when the second date is picked.
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(&(NaiveDate, NaiveDate), &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    fn get_label(&self) -> SharedString {
+        match self.value {
+            Some((start, end)) => format!(
+                "{} – {}",
+                start.format("%b %-d, %Y"),
+                end.format("%b %-d, %Y")
+            )
+            .into(),
+            None => self.placeholder.clone(),
+        }
+    }
+}
+
+impl RenderOnce for DateRangePicker {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = cx.theme();
+        let value = self.value;
+        let state = window.use_keyed_state(self.id.clone(), cx, move |_, _| PickerState {
+            open: false,
+            month: value.map_or_else(|| Local::now().date_naive(), |(start, _)| start),
+            selection: RangeSelection::default(),
+        });
+        let (open, month, selection) = {
+            let state = state.read(cx);
+            (state.open, state.month, state.selection)
+        };
+
+        let trigger_state = state.clone();
+        let trigger = Button::new("trigger", self.get_label())
+            .variant(ButtonVariant::Outline)
+            .disabled(self.disabled)
+            .on_click(move |_, _, cx| {
+                trigger_state.update(cx, |state, cx| {
+                    state.open = !state.open;
+                    state.selection = match value {
+                        Some((start, end)) => RangeSelection {
+                            start: Some(start),
+                            end: Some(end),
+                        },
+                        None => RangeSelection::default(),
+                    };
+                    cx.notify();
+                });
+            });
+
+        let shift_month = |id: &'static str, label: &'static str, forward: bool| {
+            let state = state.clone();
+            Button::new(id, label)
+                .variant(ButtonVariant::Ghost)
+                .size(ButtonSize::Small)
+                .on_click(move |_, _, cx| {
+                    state.update(cx, |state, cx| {
+                        let months = Months::new(1);
+                        state.month = if forward {
+                            state.month + months
+                        } else {
+                            state.month - months
+                        };
+                        cx.notify();
+                    });
+                })
+        };
+
+        let days = month_grid(month).into_iter().enumerate().map(|(index, day)| {
+            let Some(day) = day else {
+                return div().size(px(DAY_SIZE)).into_any_element();
+            };
+
+            let in_range = selection.contains(day);
+            let is_end = selection.start == Some(day) || selection.end == Some(day);
+            let state = state.clone();
+            let on_change = self.on_change.clone();
+
+            div()
+                .id(("day", index))
+                .size(px(DAY_SIZE))
+                .flex()
+                .items_center()
+                .justify_center()
+                .text_sm()
+                .rounded(theme.radius)
+                .cursor_pointer()
+                .when(in_range && !is_end, |cell| {
+                    cell.bg(theme.accent).text_color(theme.primary)
+                })
+                .when(is_end, |cell| cell.bg(theme.primary).text_color(colors::ON_PRIMARY))
+                .when(!in_range, |cell| {
+                    cell.text_color(theme.foreground)
+                        .hover(|style| style.bg(theme.muted))
+                })
+                .on_click(move |_, window, cx| {
+                    let selection = state.update(cx, |state, cx| {
+                        state.selection = state.selection.select(day);
+                        if state.selection.range().is_some() {
+                            state.open = false;
+                        }
+                        cx.notify();
+                        state.selection
+                    });
+                    if let (Some(range), Some(on_change)) = (selection.range(), &on_change) {
+                        on_change(&range, window, cx);
+                    }
+                })
+                .child(day.day().to_string())
+                .into_any_element()
+        });
+
+        let close_state = state.clone();
+        let calendar = Overlay::popover("calendar")
+            .on_outside_click(move |_, _, cx| {
+                close_state.update(cx, |state, cx| {
+                    state.open = false;
+                    cx.notify();
+                });
+            })
+            .child(
+                div()
+                    .mt_1()
+                    .p_3()
+                    .flex()
+                    .flex_col()
+                    .gap_2()
+                    .bg(theme.background)
+                    .border_1()
+                    .border_color(theme.border)
+                    .rounded(theme.radius)
+                    .shadow_lg()
+                    .child(
+                        div()
+                            .flex()
+                            .items_center()
+                            .justify_between()
+                            .child(shift_month("previous-month", "‹", false))
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .font_weight(FontWeight::MEDIUM)
+                                    .text_color(theme.foreground)
+                                    .child(month.format("%B %Y").to_string()),
+                            )
+                            .child(shift_month("next-month", "›", true)),
+                    )
+                    .child(
+                        div()
+                            .flex()
+                            .children(WEEKDAYS.iter().map(|weekday| {
+                                div()
+                                    .w(px(DAY_SIZE))
+                                    .flex()
+                                    .justify_center()
+                                    .text_xs()
+                                    .text_color(theme.muted_foreground)
+                                    .child(*weekday)
+                            })),
+                    )
+                    .child(
+                        div()
+                            .w(px(DAY_SIZE * 7.0))
+                            .flex()
+                            .flex_wrap()
+                            .children(days),
+                    ),
+            );
+
+        div()
+            .id(self.id)
+            .child(trigger)
+            .when(open, |this| this.child(calendar))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{month_grid, RangeSelection};
+    use chrono::NaiveDate;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn first_click_starts_and_second_click_ends_a_range() {
+        let picked = RangeSelection::default().select(date(2025, 3, 4));
+        assert_eq!(picked.range(), None);
+
+        let picked = picked.select(date(2025, 3, 9));
+        assert_eq!(picked.range(), Some((date(2025, 3, 4), date(2025, 3, 9))));
+
+        // A third click starts over
+        let picked = picked.select(date(2025, 3, 12));
+        assert_eq!(picked.start, Some(date(2025, 3, 12)));
+        assert_eq!(picked.end, None);
+    }
+
+    #[test]
+    fn clicking_before_the_start_moves_the_start() {
+        let picked = RangeSelection::default()
+            .select(date(2025, 3, 10))
+            .select(date(2025, 3, 2));
+        assert_eq!(picked.start, Some(date(2025, 3, 2)));
+        assert_eq!(picked.end, None);
+    }
+
+    #[test]
+    fn month_grid_pads_to_whole_weeks() {
+        // March 2025 starts on a Saturday and has 31 days
+        let grid = month_grid(date(2025, 3, 15));
+        assert_eq!(grid.len(), 42);
+        assert_eq!(grid[5], None);
+        assert_eq!(grid[6], Some(date(2025, 3, 1)));
+        assert_eq!(grid[36], Some(date(2025, 3, 31)));
+        assert_eq!(grid[37], None);
+    }
+}
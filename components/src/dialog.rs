@@ -1,4 +1,14 @@
+use crate::keybindings::{self, actions::Dismiss, DIALOG_CONTEXT};
+use crate::overlay::Overlay;
 use crate::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Backdrop opacity for the bottom-most dialog.
+const BASE_BACKDROP_ALPHA: f32 = 0.5;
+/// Backdrop opacity for dialogs stacked on top of another. Kept light so the
+/// backdrops don't compound into near-black.
+const NESTED_BACKDROP_ALPHA: f32 = 0.2;
 
 /// Dialog size options
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -7,6 +17,8 @@ pub enum DialogSize {
     Medium,
     Large,
     Full,
+    /// Hugs the content, up to `max` wide.
+    Auto { max: Pixels },
 }
 
 /// Dialog header component
@@ -53,7 +65,7 @@ impl RenderOnce for DialogHeader {
                 div()
                     .text_xl()
                     .font_weight(FontWeight::SEMIBOLD)
-                    .text_color(rgb(0x0f172a))
+                    .text_color(colors::FOREGROUND)
                     .child(title)
             );
         }
@@ -62,7 +74,7 @@ impl RenderOnce for DialogHeader {
             header = header.child(
                 div()
                     .text_sm()
-                    .text_color(rgb(0x64748b))
+                    .text_color(colors::MUTED_FOREGROUND)
                     .child(description)
             );
         }
@@ -152,23 +164,81 @@ impl RenderOnce for DialogFooter {
     }
 }
 
+/// Registry of the dialogs currently open, bottom-most first.
+///
+/// Dialogs register themselves the first frame they render open and drop out
+/// as soon as they stop rendering, so the order reflects opening order. It
+/// decides paint order, backdrop strength, and which dialog handles Escape.
+#[derive(Clone, Default)]
+pub struct DialogStack {
+    open: Rc<RefCell<Vec<ElementId>>>,
+}
+
+impl Global for DialogStack {}
+
+impl DialogStack {
+    /// Returns the number of dialogs currently open.
+    pub fn depth(cx: &App) -> usize {
+        cx.try_global::<DialogStack>()
+            .map_or(0, |stack| stack.open.borrow().len())
+    }
+
+    /// Returns whether the dialog with this id is the top-most open dialog.
+    pub fn is_topmost(id: &ElementId, cx: &App) -> bool {
+        cx.try_global::<DialogStack>()
+            .is_some_and(|stack| stack.open.borrow().last() == Some(id))
+    }
+
+    fn position(&self, id: &ElementId) -> Option<usize> {
+        self.open.borrow().iter().position(|open| open == id)
+    }
+}
+
+/// Keeps a dialog registered in the `DialogStack` for as long as it is kept
+/// alive by rendering.
+struct DialogRegistration {
+    id: ElementId,
+    stack: DialogStack,
+    focus_handle: FocusHandle,
+}
+
+impl Drop for DialogRegistration {
+    fn drop(&mut self) {
+        self.stack.open.borrow_mut().retain(|open| open != &self.id);
+    }
+}
+
 /// A dialog/modal overlay component
 #[derive(IntoElement)]
 pub struct Dialog {
+    id: ElementId,
     size: DialogSize,
     open: bool,
+    dismissable: bool,
     children: Vec<AnyElement>,
+    on_dismiss: Option<Rc<dyn Fn(&mut Window, &mut App) + 'static>>,
 }
 
 impl Dialog {
     pub fn new() -> Self {
         Self {
+            id: "dialog".into(),
             size: DialogSize::Medium,
             open: true,
+            dismissable: true,
             children: Vec::new(),
+            on_dismiss: None,
         }
     }
 
+    /// Identifies the dialog in the dialog stack. Dialogs that can be open at
+    /// the same time, such as a confirm opened from another dialog, need
+    /// distinct ids.
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = id.into();
+        self
+    }
+
     pub fn size(mut self, size: DialogSize) -> Self {
         self.size = size;
         self
@@ -179,6 +249,25 @@ impl Dialog {
         self
     }
 
+    /// Called when the user presses Escape or clicks the backdrop while this
+    /// is the top-most dialog. Escape is the default binding for `Dismiss` in
+    /// the `Dialog` key context; apps can move it with `keybindings::rebind`.
+    pub fn on_dismiss(
+        mut self,
+        handler: impl Fn(&mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_dismiss = Some(Rc::new(handler));
+        self
+    }
+
+    /// Whether a click on the backdrop dismisses the dialog. Defaults to
+    /// true; turn it off for dialogs the user must answer. Escape still
+    /// dismisses.
+    pub fn dismissable(mut self, dismissable: bool) -> Self {
+        self.dismissable = dismissable;
+        self
+    }
+
     pub fn child(mut self, child: impl IntoElement) -> Self {
         self.children.push(child.into_any_element());
         self
@@ -199,13 +288,37 @@ impl Dialog {
         self
     }
 
+    /// The fixed width, or the cap for `DialogSize::Auto`.
     fn get_width(&self) -> Pixels {
         match self.size {
             DialogSize::Small => px(400.0),
             DialogSize::Medium => px(500.0),
             DialogSize::Large => px(700.0),
             DialogSize::Full => px(900.0),
+            DialogSize::Auto { max } => max,
+        }
+    }
+
+    /// Adds this dialog to the stack on its first open frame and returns its
+    /// depth, 0 being the bottom-most dialog, and its focus handle.
+    fn register(&self, window: &mut Window, cx: &mut App) -> (usize, FocusHandle) {
+        if !cx.has_global::<DialogStack>() {
+            cx.set_global(DialogStack::default());
         }
+        let stack = cx.global::<DialogStack>().clone();
+
+        let id = self.id.clone();
+        let registration = window.use_keyed_state(self.id.clone(), cx, |_, cx| {
+            stack.open.borrow_mut().push(id.clone());
+            DialogRegistration {
+                id,
+                stack: stack.clone(),
+                focus_handle: cx.focus_handle(),
+            }
+        });
+
+        let focus_handle = registration.read(cx).focus_handle.clone();
+        (stack.position(&self.id).unwrap_or(0), focus_handle)
     }
 }
 
@@ -216,37 +329,73 @@ impl Default for Dialog {
 }
 
 impl RenderOnce for Dialog {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         if !self.open {
-            return div(); // Empty div when closed
+            return div().into_any_element(); // Empty div when closed
         }
 
+        keybindings::init(cx);
+        let (depth, focus_handle) = self.register(window, cx);
         let dialog_width = self.get_width();
+        let auto_width = matches!(self.size, DialogSize::Auto { .. });
+        let backdrop_alpha = if depth == 0 {
+            BASE_BACKDROP_ALPHA
+        } else {
+            NESTED_BACKDROP_ALPHA
+        };
+
+        // Key bindings only reach the focused element and its ancestors, so
+        // the top-most dialog holds focus while nothing inside it does.
+        if DialogStack::is_topmost(&self.id, cx) && !focus_handle.contains_focused(window, cx) {
+            window.focus(&focus_handle);
+        }
 
-        // Overlay backdrop
-        div()
-            .absolute()
-            .top_0()
-            .left_0()
-            .size_full()
-            .flex()
-            .items_center()
-            .justify_center()
-            .bg(hsla(0.0, 0.0, 0.0, 0.5)) // Semi-transparent black backdrop
+        // Only the top-most dialog reacts to Dismiss. Lower dialogs pass it on.
+        let id = self.id.clone();
+        let on_dismiss = self.on_dismiss.clone();
+        let backdrop_dismiss = self.on_dismiss.clone().filter(|_| self.dismissable);
+        let on_dismiss_action = move |_: &Dismiss, window: &mut Window, cx: &mut App| {
+            match &on_dismiss {
+                Some(on_dismiss) if DialogStack::is_topmost(&id, cx) => {
+                    on_dismiss(window, cx)
+                }
+                _ => cx.propagate(),
+            }
+        };
+
+        // Overlay backdrop, painted above everything else and above the
+        // dialogs opened before this one. The overlay centers its children, so
+        // an auto-width panel stays centered as it shrinks to its content.
+        Overlay::modal(self.id)
+            .priority(depth + 1)
+            .backdrop(hsla(0.0, 0.0, 0.0, backdrop_alpha)) // Semi-transparent black backdrop
+            // The overlay keeps presses on the panel from reaching the
+            // backdrop, and a nested dialog's backdrop covers this one
+            .when_some(backdrop_dismiss, |overlay, on_dismiss| {
+                overlay.on_outside_click(move |_, window, cx| {
+                    on_dismiss(window, cx)
+                })
+            })
             .child(
                 // Dialog content
                 div()
+                    .key_context(DIALOG_CONTEXT)
+                    .track_focus(&focus_handle)
+                    .on_action(on_dismiss_action)
                     .flex()
                     .flex_col()
                     .gap_4()
-                    .w(dialog_width)
+                    .when(auto_width, |panel| panel.max_w(dialog_width))
+                    .when(!auto_width, |panel| panel.w(dialog_width))
                     .max_h(px(600.0))
                     .p_6()
-                    .bg(rgb(0xffffff))
+                    .bg(colors::BACKGROUND)
                     .rounded(px(12.0))
                     .border_1()
-                    .border_color(rgb(0xe5e7eb))
+                    .border_color(colors::BORDER)
+                    .shadow_xl()
                     .children(self.children)
             )
+        .into_any_element()
     }
 }
@@ -0,0 +1,251 @@
+use crate::badge::{Badge, BadgeVariant};
+use crate::focus_visible::{self, InputModality};
+use crate::prelude::*;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+type SelectHandler = Rc<dyn Fn(&Vec<PathBuf>, &mut Window, &mut App) + 'static>;
+
+/// A dashed drop zone for picking files, by dropping them onto it or by
+/// clicking to open the native file dialog.
+///
+/// The input is controlled: it reports picks through `on_select` and shows
+/// whatever the parent passes to `files`, each as a badge that can be removed
+/// through `on_remove`.
+#[derive(IntoElement)]
+pub struct FileInput {
+    id: ElementId,
+    label: SharedString,
+    accept: Vec<SharedString>,
+    multiple: bool,
+    disabled: bool,
+    files: Vec<PathBuf>,
+    on_select: Option<SelectHandler>,
+    on_remove: Option<Rc<dyn Fn(&PathBuf, &mut Window, &mut App) + 'static>>,
+}
+
+impl FileInput {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            label: "Drop files here or click to browse".into(),
+            accept: Vec::new(),
+            multiple: false,
+            disabled: false,
+            files: Vec::new(),
+            on_select: None,
+            on_remove: None,
+        }
+    }
+
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Only accept files with these extensions, given without the dot,
+    /// e.g. `["png", "jpg"]`. Matching ignores case.
+    pub fn accept<I, S>(mut self, extensions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<SharedString>,
+    {
+        self.accept = extensions.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Allows picking more than one file at a time.
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// The currently selected files, shown as removable badges.
+    pub fn files(mut self, files: impl IntoIterator<Item = PathBuf>) -> Self {
+        self.files = files.into_iter().collect();
+        self
+    }
+
+    /// Called with the accepted paths after a drop or a pick from the dialog.
+    /// Not called when none of the paths match `accept`.
+    pub fn on_select(
+        mut self,
+        handler: impl Fn(&Vec<PathBuf>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_select = Some(Rc::new(handler));
+        self
+    }
+
+    /// Called when a file's badge is dismissed.
+    pub fn on_remove(
+        mut self,
+        handler: impl Fn(&PathBuf, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_remove = Some(Rc::new(handler));
+        self
+    }
+}
+
+/// Keeps the paths whose extension is in `accept` (all of them when `accept`
+/// is empty), and only the first one unless `multiple` is set.
+fn filter_paths(paths: &[PathBuf], accept: &[SharedString], multiple: bool) -> Vec<PathBuf> {
+    let accepted = |path: &Path| {
+        accept.is_empty()
+            || path.extension().and_then(|ext| ext.to_str()).is_some_and(|ext| {
+                accept
+                    .iter()
+                    .any(|allowed| allowed.trim_start_matches('.').eq_ignore_ascii_case(ext))
+            })
+    };
+
+    let mut paths: Vec<PathBuf> = paths.iter().filter(|path| accepted(path)).cloned().collect();
+    if !multiple {
+        paths.truncate(1);
+    }
+    paths
+}
+
+fn file_name(path: &Path) -> SharedString {
+    path.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string_lossy().to_string())
+        .into()
+}
+
+impl RenderOnce for FileInput {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let preset = ComponentSize::Medium.preset(cx);
+        let accept = Rc::new(self.accept);
+        let multiple = self.multiple;
+        let enabled = !self.disabled;
+
+        let hint: Option<SharedString> = (!accept.is_empty()).then(|| {
+            accept
+                .iter()
+                .map(|ext| format!(".{}", ext.trim_start_matches('.')))
+                .collect::<Vec<_>>()
+                .join(", ")
+                .into()
+        });
+
+        let zone = div()
+            .id(self.id.clone())
+            .flex()
+            .flex_col()
+            .items_center()
+            .justify_center()
+            .gap_1()
+            .w_full()
+            .p(preset.padding * 2.0)
+            .border_2()
+            .border_dashed()
+            .border_color(colors::INPUT_BORDER)
+            .rounded(px(8.0))
+            .text_size(preset.text_size)
+            .text_color(colors::MUTED_FOREGROUND)
+            .when(self.disabled, |zone| zone.opacity(0.5).cursor_not_allowed())
+            .child(self.label)
+            .when_some(hint, |zone, hint| zone.child(div().text_xs().child(hint)))
+            .when_some(self.on_select.filter(|_| enabled), |zone, on_select| {
+                let drop_accept = accept.clone();
+                let drop_select = on_select.clone();
+                zone.cursor_pointer()
+                    .hover(|style| style.bg(colors::MUTED))
+                    .drag_over::<ExternalPaths>(|style, _, _, _| {
+                        style.border_color(colors::PRIMARY).bg(colors::ACCENT)
+                    })
+                    .on_drop(move |paths: &ExternalPaths, window, cx| {
+                        let paths = filter_paths(paths.paths(), &drop_accept, multiple);
+                        if !paths.is_empty() {
+                            drop_select(&paths, window, cx);
+                        }
+                    })
+                    .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                        focus_visible::set_modality(InputModality::Pointer, cx);
+                    })
+                    .on_click(move |_, window, cx| {
+                        let receiver = cx.prompt_for_paths(PathPromptOptions {
+                            files: true,
+                            directories: false,
+                            multiple,
+                            prompt: None,
+                        });
+                        let accept = accept.clone();
+                        let on_select = on_select.clone();
+                        window
+                            .spawn(cx, async move |cx| {
+                                let Ok(Ok(Some(paths))) = receiver.await else {
+                                    return;
+                                };
+                                let paths = filter_paths(&paths, &accept, multiple);
+                                if paths.is_empty() {
+                                    return;
+                                }
+                                cx.update(|window, cx| on_select(&paths, window, cx)).ok();
+                            })
+                            .detach();
+                    })
+            });
+
+        let on_remove = self.on_remove;
+        let files = self.files.into_iter().enumerate().map(|(index, path)| {
+            div()
+                .flex()
+                .items_center()
+                .gap_1()
+                .child(Badge::new(file_name(&path)).variant(BadgeVariant::Outline))
+                .when_some(on_remove.clone().filter(|_| enabled), |chip, on_remove| {
+                    chip.child(
+                        div()
+                            .id(("remove-file", index))
+                            .px_1()
+                            .rounded(colors::RADIUS)
+                            .text_xs()
+                            .text_color(colors::MUTED_FOREGROUND)
+                            .cursor_pointer()
+                            .hover(|style| style.bg(colors::MUTED).text_color(colors::FOREGROUND))
+                            .on_click(move |_, window, cx| on_remove(&path, window, cx))
+                            .child("✕"),
+                    )
+                })
+        });
+
+        div()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .w_full()
+            .child(zone)
+            .child(div().flex().flex_wrap().gap_2().children(files))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::filter_paths;
+    use gpui::SharedString;
+    use std::path::PathBuf;
+
+    #[test]
+    fn filter_paths_applies_accept_and_multiple() {
+        let paths = vec![
+            PathBuf::from("a.PNG"),
+            PathBuf::from("b.txt"),
+            PathBuf::from("c.jpg"),
+            PathBuf::from("README"),
+        ];
+        let images: [SharedString; 2] = ["png".into(), ".jpg".into()];
+
+        assert_eq!(
+            filter_paths(&paths, &images, true),
+            vec![PathBuf::from("a.PNG"), PathBuf::from("c.jpg")]
+        );
+        assert_eq!(filter_paths(&paths, &images, false), vec![PathBuf::from("a.PNG")]);
+        assert_eq!(filter_paths(&paths, &[], true).len(), 4);
+    }
+}
@@ -0,0 +1,80 @@
+// Focus-visible tracking.
+//
+// Focus rings should appear when focus arrives from the keyboard (Tab) but not
+// when a control is clicked. GPUI only tracks *whether* something is focused,
+// so this module remembers how the user last moved focus in a global and lets
+// components ask `is_focus_visible` instead of `is_focused` for their ring.
+
+use crate::prelude::*;
+
+/// How the user last moved focus.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InputModality {
+    /// Focus moved with the keyboard, so focus rings are shown.
+    #[default]
+    Keyboard,
+    /// Focus moved with the pointer, so focus rings are hidden.
+    Pointer,
+}
+
+struct FocusModality(InputModality);
+
+impl Global for FocusModality {}
+
+/// Installs the keystroke observer that switches back to keyboard modality
+/// on Tab. Components call this lazily, so apps don't have to.
+pub fn init(cx: &mut App) {
+    if cx.has_global::<FocusModality>() {
+        return;
+    }
+
+    cx.set_global(FocusModality(InputModality::Keyboard));
+    cx.observe_keystrokes(|event, _window, cx| {
+        if event.keystroke.key == "tab" {
+            set_modality(InputModality::Keyboard, cx);
+        }
+    })
+    .detach();
+}
+
+/// Returns how the user last moved focus.
+pub fn modality(cx: &App) -> InputModality {
+    cx.try_global::<FocusModality>()
+        .map_or(InputModality::Keyboard, |modality| modality.0)
+}
+
+/// Records how focus last moved. Focusable components call this with
+/// `Pointer` from their mouse-down handlers.
+pub fn set_modality(modality: InputModality, cx: &mut App) {
+    cx.set_global(FocusModality(modality));
+}
+
+/// Returns whether `handle` is focused and got there by keyboard.
+pub fn is_focus_visible(handle: &FocusHandle, window: &Window, cx: &App) -> bool {
+    handle.is_focused(window) && modality(cx) == InputModality::Keyboard
+}
+
+/// A 2px ring drawn outside the element's border.
+pub fn focus_ring(color: impl Into<Hsla>) -> Vec<BoxShadow> {
+    focus_ring_with_width(color, px(2.0))
+}
+
+/// Like `focus_ring`, with a custom ring width for larger or smaller controls.
+pub fn focus_ring_with_width(color: impl Into<Hsla>, width: Pixels) -> Vec<BoxShadow> {
+    vec![BoxShadow {
+        color: color.into().opacity(0.5),
+        offset: point(px(0.0), px(0.0)),
+        blur_radius: px(0.0),
+        spread_radius: width,
+    }]
+}
+
+/// Adds `is_focus_visible` to every `Focusable` view.
+pub trait FocusVisible: Focusable {
+    /// Returns whether this view is focused and got there by keyboard.
+    fn is_focus_visible(&self, window: &Window, cx: &App) -> bool {
+        is_focus_visible(&self.focus_handle(cx), window, cx)
+    }
+}
+
+impl<T: Focusable> FocusVisible for T {}
@@ -0,0 +1,176 @@
+use crate::prelude::*;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Where an image is in loading, as last reported to the callbacks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LoadStatus {
+    Loading,
+    Loaded,
+    Failed,
+}
+
+/// An image from a file path or URL, with a pulsing placeholder while it
+/// loads and a fallback if it can't be loaded.
+///
+/// Unlike GPUI's bare `img()`, it reports the outcome through `on_load` and
+/// `on_error`, each called once per source.
+#[derive(IntoElement)]
+pub struct Image {
+    id: ElementId,
+    source: SharedString,
+    width: Option<Pixels>,
+    height: Option<Pixels>,
+    rounded: Pixels,
+    object_fit: ObjectFit,
+    fallback: Option<AnyElement>,
+    on_load: Option<Box<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+    on_error: Option<Box<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+}
+
+impl Image {
+    /// `source` is an `http(s)://` URL or a path on disk.
+    pub fn new(id: impl Into<ElementId>, source: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            source: source.into(),
+            width: None,
+            height: None,
+            rounded: px(0.0),
+            object_fit: ObjectFit::Cover,
+            fallback: None,
+            on_load: None,
+            on_error: None,
+        }
+    }
+
+    /// Sets width and height to the same value.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        let size = size.into();
+        self.width = Some(size);
+        self.height = Some(size);
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = Some(height.into());
+        self
+    }
+
+    pub fn rounded(mut self, rounded: impl Into<Pixels>) -> Self {
+        self.rounded = rounded.into();
+        self
+    }
+
+    /// How the image fills its box. Defaults to `ObjectFit::Cover`.
+    pub fn object_fit(mut self, object_fit: ObjectFit) -> Self {
+        self.object_fit = object_fit;
+        self
+    }
+
+    /// Shown instead of the image when it fails to load, such as an icon or
+    /// a person's initials. Defaults to a muted box.
+    pub fn fallback(mut self, fallback: impl IntoElement) -> Self {
+        self.fallback = Some(fallback.into_any_element());
+        self
+    }
+
+    /// Called with the source once it has loaded.
+    pub fn on_load(
+        mut self,
+        handler: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_load = Some(Box::new(handler));
+        self
+    }
+
+    /// Called with the source if it fails to load.
+    pub fn on_error(
+        mut self,
+        handler: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_error = Some(Box::new(handler));
+        self
+    }
+
+    fn get_resource(&self) -> Resource {
+        if self.source.starts_with("http://") || self.source.starts_with("https://") {
+            Resource::Uri(SharedUri::from(self.source.clone()))
+        } else {
+            Resource::Path(Arc::from(Path::new(self.source.as_ref())))
+        }
+    }
+}
+
+impl RenderOnce for Image {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = cx.theme();
+        let resource = self.get_resource();
+
+        let status = match window.use_asset::<ImgResourceLoader>(&resource, cx) {
+            None => LoadStatus::Loading,
+            Some(Ok(_)) => LoadStatus::Loaded,
+            Some(Err(_)) => LoadStatus::Failed,
+        };
+
+        // Report each outcome once per source. Callbacks run after this frame,
+        // since they usually update the view that is rendering the image.
+        let reported = window.use_keyed_state(self.id.clone(), cx, |_, _| {
+            (self.source.clone(), LoadStatus::Loading)
+        });
+        if *reported.read(cx) != (self.source.clone(), status) {
+            reported.update(cx, |reported, _| *reported = (self.source.clone(), status));
+            let callback = match status {
+                LoadStatus::Loaded => self.on_load,
+                LoadStatus::Failed => self.on_error,
+                LoadStatus::Loading => None,
+            };
+            if let Some(callback) = callback {
+                let source = self.source.clone();
+                window.defer(cx, move |window, cx| callback(&source, window, cx));
+            }
+        }
+
+        let content = match status {
+            LoadStatus::Loading => div()
+                .size_full()
+                .bg(theme.muted)
+                .with_animation(
+                    "image-loading",
+                    Animation::new(Duration::from_millis(1200))
+                        .repeat()
+                        .with_easing(pulsating_between(0.5, 1.0)),
+                    |placeholder, delta| placeholder.opacity(delta),
+                )
+                .into_any_element(),
+            LoadStatus::Loaded => img(ImageSource::Resource(resource))
+                .size_full()
+                .object_fit(self.object_fit)
+                .into_any_element(),
+            LoadStatus::Failed => div()
+                .size_full()
+                .flex()
+                .items_center()
+                .justify_center()
+                .bg(theme.muted)
+                .text_color(theme.muted_foreground)
+                .children(self.fallback)
+                .into_any_element(),
+        };
+
+        div()
+            .id(self.id)
+            .flex_none()
+            .overflow_hidden()
+            .rounded(self.rounded)
+            .when_some(self.width, |image, width| image.w(width))
+            .when_some(self.height, |image, height| image.h(height))
+            .child(content)
+    }
+}
@@ -1,4 +1,7 @@
+use crate::accessibility::{self, AccessibleNode, Role};
+use crate::focus_visible::{self, InputModality};
 use crate::prelude::*;
+use crate::tooltip::Tooltip;
 use gpui::{
     Bounds, ClipboardItem, Context, CursorStyle, ElementId, ElementInputHandler, Entity,
     EntityInputHandler, FocusHandle, Focusable, GlobalElementId, LayoutId, MouseButton,
@@ -33,13 +36,8 @@ pub mod input_actions {
     );
 }
 
-/// Size variants for input
-#[derive(Clone, Copy, Debug, PartialEq)]
-pub enum InputSize {
-    Small,
-    Medium,
-    Large,
-}
+/// Size variants for input, drawn from the shared `SizeScale`
+pub type InputSize = ComponentSize;
 
 /// Visual variants for input
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -47,6 +45,278 @@ pub enum InputVariant {
     Default,
     Error,
     Success,
+    /// Valid but risky, like a weak password.
+    Warning,
+}
+
+/// Thousands and decimal separators used to format numbers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NumberGrouping {
+    pub group: char,
+    pub decimal: char,
+}
+
+impl NumberGrouping {
+    /// `1,234.56`
+    pub const COMMA: Self = Self { group: ',', decimal: '.' };
+    /// `1.234,56`
+    pub const PERIOD: Self = Self { group: '.', decimal: ',' };
+    /// `1 234,56`
+    pub const SPACE: Self = Self { group: ' ', decimal: ',' };
+}
+
+impl Default for NumberGrouping {
+    fn default() -> Self {
+        Self::COMMA
+    }
+}
+
+/// What kind of value the input accepts.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum InputType {
+    /// Free text.
+    #[default]
+    Text,
+    /// Free text shown as a bullet per character. The real text is still
+    /// what `content` and the callbacks see, but it can't be copied or cut.
+    Password,
+    /// Digits with an optional sign and decimal part.
+    Number,
+    /// A money amount: the symbol is shown as a prefix, the integer part is
+    /// grouped, and at most two decimals are accepted.
+    Currency {
+        symbol: SharedString,
+        grouping: NumberGrouping,
+    },
+}
+
+impl InputType {
+    /// A currency input using comma grouping, e.g. `InputType::currency("$")`.
+    pub fn currency(symbol: impl Into<SharedString>) -> Self {
+        Self::Currency {
+            symbol: symbol.into(),
+            grouping: NumberGrouping::COMMA,
+        }
+    }
+
+    fn is_numeric(&self) -> bool {
+        !matches!(self, Self::Text | Self::Password)
+    }
+
+    fn grouping(&self) -> Option<NumberGrouping> {
+        match self {
+            Self::Currency { grouping, .. } => Some(*grouping),
+            _ => None,
+        }
+    }
+
+    fn decimal_separator(&self) -> char {
+        self.grouping().map_or('.', |grouping| grouping.decimal)
+    }
+
+    fn max_decimals(&self) -> Option<usize> {
+        match self {
+            Self::Currency { .. } => Some(2),
+            _ => None,
+        }
+    }
+}
+
+/// Keeps only the characters of a number: digits, one decimal separator, and
+/// a leading minus sign. Group separators and anything else are dropped, and
+/// the decimal part is cut to `max_decimals` digits.
+pub fn sanitize_number(text: &str, decimal: char, max_decimals: Option<usize>) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut seen_decimal = false;
+    let mut decimals = 0;
+
+    for ch in text.chars() {
+        if ch == '-' && result.is_empty() {
+            result.push(ch);
+        } else if ch == decimal && !seen_decimal && max_decimals != Some(0) {
+            seen_decimal = true;
+            result.push(ch);
+        } else if ch.is_ascii_digit() {
+            if seen_decimal {
+                if max_decimals.is_some_and(|max| decimals >= max) {
+                    continue;
+                }
+                decimals += 1;
+            }
+            result.push(ch);
+        }
+    }
+
+    result
+}
+
+/// Inserts group separators into the integer part of a sanitized number,
+/// leaving the sign and decimal part as typed.
+pub fn group_digits(number: &str, grouping: NumberGrouping) -> String {
+    let (sign, unsigned) = match number.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", number),
+    };
+    let (integer, fraction) = match unsigned.find(grouping.decimal) {
+        Some(index) => unsigned.split_at(index),
+        None => (unsigned, ""),
+    };
+
+    let mut grouped = String::with_capacity(number.len() + integer.len() / 3);
+    grouped.push_str(sign);
+    for (index, ch) in integer.chars().enumerate() {
+        if index > 0 && (integer.len() - index) % 3 == 0 {
+            grouped.push(grouping.group);
+        }
+        grouped.push(ch);
+    }
+    grouped.push_str(fraction);
+    grouped
+}
+
+/// Parses text produced by `group_digits` (or typed by hand) back to a number.
+pub fn parse_number(text: &str, grouping: NumberGrouping) -> Option<f64> {
+    let normalized: String = text
+        .chars()
+        .filter(|ch| *ch != grouping.group)
+        .map(|ch| if ch == grouping.decimal { '.' } else { ch })
+        .collect();
+    normalized.trim().parse().ok()
+}
+
+/// The two forms of a masked value: what the field shows, and just the
+/// characters the user typed into the mask's slots.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MaskedValue {
+    pub masked: String,
+    pub raw: String,
+}
+
+/// What a mask pattern character accepts, or `None` for a literal.
+///
+/// - `#` a digit
+/// - `A` a letter
+/// - `*` a letter or digit
+fn mask_slot(pattern_char: char) -> Option<fn(char) -> bool> {
+    match pattern_char {
+        '#' => Some(|ch: char| ch.is_ascii_digit()),
+        'A' => Some(|ch: char| ch.is_alphabetic()),
+        '*' => Some(|ch: char| ch.is_alphanumeric()),
+        _ => None,
+    }
+}
+
+/// Fits text into a mask pattern like `(###) ###-####`.
+///
+/// Letters and digits in `text` fill the slots in order; characters a slot
+/// doesn't accept are skipped, and anything past the last slot is dropped.
+/// Literals are written as the text reaches them, so `"555"` becomes
+/// `"(555"` and the `") "` only appears once a fourth digit is typed.
+/// Passing already-masked text back in gives the same result.
+pub fn apply_mask(pattern: &str, text: &str) -> MaskedValue {
+    let mut pending = text.chars().filter(|ch| ch.is_alphanumeric()).peekable();
+    let mut value = MaskedValue::default();
+    let mut filled_len = 0;
+
+    'pattern: for pattern_char in pattern.chars() {
+        if pending.peek().is_none() {
+            break;
+        }
+
+        match mask_slot(pattern_char) {
+            Some(accepts) => loop {
+                match pending.next() {
+                    Some(ch) if accepts(ch) => {
+                        value.masked.push(ch);
+                        value.raw.push(ch);
+                        filled_len = value.masked.len();
+                        break;
+                    }
+                    Some(_) => continue,
+                    None => break 'pattern,
+                }
+            },
+            None => {
+                // A literal that is also a letter or digit, like the `1` in
+                // `+1 ###`, consumes itself when re-masking masked text
+                if pending.peek() == Some(&pattern_char) {
+                    pending.next();
+                }
+                value.masked.push(pattern_char);
+            }
+        }
+    }
+
+    // Don't leave literals dangling after the last filled slot
+    value.masked.truncate(filled_len);
+    value
+}
+
+/// Events emitted by `Input`. Subscribing with `cx.subscribe` lets a parent
+/// react to edits without the input holding a callback into it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputEvent {
+    /// The text changed. Not emitted for edits that leave the text as it was.
+    Changed(SharedString),
+    /// The user pressed the submit key.
+    Submitted(SharedString),
+}
+
+/// Moves the cursor end of a selection to `offset`, keeping the other end
+/// (the anchor) in place. `reversed` says the cursor is at `range.start`.
+/// Returns the new range and whether the cursor ended up at its start.
+fn extend_selection(range: Range<usize>, reversed: bool, offset: usize) -> (Range<usize>, bool) {
+    let anchor = if reversed { range.end } else { range.start };
+    if offset < anchor {
+        (offset..anchor, true)
+    } else {
+        (anchor..offset, false)
+    }
+}
+
+/// The word at `offset`, for double-click selection. Runs of whitespace and
+/// punctuation count as their own words, as in most editors. An offset just
+/// after a word, which is where a click on its last letter's right half
+/// lands, picks that word rather than the space that follows.
+fn word_range_at(text: &str, offset: usize) -> Range<usize> {
+    let mut previous: Option<Range<usize>> = None;
+    for (start, segment) in text.split_word_bound_indices() {
+        let range = start..start + segment.len();
+        if offset < range.end {
+            let is_space = segment.chars().all(char::is_whitespace);
+            return match previous {
+                Some(previous) if is_space && offset == range.start => previous,
+                _ => range,
+            };
+        }
+        previous = Some(range);
+    }
+    previous.unwrap_or(0..0)
+}
+
+/// What a password input shows in place of each character.
+const PASSWORD_BULLET: char = '•';
+
+/// A bullet per grapheme of `text`, so an accented letter or emoji typed as
+/// several code points still shows as one.
+fn mask_password(text: &str) -> String {
+    text.graphemes(true).map(|_| PASSWORD_BULLET).collect()
+}
+
+/// Maps a byte offset in `text` to the same position in `mask_password(text)`.
+fn masked_offset(text: &str, offset: usize) -> usize {
+    let graphemes = text
+        .grapheme_indices(true)
+        .take_while(|(index, _)| *index < offset)
+        .count();
+    graphemes * PASSWORD_BULLET.len_utf8()
+}
+
+/// Maps a byte offset in `mask_password(text)` back to `text`.
+fn unmasked_offset(text: &str, masked: usize) -> usize {
+    text.grapheme_indices(true)
+        .nth(masked / PASSWORD_BULLET.len_utf8())
+        .map_or(text.len(), |(index, _)| index)
 }
 
 /// A fully functional input component with keyboard support
@@ -54,7 +324,7 @@ pub struct Input {
     id: ElementId,
     focus_handle: FocusHandle,
     content: SharedString,
-    placeholder: SharedString,
+    placeholder: Option<SharedString>,
     selected_range: Range<usize>,
     selection_reversed: bool,
     marked_range: Option<Range<usize>>,
@@ -62,17 +332,31 @@ pub struct Input {
     last_bounds: Option<Bounds<Pixels>>,
     is_selecting: bool,
     
+    // Value kind
+    input_type: InputType,
+    min: Option<f64>,
+    max: Option<f64>,
+    mask: Option<SharedString>,
+
     // Styling
+    prefix: Option<SharedString>,
     size: InputSize,
     variant: InputVariant,
     label: Option<SharedString>,
+    description: Option<SharedString>,
+    max_width: Option<Pixels>,
     error: Option<SharedString>,
+    warning: Option<SharedString>,
     disabled: bool,
+    disabled_reason: Option<SharedString>,
     required: bool,
+    bordered: bool,
+    accessible_name: Option<SharedString>,
     
     // Callbacks
     on_change: Option<Box<dyn Fn(&str, &mut Window, &mut App) + 'static>>,
     on_submit: Option<Box<dyn Fn(&str, &mut Window, &mut App) + 'static>>,
+    on_mask_change: Option<Box<dyn Fn(&MaskedValue, &mut Window, &mut App) + 'static>>,
 }
 
 impl Input {
@@ -81,21 +365,33 @@ impl Input {
             id: id.into(),
             focus_handle: cx.focus_handle(),
             content: "".into(),
-            placeholder: "Type here...".into(),
+            placeholder: None,
             selected_range: 0..0,
             selection_reversed: false,
             marked_range: None,
             last_layout: None,
             last_bounds: None,
             is_selecting: false,
+            input_type: InputType::Text,
+            min: None,
+            max: None,
+            mask: None,
+            prefix: None,
             size: InputSize::Medium,
             variant: InputVariant::Default,
             label: None,
+            description: None,
+            max_width: None,
             error: None,
+            warning: None,
             disabled: false,
+            disabled_reason: None,
             required: false,
+            bordered: true,
+            accessible_name: None,
             on_change: None,
             on_submit: None,
+            on_mask_change: None,
         }
     }
 
@@ -110,12 +406,59 @@ impl Input {
     }
 
     pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
-        self.placeholder = placeholder.into();
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets what kind of value the input accepts. Numeric types drop
+    /// anything that isn't part of a number as it is typed or pasted.
+    pub fn input_type(mut self, input_type: InputType) -> Self {
+        self.input_type = input_type;
+        let len = self.content.len();
+        self.content = self.normalize(&self.content.clone()).into();
+        if self.content.len() != len {
+            let len = self.content.len();
+            self.selected_range = len..len;
+        }
+        self
+    }
+
+    /// Lowest value a numeric input accepts. Applied on submit.
+    pub fn min_value(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Highest value a numeric input accepts. Applied on submit.
+    pub fn max_value(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Restricts entry to a pattern such as `(###) ###-####` or `##/##/####`.
+    /// `#` takes a digit, `A` a letter, and `*` either; everything else is a
+    /// literal that is filled in as the user types. See `apply_mask`.
+    pub fn mask(mut self, pattern: impl Into<SharedString>) -> Self {
+        self.mask = Some(pattern.into());
+        let len = self.content.len();
+        self.content = self.normalize(&self.content.clone()).into();
+        if self.content.len() != len {
+            let len = self.content.len();
+            self.selected_range = len..len;
+        }
+        self
+    }
+
+    /// Shows fixed text before the editable area, such as a unit or `https://`.
+    /// Currency inputs use their symbol when no prefix is set.
+    pub fn prefix(mut self, prefix: impl Into<SharedString>) -> Self {
+        self.prefix = Some(prefix.into());
         self
     }
 
     pub fn value(mut self, value: impl Into<SharedString>) -> Self {
-        let value = value.into();
+        let value: SharedString = value.into();
+        let value: SharedString = self.normalize(&value).into();
         let len = value.len();
         self.content = value;
         self.selected_range = len..len;
@@ -127,12 +470,35 @@ impl Input {
         self
     }
 
+    /// Helper text shown under the field.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Caps the width of the label, description, and error or warning text
+    /// so long messages wrap instead of stretching the form.
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.max_width = Some(max_width.into());
+        self
+    }
+
     pub fn error(mut self, error: impl Into<SharedString>) -> Self {
         self.error = Some(error.into());
         self.variant = InputVariant::Error;
         self
     }
 
+    /// Shows an amber message under the field and switches to the Warning
+    /// variant. An error, if set, takes precedence.
+    pub fn warning(mut self, warning: impl Into<SharedString>) -> Self {
+        self.warning = Some(warning.into());
+        if self.error.is_none() {
+            self.variant = InputVariant::Warning;
+        }
+        self
+    }
+
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
@@ -143,6 +509,14 @@ impl Input {
         self
     }
 
+    /// Draws the field's own border and rounding. Turn this off when the
+    /// input is embedded in a container that provides them, like `InputGroup`.
+    pub fn bordered(mut self, bordered: bool) -> Self {
+        self.bordered = bordered;
+        self
+    }
+
+    /// Called with the new text after each edit.
     pub fn on_change(
         mut self,
         handler: impl Fn(&str, &mut Window, &mut App) + 'static,
@@ -151,6 +525,8 @@ impl Input {
         self
     }
 
+    /// Called with the current text when Enter is pressed while the input is
+    /// focused. The input clears itself afterwards unless the text was empty.
     pub fn on_submit(
         mut self,
         handler: impl Fn(&str, &mut Window, &mut App) + 'static,
@@ -159,43 +535,211 @@ impl Input {
         self
     }
 
-    fn get_padding(&self) -> Pixels {
-        match self.size {
-            InputSize::Small => px(8.0),
-            InputSize::Medium => px(10.0),
-            InputSize::Large => px(12.0),
+    /// Called after each edit of a masked input with both the displayed
+    /// text and the raw slot characters. `on_change` still gets the
+    /// displayed text.
+    pub fn on_mask_change(
+        mut self,
+        handler: impl Fn(&MaskedValue, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_mask_change = Some(Box::new(handler));
+        self
+    }
+
+    /// Returns the placeholder, falling back to one that suits the input type.
+    pub fn get_placeholder_text(&self) -> SharedString {
+        if let Some(placeholder) = &self.placeholder {
+            return placeholder.clone();
+        }
+        if let Some(mask) = &self.mask {
+            return mask.clone();
+        }
+
+        match &self.input_type {
+            InputType::Text => "Type here...".into(),
+            InputType::Password => "Enter password...".into(),
+            InputType::Number => "0".into(),
+            InputType::Currency { grouping, .. } => format!("0{}00", grouping.decimal).into(),
         }
     }
 
-    fn get_text_size(&self) -> Pixels {
-        match self.size {
-            InputSize::Small => px(13.0),
-            InputSize::Medium => px(14.0),
-            InputSize::Large => px(16.0),
+    fn get_prefix(&self) -> Option<SharedString> {
+        match (&self.prefix, &self.input_type) {
+            (Some(prefix), _) => Some(prefix.clone()),
+            (None, InputType::Currency { symbol, .. }) => Some(symbol.clone()),
+            (None, _) => None,
+        }
+    }
+
+    /// Returns the numeric value of a `Number` or `Currency` input, clamped to
+    /// the configured bounds. `None` for text inputs and empty or partial
+    /// numbers like `-`.
+    pub fn numeric_value(&self) -> Option<f64> {
+        if !self.input_type.is_numeric() {
+            return None;
+        }
+
+        let grouping = self.input_type.grouping().unwrap_or_default();
+        let value = parse_number(&self.content, grouping)?;
+        Some(self.clamp(value))
+    }
+
+    fn clamp(&self, value: f64) -> f64 {
+        let value = self.min.map_or(value, |min| value.max(min));
+        self.max.map_or(value, |max| value.min(max))
+    }
+
+    /// The content without a mask's literals. Same as `content` for
+    /// unmasked inputs.
+    pub fn raw_value(&self) -> String {
+        match &self.mask {
+            Some(mask) => apply_mask(mask, &self.content).raw,
+            None => self.content.to_string(),
+        }
+    }
+
+    /// Fits text to the mask, or cleans it up for numeric input types;
+    /// plain text inputs pass through.
+    fn normalize(&self, text: &str) -> String {
+        if let Some(mask) = &self.mask {
+            return apply_mask(mask, text).masked;
+        }
+        if !self.input_type.is_numeric() {
+            return text.to_string();
         }
+
+        let sanitized = sanitize_number(
+            text,
+            self.input_type.decimal_separator(),
+            self.input_type.max_decimals(),
+        );
+        match self.input_type.grouping() {
+            Some(grouping) => group_digits(&sanitized, grouping),
+            None => sanitized,
+        }
+    }
+
+    /// Normalizes edited text and works out where the cursor lands, keeping
+    /// the same number of significant characters after it so inserted group
+    /// separators and mask literals don't push it around.
+    fn normalize_with_cursor(&self, text: &str, cursor: usize) -> (String, usize) {
+        let decimal = self.input_type.decimal_separator();
+        let masked = self.mask.is_some();
+        let significant = |ch: char| {
+            if masked {
+                ch.is_alphanumeric()
+            } else {
+                ch.is_ascii_digit() || ch == decimal || ch == '-'
+            }
+        };
+
+        let after = text[cursor..].chars().filter(|ch| significant(*ch)).count();
+        let normalized = self.normalize(text);
+
+        let mut new_cursor = normalized.len();
+        let mut seen = 0;
+        for (index, ch) in normalized.char_indices().rev() {
+            if seen == after {
+                break;
+            }
+            if significant(ch) {
+                seen += 1;
+            }
+            new_cursor = index;
+        }
+
+        (normalized, new_cursor)
+    }
+
+    fn format_value(&self, value: f64) -> String {
+        let text = match self.input_type.max_decimals() {
+            Some(decimals) => format!("{:.*}", decimals, value),
+            None => value.to_string(),
+        };
+        self.normalize(&text.replace('.', &self.input_type.decimal_separator().to_string()))
     }
 
-    fn get_border_color(&self, is_focused: bool) -> Rgba {
+    fn is_password(&self) -> bool {
+        self.input_type == InputType::Password
+    }
+
+    /// The text as drawn: bullets for a password, otherwise the content.
+    fn display_text(&self) -> SharedString {
+        if self.is_password() {
+            mask_password(&self.content).into()
+        } else {
+            self.content.clone()
+        }
+    }
+
+    /// Maps an offset in the content to the drawn text.
+    fn display_offset(&self, offset: usize) -> usize {
+        if self.is_password() {
+            masked_offset(&self.content, offset)
+        } else {
+            offset
+        }
+    }
+
+    /// Maps an offset in the drawn text back to the content.
+    fn content_offset(&self, display_offset: usize) -> usize {
+        if self.is_password() {
+            unmasked_offset(&self.content, display_offset)
+        } else {
+            display_offset
+        }
+    }
+
+    fn get_padding(&self, cx: &App) -> Pixels {
+        self.size.preset(cx).padding
+    }
+
+    fn get_text_size(&self, cx: &App) -> Pixels {
+        self.size.preset(cx).text_size
+    }
+
+    fn get_border_color(&self, is_focused: bool, theme: &Theme) -> Rgba {
         if self.disabled {
-            return rgb(0xe2e8f0);
+            return theme.border;
         }
 
         if is_focused {
-            return rgb(0x3b82f6); // Blue when focused
+            return self.get_focus_color(theme);
         }
 
         match self.variant {
-            InputVariant::Default => rgb(0xd1d5db),
-            InputVariant::Error => rgb(0xef4444),
-            InputVariant::Success => rgb(0x22c55e),
+            InputVariant::Default => colors::INPUT_BORDER,
+            InputVariant::Error => theme.destructive,
+            InputVariant::Success => theme.success,
+            InputVariant::Warning => theme.warning,
         }
     }
 
-    fn get_background_color(&self) -> Rgba {
+    /// The border and ring color while focused, keeping the variant's
+    /// meaning visible.
+    fn get_focus_color(&self, theme: &Theme) -> Rgba {
+        match self.variant {
+            InputVariant::Default => theme.primary,
+            InputVariant::Error => theme.destructive,
+            InputVariant::Success => theme.success,
+            InputVariant::Warning => theme.warning,
+        }
+    }
+
+    // Larger inputs get a slightly wider ring so it reads at the same weight.
+    fn get_ring_width(&self) -> Pixels {
+        match self.size {
+            InputSize::Small => px(2.0),
+            InputSize::Medium => px(2.5),
+            InputSize::Large => px(3.0),
+        }
+    }
+
+    fn get_background_color(&self, theme: &Theme) -> Rgba {
         if self.disabled {
-            rgb(0xf1f5f9)
+            theme.muted
         } else {
-            rgb(0xffffff)
+            theme.background
         }
     }
 
@@ -258,7 +802,7 @@ impl Input {
     }
 
     fn copy(&mut self, _: &input_actions::Copy, _: &mut Window, cx: &mut Context<Self>) {
-        if !self.selected_range.is_empty() {
+        if !self.selected_range.is_empty() && !self.is_password() {
             cx.write_to_clipboard(ClipboardItem::new_string(
                 self.content[self.selected_range.clone()].to_string(),
             ));
@@ -266,7 +810,7 @@ impl Input {
     }
 
     fn cut(&mut self, _: &input_actions::Cut, window: &mut Window, cx: &mut Context<Self>) {
-        if !self.selected_range.is_empty() {
+        if !self.selected_range.is_empty() && !self.is_password() {
             cx.write_to_clipboard(ClipboardItem::new_string(
                 self.content[self.selected_range.clone()].to_string(),
             ));
@@ -275,6 +819,22 @@ impl Input {
     }
 
     fn submit(&mut self, _: &input_actions::Submit, window: &mut Window, cx: &mut Context<Self>) {
+        if self.disabled {
+            return;
+        }
+
+        // Pull out-of-range numbers back into bounds before handing them on
+        if let Some(value) = self.numeric_value() {
+            let formatted = self.format_value(value);
+            if parse_number(&self.content, self.input_type.grouping().unwrap_or_default())
+                != Some(value)
+            {
+                self.set_content(formatted, window, cx);
+            }
+        }
+
+        cx.emit(InputEvent::Submitted(self.content.clone()));
+
         if let Some(on_submit) = &self.on_submit {
             let content = self.content.clone();
             on_submit(&content, window, cx);
@@ -288,11 +848,35 @@ impl Input {
     fn on_mouse_down(
         &mut self,
         event: &MouseDownEvent,
-        _window: &mut Window,
+        window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        self.is_selecting = true;
+        if self.disabled {
+            return;
+        }
+        focus_visible::set_modality(InputModality::Pointer, cx);
+        window.focus(&self.focus_handle);
+
+        // Double-click selects a word and triple-click everything. Neither
+        // extends by dragging. Passwords select everything on double-click
+        // too, so word boundaries don't hint at what was typed.
+        match event.click_count {
+            2 if !self.is_password() => {
+                let offset = self.index_for_mouse_position(event.position);
+                let word = word_range_at(&self.content, offset);
+                self.move_to(word.start, cx);
+                self.select_to(word.end, cx);
+                return;
+            }
+            2.. => {
+                self.move_to(0, cx);
+                self.select_to(self.content.len(), cx);
+                return;
+            }
+            _ => {}
+        }
 
+        self.is_selecting = true;
         if event.modifiers.shift {
             self.select_to(self.index_for_mouse_position(event.position), cx);
         } else {
@@ -338,19 +922,12 @@ impl Input {
         if position.y > bounds.bottom() {
             return self.content.len();
         }
-        line.closest_index_for_x(position.x - bounds.left())
+        self.content_offset(line.closest_index_for_x(position.x - bounds.left()))
     }
 
     fn select_to(&mut self, offset: usize, cx: &mut Context<Self>) {
-        if self.selection_reversed {
-            self.selected_range.start = offset
-        } else {
-            self.selected_range.end = offset
-        };
-        if self.selected_range.end < self.selected_range.start {
-            self.selection_reversed = !self.selection_reversed;
-            self.selected_range = self.selected_range.end..self.selected_range.start;
-        }
+        (self.selected_range, self.selection_reversed) =
+            extend_selection(self.selected_range.clone(), self.selection_reversed, offset);
         cx.notify()
     }
 
@@ -407,11 +984,59 @@ impl Input {
             .unwrap_or(self.content.len())
     }
 
+    /// Applies an edit, normalizing the result and firing the change
+    /// callbacks. Typing and IME input arrive through
+    /// `replace_text_in_range`, which ignores them while disabled; calls from
+    /// code go straight here.
+    fn replace_text(
+        &mut self,
+        range_utf16: Option<Range<usize>>,
+        new_text: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let range = range_utf16
+            .as_ref()
+            .map(|range_utf16| self.range_from_utf16(range_utf16))
+            .or(self.marked_range.clone())
+            .unwrap_or(self.selected_range.clone());
+
+        let edited = self.content[0..range.start].to_owned() + new_text + &self.content[range.end..];
+        let (new_content, cursor) =
+            self.normalize_with_cursor(&edited, range.start + new_text.len());
+        let new_content: SharedString = new_content.into();
+        let new_selection = cursor..cursor;
+
+        // Deleting at the start or end of the text, or re-typing the selection,
+        // can leave everything as it was. Skip the callbacks and the re-render.
+        let text_changed = new_content != self.content;
+        if !text_changed && new_selection == self.selected_range && self.marked_range.is_none() {
+            return;
+        }
+
+        self.content = new_content;
+        self.selected_range = new_selection;
+        self.marked_range.take();
+
+        if text_changed {
+            // Trigger on_change callback
+            if let Some(on_change) = &self.on_change {
+                on_change(&self.content, window, cx);
+            }
+            if let (Some(mask), Some(on_mask_change)) = (&self.mask, &self.on_mask_change) {
+                on_mask_change(&apply_mask(mask, &self.content), window, cx);
+            }
+            cx.emit(InputEvent::Changed(self.content.clone()));
+        }
+
+        cx.notify();
+    }
+
     /// Clear the text input
     pub fn clear(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         let len = self.content.len();
         if len > 0 {
-            self.replace_text_in_range(Some(0..len), "", window, cx);
+            self.replace_text(Some(0..len), "", window, cx);
         }
     }
 
@@ -419,6 +1044,22 @@ impl Input {
     pub fn content(&self) -> &str {
         &self.content
     }
+
+    /// Replace the whole content, e.g. to bind the input to external state.
+    /// Does nothing, and emits nothing, if the text is already `content`.
+    pub fn set_content(
+        &mut self,
+        content: impl Into<SharedString>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let content = content.into();
+        if content == self.content {
+            return;
+        }
+        let len = self.content.len();
+        self.replace_text(Some(0..self.offset_to_utf16(len)), &content, window, cx);
+    }
 }
 
 impl EntityInputHandler for Input {
@@ -467,24 +1108,10 @@ impl EntityInputHandler for Input {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        let range = range_utf16
-            .as_ref()
-            .map(|range_utf16| self.range_from_utf16(range_utf16))
-            .or(self.marked_range.clone())
-            .unwrap_or(self.selected_range.clone());
-
-        self.content =
-            (self.content[0..range.start].to_owned() + new_text + &self.content[range.end..])
-                .into();
-        self.selected_range = range.start + new_text.len()..range.start + new_text.len();
-        self.marked_range.take();
-        
-        // Trigger on_change callback
-        if let Some(on_change) = &self.on_change {
-            on_change(&self.content, window, cx);
+        if self.disabled {
+            return;
         }
-        
-        cx.notify();
+        self.replace_text(range_utf16, new_text, window, cx);
     }
 
     fn replace_and_mark_text_in_range(
@@ -495,6 +1122,10 @@ impl EntityInputHandler for Input {
         _window: &mut Window,
         cx: &mut Context<Self>,
     ) {
+        if self.disabled {
+            return;
+        }
+
         let range = range_utf16
             .as_ref()
             .map(|range_utf16| self.range_from_utf16(range_utf16))
@@ -529,11 +1160,11 @@ impl EntityInputHandler for Input {
         let range = self.range_from_utf16(&range_utf16);
         Some(Bounds::from_corners(
             point(
-                bounds.left() + last_layout.x_for_index(range.start),
+                bounds.left() + last_layout.x_for_index(self.display_offset(range.start)),
                 bounds.top(),
             ),
             point(
-                bounds.left() + last_layout.x_for_index(range.end),
+                bounds.left() + last_layout.x_for_index(self.display_offset(range.end)),
                 bounds.bottom(),
             ),
         ))
@@ -548,9 +1179,9 @@ impl EntityInputHandler for Input {
         let line_point = self.last_bounds?.localize(&point)?;
         let last_layout = self.last_layout.as_ref()?;
 
-        assert_eq!(last_layout.text, self.content);
+        assert_eq!(last_layout.text, self.display_text());
         let utf8_index = last_layout.index_for_x(point.x - line_point.x)?;
-        Some(self.offset_to_utf16(utf8_index))
+        Some(self.offset_to_utf16(self.content_offset(utf8_index)))
     }
 }
 
@@ -598,7 +1229,7 @@ impl Element for InputElement {
         style.size.height = window.line_height().into();
         
         // Add padding based on size
-        let padding = input.get_padding();
+        let padding = input.get_padding(cx) / 2.0;
         style.padding.top = padding.into();
         style.padding.bottom = padding.into();
         
@@ -615,15 +1246,16 @@ impl Element for InputElement {
         cx: &mut App,
     ) -> Self::PrepaintState {
         let input = self.input.read(cx);
-        let content = input.content.clone();
-        let selected_range = input.selected_range.clone();
-        let cursor = input.cursor_offset();
+        let selected_range = input.display_offset(input.selected_range.start)
+            ..input.display_offset(input.selected_range.end);
+        let cursor = input.display_offset(input.cursor_offset());
         let style = window.text_style();
+        let theme = cx.theme();
 
-        let (display_text, text_color) = if content.is_empty() {
-            (input.placeholder.clone(), rgb(0x9ca3af).into())
+        let (display_text, text_color) = if input.content.is_empty() {
+            (input.get_placeholder_text(), colors::PLACEHOLDER.into())
         } else {
-            (content, rgb(0x0f172a).into())
+            (input.display_text(), theme.foreground.into())
         };
 
         let run = TextRun {
@@ -649,7 +1281,7 @@ impl Element for InputElement {
                         point(bounds.left() + cursor_pos, bounds.top()),
                         size(px(2.), bounds.bottom() - bounds.top()),
                     ),
-                    rgb(0x3b82f6),
+                    theme.primary,
                 )),
             )
         } else {
@@ -665,7 +1297,7 @@ impl Element for InputElement {
                             bounds.bottom(),
                         ),
                     ),
-                    rgb(0xbfdbfe),
+                    colors::SELECTION,
                 )),
                 None,
             )
@@ -718,25 +1350,61 @@ impl Element for InputElement {
 
 impl Render for Input {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
-        let padding = self.get_padding();
+        focus_visible::init(cx);
+        // Unlabeled inputs fall back to their placeholder
+        let placeholder = self.get_placeholder_text();
+        accessibility::expose(
+            &self.id,
+            AccessibleNode {
+                role: Role::TextInput,
+                name: accessibility::accessible_name(
+                    self.accessible_name.as_ref(),
+                    self.label.as_ref().map_or("", |label| label.as_ref()),
+                    Some(&placeholder),
+                ),
+                disabled: self.disabled,
+                toggle_state: None,
+            },
+            window,
+            cx,
+        );
+        let preset = self.size.preset(cx);
+        let padding = preset.padding;
+        let theme = cx.theme();
         let is_focused = self.focus_handle.is_focused(window);
-        let border_color = self.get_border_color(is_focused);
-        let bg_color = self.get_background_color();
+        // Text fields show their ring on any focus, not just keyboard focus,
+        // since a click is how they're usually focused and the caret alone is
+        // easy to miss.
+        let show_ring = !self.disabled && is_focused;
+        let border_color = self.get_border_color(is_focused, &theme);
+        let bg_color = self.get_background_color(&theme);
 
         let input_field = div()
             .id(self.id.clone())
             .key_context("Input")
-            .track_focus(&self.focus_handle)
+            .when(!self.disabled, |input_field| {
+                input_field.track_focus(&self.focus_handle)
+            })
             .cursor(CursorStyle::IBeam)
             .flex()
             .items_center()
             .w_full()
             .px(padding)
             .bg(bg_color)
-            .border_1()
-            .border_color(border_color)
-            .rounded(px(6.0))
-            .text_size(self.get_text_size())
+            .when(self.bordered, |input_field| {
+                input_field
+                    .border_1()
+                    .border_color(border_color)
+                    .rounded(preset.radius)
+            })
+            .when(show_ring && self.bordered, |input_field| {
+                input_field.shadow(focus_visible::focus_ring_with_width(
+                    border_color,
+                    self.get_ring_width(),
+                ))
+            })
+            .min_h(preset.min_height)
+            .text_size(self.get_text_size(cx))
             .on_action(cx.listener(Self::backspace))
             .on_action(cx.listener(Self::delete))
             .on_action(cx.listener(Self::left))
@@ -754,14 +1422,29 @@ impl Render for Input {
             .on_mouse_up(MouseButton::Left, cx.listener(Self::on_mouse_up))
             .on_mouse_up_out(MouseButton::Left, cx.listener(Self::on_mouse_up))
             .on_mouse_move(cx.listener(Self::on_mouse_move))
+            .when_some(self.get_prefix(), |input_field, prefix| {
+                input_field.child(
+                    div()
+                        .flex_none()
+                        .mr_1()
+                        .text_color(theme.muted_foreground)
+                        .child(prefix)
+                )
+            })
             .child(InputElement {
                 input: cx.entity(),
             });
 
         let input_field = if !self.disabled {
-            input_field.hover(|style| style.border_color(rgb(0x94a3b8)))
+            input_field.when(self.bordered, |input_field| {
+                input_field.hover(|style| style.border_color(colors::HOVER_BORDER))
+            })
         } else {
-            input_field.cursor_not_allowed()
+            input_field
+                .cursor_not_allowed()
+                .when_some(self.disabled_reason.clone(), |input_field, reason| {
+                    input_field.tooltip(Tooltip::text(reason))
+                })
         };
 
         let mut container = div()
@@ -775,19 +1458,21 @@ impl Render for Input {
             container = container.child(
                 div()
                     .flex()
-                    .items_center()
+                    .items_start()
                     .gap_1()
+                    .when_some(self.max_width, |d, max_width| d.max_w(max_width))
                     .child(
                         div()
+                            .min_w_0()
                             .text_sm()
                             .font_weight(FontWeight::MEDIUM)
-                            .text_color(rgb(0x0f172a))
+                            .text_color(theme.foreground)
                             .child(label.clone())
                     )
                     .when(self.required, |d| {
                         d.child(
                             div()
-                                .text_color(rgb(0xef4444))
+                                .text_color(theme.destructive)
                                 .child("*")
                         )
                     })
@@ -796,23 +1481,179 @@ impl Render for Input {
 
         container = container.child(input_field);
 
+        // Description
+        if let Some(description) = &self.description {
+            container = container.child(
+                div()
+                    .text_xs()
+                    .text_color(theme.muted_foreground)
+                    .when_some(self.max_width, |d, max_width| d.max_w(max_width))
+                    .child(description.clone())
+            );
+        }
+
         // Error message
         if let Some(error) = &self.error {
             container = container.child(
                 div()
                     .text_xs()
-                    .text_color(rgb(0xef4444))
+                    .text_color(theme.destructive)
+                    .when_some(self.max_width, |d, max_width| d.max_w(max_width))
                     .child(error.clone())
             );
+        } else if let Some(warning) = &self.warning {
+            container = container.child(
+                div()
+                    .text_xs()
+                    .text_color(theme.warning)
+                    .when_some(self.max_width, |d, max_width| d.max_w(max_width))
+                    .child(warning.clone())
+            );
         }
 
         container
     }
 }
 
+impl Accessible for Input {
+    fn accessible_name(mut self, name: impl Into<SharedString>) -> Self {
+        self.accessible_name = Some(name.into());
+        self
+    }
+}
+
+impl Disableable for Input {
+    fn disabled(self, disabled: bool) -> Self {
+        Self { disabled, ..self }
+    }
+
+    fn disabled_reason(mut self, reason: impl Into<SharedString>) -> Self {
+        self.disabled_reason = Some(reason.into());
+        self
+    }
+}
+
+impl EventEmitter<InputEvent> for Input {}
+
 impl Focusable for Input {
     fn focus_handle(&self, _: &App) -> FocusHandle {
         self.focus_handle.clone()
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        apply_mask, extend_selection, group_digits, mask_password, masked_offset, parse_number,
+        sanitize_number, unmasked_offset, word_range_at, NumberGrouping, PASSWORD_BULLET,
+    };
+
+    #[test]
+    fn extend_selection_keeps_the_anchor() {
+        // Cursor at the end moves right, then back past the anchor
+        assert_eq!(extend_selection(2..2, false, 5), (2..5, false));
+        assert_eq!(extend_selection(2..5, false, 0), (0..2, true));
+        // A reversed selection grows leftward from its end
+        assert_eq!(extend_selection(3..6, true, 1), (1..6, true));
+        assert_eq!(extend_selection(1..6, true, 8), (6..8, false));
+        // Shrinking back onto the anchor collapses it
+        assert_eq!(extend_selection(2..5, false, 2), (2..2, false));
+    }
+
+    #[test]
+    fn word_range_at_selects_the_word_under_the_click() {
+        let text = "hello brave_new world!";
+        assert_eq!(word_range_at(text, 0), 0..5);
+        assert_eq!(word_range_at(text, 3), 0..5);
+        // Just past a word still picks the word, not the space
+        assert_eq!(word_range_at(text, 5), 0..5);
+        // Underscores join words
+        assert_eq!(word_range_at(text, 8), 6..15);
+        // Punctuation is its own word
+        assert_eq!(word_range_at(text, 21), 21..22);
+        assert_eq!(word_range_at(text, 22), 21..22);
+        assert_eq!(word_range_at("", 0), 0..0);
+    }
+
+    #[test]
+    fn password_offsets_map_graphemes_to_bullets() {
+        // "é" typed as e + combining accent is one grapheme of three bytes
+        let text = "ae\u{301}☕";
+        assert_eq!(mask_password(text), "•••");
+
+        let bullet = PASSWORD_BULLET.len_utf8();
+        assert_eq!(masked_offset(text, 0), 0);
+        assert_eq!(masked_offset(text, 1), bullet);
+        assert_eq!(masked_offset(text, 4), 2 * bullet);
+        assert_eq!(masked_offset(text, text.len()), 3 * bullet);
+
+        assert_eq!(unmasked_offset(text, 0), 0);
+        assert_eq!(unmasked_offset(text, 2 * bullet), 4);
+        assert_eq!(unmasked_offset(text, 3 * bullet), text.len());
+        assert_eq!(unmasked_offset("", 0), 0);
+    }
+
+    #[test]
+    fn word_range_at_handles_multibyte_text() {
+        let text = "café ☕ ok";
+        assert_eq!(word_range_at(text, 2), 0..5);
+        assert_eq!(&text[word_range_at(text, 6)], "☕");
+    }
+
+    #[test]
+    fn sanitize_drops_non_numeric_characters() {
+        assert_eq!(sanitize_number("1a2b3", '.', None), "123");
+        assert_eq!(sanitize_number("-12.5.6", '.', None), "-12.56");
+        assert_eq!(sanitize_number("1-2", '.', None), "12");
+        assert_eq!(sanitize_number("3.14159", '.', Some(2)), "3.14");
+        assert_eq!(sanitize_number("1.234,5", ',', None), "1234,5");
+    }
+
+    #[test]
+    fn group_digits_inserts_separators_in_integer_part() {
+        assert_eq!(group_digits("1234567", NumberGrouping::COMMA), "1,234,567");
+        assert_eq!(group_digits("-1234.5", NumberGrouping::COMMA), "-1,234.5");
+        assert_eq!(group_digits("999", NumberGrouping::COMMA), "999");
+        assert_eq!(group_digits("1234,50", NumberGrouping::PERIOD), "1.234,50");
+        assert_eq!(group_digits("12.", NumberGrouping::COMMA), "12.");
+    }
+
+    #[test]
+    fn parse_number_round_trips_grouped_text() {
+        assert_eq!(parse_number("1,234.56", NumberGrouping::COMMA), Some(1234.56));
+        assert_eq!(parse_number("1.234,56", NumberGrouping::PERIOD), Some(1234.56));
+        assert_eq!(parse_number("-", NumberGrouping::COMMA), None);
+        assert_eq!(parse_number("", NumberGrouping::COMMA), None);
+    }
+
+    #[test]
+    fn apply_mask_inserts_literals_as_slots_fill() {
+        let phone = "(###) ###-####";
+        assert_eq!(apply_mask(phone, "").masked, "");
+        assert_eq!(apply_mask(phone, "5").masked, "(5");
+        assert_eq!(apply_mask(phone, "555").masked, "(555");
+        assert_eq!(apply_mask(phone, "5551").masked, "(555) 1");
+
+        let full = apply_mask(phone, "5551234567");
+        assert_eq!(full.masked, "(555) 123-4567");
+        assert_eq!(full.raw, "5551234567");
+    }
+
+    #[test]
+    fn apply_mask_restricts_slots_and_length() {
+        assert_eq!(apply_mask("##/##/####", "12ab3").masked, "12/3");
+        assert_eq!(apply_mask("#### ####", "1234567890").masked, "1234 5678");
+        assert_eq!(apply_mask("AA-##", "1ab23").masked, "ab-23");
+        assert_eq!(apply_mask("AA-##", "1ab23").raw, "ab23");
+    }
+
+    #[test]
+    fn apply_mask_is_stable_on_masked_text() {
+        let phone = "(###) ###-####";
+        assert_eq!(apply_mask(phone, "(555) 123-4567").masked, "(555) 123-4567");
+        assert_eq!(apply_mask(phone, "(555) 1").masked, "(555) 1");
+        assert_eq!(apply_mask("+1 ###", "+1 55").masked, "+1 55");
+        assert_eq!(apply_mask("+1 ###", "+1 55").raw, "55");
+    }
+}
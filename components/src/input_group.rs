@@ -0,0 +1,77 @@
+use crate::button::Button;
+use crate::input::Input;
+use crate::prelude::*;
+
+/// Combines an `Input` with leading and/or trailing `Button`s into a single
+/// bordered control, e.g. a search box with an attached "Go" button.
+///
+/// Build the input with `.bordered(false)` so the group's border is the only
+/// one drawn.
+#[derive(IntoElement)]
+pub struct InputGroup {
+    input: Entity<Input>,
+    leading: Vec<Button>,
+    trailing: Vec<Button>,
+}
+
+impl InputGroup {
+    pub fn new(input: Entity<Input>) -> Self {
+        Self {
+            input,
+            leading: Vec::new(),
+            trailing: Vec::new(),
+        }
+    }
+
+    /// Adds a button before the input.
+    pub fn leading(mut self, button: Button) -> Self {
+        self.leading.push(button);
+        self
+    }
+
+    /// Adds a button after the input.
+    pub fn trailing(mut self, button: Button) -> Self {
+        self.trailing.push(button);
+        self
+    }
+}
+
+impl RenderOnce for InputGroup {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let is_focused = self.input.focus_handle(cx).is_focused(window);
+        let border_color = if is_focused {
+            colors::PRIMARY
+        } else {
+            colors::INPUT_BORDER
+        };
+
+        // Buttons are squared off; the group's own rounding and clipping shape
+        // the outer corners, and a divider separates each segment. Flex rows
+        // stretch their children by default, so segments fill the group's height.
+        let segment = move |button: Button| {
+            div()
+                .flex()
+                .border_color(border_color)
+                .child(button.rounded(false))
+        };
+
+        div()
+            .flex()
+            .w_full()
+            .border_1()
+            .border_color(border_color)
+            .rounded(px(6.0))
+            .overflow_hidden()
+            .children(
+                self.leading
+                    .into_iter()
+                    .map(|button| segment(button).border_r_1()),
+            )
+            .child(div().flex_1().min_w_0().child(self.input))
+            .children(
+                self.trailing
+                    .into_iter()
+                    .map(|button| segment(button).border_l_1()),
+            )
+    }
+}
@@ -0,0 +1,118 @@
+use crate::prelude::*;
+
+/// Splits a key combo like `"Cmd+Shift+K"` into display key caps, replacing
+/// common modifier and special key names with their symbols.
+pub fn parse_keys(combo: &str) -> Vec<SharedString> {
+    let mut keys = Vec::new();
+    let mut rest = combo.trim();
+
+    while !rest.is_empty() {
+        // A leading "+" is the plus key itself, e.g. the last cap of "Ctrl++".
+        let (key, remainder) = match rest.strip_prefix('+') {
+            Some(remainder) => ("+", remainder),
+            None => match rest.find('+') {
+                Some(index) => (&rest[..index], &rest[index + 1..]),
+                None => (rest, ""),
+            },
+        };
+
+        let key = key.trim();
+        if !key.is_empty() {
+            keys.push(key_symbol(key));
+        }
+        rest = remainder;
+    }
+
+    keys
+}
+
+fn key_symbol(key: &str) -> SharedString {
+    let symbol = match key.to_ascii_lowercase().as_str() {
+        "cmd" | "command" | "super" | "meta" => "⌘",
+        "shift" => "⇧",
+        "alt" | "option" | "opt" => "⌥",
+        "ctrl" | "control" => "Ctrl",
+        "enter" | "return" => "↵",
+        "esc" | "escape" => "Esc",
+        "backspace" => "⌫",
+        "delete" | "del" => "Del",
+        "tab" => "⇥",
+        "space" => "Space",
+        "up" => "↑",
+        "down" => "↓",
+        "left" => "←",
+        "right" => "→",
+        _ => return key.to_uppercase().into(),
+    };
+    symbol.into()
+}
+
+/// Renders a keyboard shortcut as a row of key caps
+#[derive(IntoElement)]
+pub struct Kbd {
+    keys: Vec<SharedString>,
+}
+
+impl Kbd {
+    /// Creates key caps from a combo string such as `"Ctrl+S"` or `"Cmd+K"`.
+    pub fn new(combo: impl AsRef<str>) -> Self {
+        Self {
+            keys: parse_keys(combo.as_ref()),
+        }
+    }
+
+    /// Creates key caps from already separated keys, e.g. `["⌘", "K"]`.
+    pub fn keys(keys: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        Self {
+            keys: keys.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl RenderOnce for Kbd {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        div()
+            .flex()
+            .items_center()
+            .gap(px(2.0))
+            .children(self.keys.into_iter().map(|key| {
+                div()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .min_w(px(20.0))
+                    .h(px(20.0))
+                    .px_1()
+                    .bg(colors::MUTED)
+                    .border_1()
+                    .border_b_2()
+                    .border_color(colors::BORDER)
+                    .rounded(colors::RADIUS)
+                    .text_xs()
+                    .font_weight(FontWeight::MEDIUM)
+                    .text_color(colors::MUTED_FOREGROUND)
+                    .child(key)
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_keys;
+
+    fn parse(combo: &str) -> Vec<String> {
+        parse_keys(combo).iter().map(|key| key.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_keys_maps_modifiers() {
+        assert_eq!(parse("Cmd+K"), vec!["⌘", "K"]);
+        assert_eq!(parse("ctrl+shift+s"), vec!["Ctrl", "⇧", "S"]);
+    }
+
+    #[test]
+    fn test_parse_keys_handles_plus_key() {
+        assert_eq!(parse("Ctrl++"), vec!["Ctrl", "+"]);
+        assert_eq!(parse("+"), vec!["+"]);
+    }
+}
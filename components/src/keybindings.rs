@@ -0,0 +1,157 @@
+// Shared keyboard shortcuts.
+//
+// Components declare the shortcuts they respond to as GPUI actions bound here,
+// rather than matching keystrokes in key-down handlers. The bindings live in
+// GPUI's keymap, so an app can move any of them with `rebind`, and
+// `shortcuts` lists what is bound for help screens and menus.
+
+use crate::prelude::*;
+
+pub mod actions {
+    use gpui::actions;
+
+    actions!(gpui_ui, [Dismiss, ToggleCommandPalette]);
+}
+
+use actions::{Dismiss, ToggleCommandPalette};
+
+/// Key context set by `Dialog` on its panel.
+pub const DIALOG_CONTEXT: &str = "Dialog";
+
+/// Key context set by `OtpInput` on its row of boxes.
+pub const OTP_INPUT_CONTEXT: &str = "OtpInput";
+
+/// A keystroke bound to an action.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Shortcut {
+    /// The action's name, e.g. `gpui_ui::Dismiss`.
+    pub action: &'static str,
+    /// The keystrokes in GPUI's syntax, e.g. `secondary-k` for ⌘K on macOS
+    /// and Ctrl+K elsewhere.
+    pub keystrokes: SharedString,
+    /// The key context the binding applies in, or `None` for everywhere.
+    pub context: Option<SharedString>,
+}
+
+struct Shortcuts(Vec<Shortcut>);
+
+impl Global for Shortcuts {}
+
+/// Binds the default shortcuts: Escape dismisses the top-most dialog, and
+/// ⌘K/Ctrl+K dispatches `ToggleCommandPalette`. Components call this lazily,
+/// so apps only need it before rebinding.
+pub fn init(cx: &mut App) {
+    if cx.has_global::<Shortcuts>() {
+        return;
+    }
+
+    cx.set_global(Shortcuts(Vec::new()));
+    bind("escape", Dismiss, Some(DIALOG_CONTEXT), cx);
+    bind("secondary-k", ToggleCommandPalette, None, cx);
+}
+
+/// Binds `keystrokes` to `action` in `context`, alongside any keystrokes
+/// already bound to it.
+pub fn bind<A: Action>(keystrokes: &str, action: A, context: Option<&str>, cx: &mut App) {
+    init(cx);
+    let shortcut = Shortcut {
+        action: action.name(),
+        keystrokes: SharedString::from(keystrokes.to_string()),
+        context: context.map(|context| SharedString::from(context.to_string())),
+    };
+    cx.bind_keys([KeyBinding::new(keystrokes, action, context)]);
+    cx.global_mut::<Shortcuts>().0.push(shortcut);
+}
+
+/// Moves `action` in `context` to `keystrokes`. Its previous keystrokes
+/// there stop triggering it; other bindings of those keystrokes, such as an
+/// app's own `escape` binding, keep working.
+pub fn rebind<A: Action>(keystrokes: &str, action: A, context: Option<&str>, cx: &mut App) {
+    init(cx);
+    let name = action.name();
+    let previous: Vec<Shortcut> = cx
+        .global::<Shortcuts>()
+        .0
+        .iter()
+        .filter(|shortcut| shortcut.action == name && shortcut.context.as_ref().map(SharedString::as_ref) == context)
+        .cloned()
+        .collect();
+
+    // GPUI's keymap can't drop a single binding, so it is rebuilt without the
+    // moved ones. Shadowing them with `NoAction` instead would turn those
+    // keystrokes off for every action in `context`.
+    let moved: Vec<KeyBinding> = previous
+        .iter()
+        .map(|shortcut| KeyBinding::new(&shortcut.keystrokes, NoAction, context))
+        .collect();
+    let kept: Vec<KeyBinding> = cx
+        .key_bindings()
+        .borrow()
+        .bindings()
+        .filter(|binding| {
+            binding.action().name() != name
+                || !moved.iter().any(|old| {
+                    old.keystrokes() == binding.keystrokes() && old.predicate() == binding.predicate()
+                })
+        })
+        .cloned()
+        .collect();
+    cx.clear_key_bindings();
+    cx.bind_keys(kept);
+
+    cx.global_mut::<Shortcuts>()
+        .0
+        .retain(|shortcut| !previous.contains(shortcut));
+    bind(keystrokes, action, context, cx);
+}
+
+/// Every shortcut bound through this module, oldest first.
+pub fn shortcuts(cx: &App) -> Vec<Shortcut> {
+    cx.try_global::<Shortcuts>()
+        .map(|shortcuts| shortcuts.0.clone())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{init, rebind, shortcuts, Shortcut, DIALOG_CONTEXT};
+    use super::actions::{Dismiss, ToggleCommandPalette};
+    use gpui::{Action, KeyBinding, NoAction, SharedString, TestAppContext};
+
+    #[gpui::test]
+    fn rebind_replaces_the_default(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            rebind("ctrl-w", Dismiss, Some(DIALOG_CONTEXT), cx);
+
+            let dismiss: Vec<Shortcut> = shortcuts(cx)
+                .into_iter()
+                .filter(|shortcut| shortcut.action == Dismiss.name())
+                .collect();
+            assert_eq!(dismiss.len(), 1);
+            assert_eq!(dismiss[0].keystrokes.as_ref(), "ctrl-w");
+            assert_eq!(dismiss[0].context.as_ref().map(SharedString::as_ref), Some(DIALOG_CONTEXT));
+            assert!(shortcuts(cx)
+                .iter()
+                .any(|shortcut| shortcut.action == ToggleCommandPalette.name()));
+        });
+    }
+
+    gpui::actions!(test, [AppEscape]);
+
+    #[gpui::test]
+    fn rebind_leaves_other_bindings_of_the_old_keystrokes(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            init(cx);
+            cx.bind_keys([KeyBinding::new("escape", AppEscape, Some(DIALOG_CONTEXT))]);
+            rebind("ctrl-w", Dismiss, Some(DIALOG_CONTEXT), cx);
+
+            let keymap = cx.key_bindings();
+            let keymap = keymap.borrow();
+            let bound: Vec<&str> = keymap.bindings().map(|binding| binding.action().name()).collect();
+            assert!(bound.contains(&AppEscape.name()));
+            assert!(bound.contains(&ToggleCommandPalette.name()));
+            assert!(!bound.contains(&NoAction.name()));
+            assert_eq!(keymap.bindings_for_action(&Dismiss).count(), 1);
+        });
+    }
+}
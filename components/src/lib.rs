@@ -1,20 +1,179 @@
 // GPUI UI Components Library
 // This library contains reusable components for GPUI applications
+//
+// Components and the support modules they share (`accessibility`, `announce`,
+// `focus_visible`, `keybindings`, `truncate`) are gated behind Cargo features of
+// the same name, all enabled by the default `full` feature. Each feature turns
+// on the ones its module uses. `traits`, `prelude`, `colors`, `size`, `theme`,
+// and `breakpoint` are always available.
 
+#[cfg(feature = "accessibility")]
+pub mod accessibility;
+#[cfg(feature = "alert")]
+pub mod alert;
+#[cfg(feature = "announce")]
+pub mod announce;
+#[cfg(feature = "aspect_ratio")]
+pub mod aspect_ratio;
+#[cfg(feature = "badge")]
 pub mod badge;
+#[cfg(feature = "banner")]
+pub mod banner;
+pub mod breakpoint;
+#[cfg(feature = "button")]
 pub mod button;
+#[cfg(feature = "card")]
 pub mod card;
+#[cfg(feature = "card_grid")]
+pub mod card_grid;
+#[cfg(feature = "carousel")]
+pub mod carousel;
+#[cfg(feature = "checkbox")]
 pub mod checkbox;
+#[cfg(feature = "chip")]
+pub mod chip;
+#[cfg(feature = "collapsible")]
+pub mod collapsible;
+pub mod colors;
+#[cfg(feature = "copy_button")]
+pub mod copy_button;
+#[cfg(feature = "date_range_picker")]
+pub mod date_range_picker;
+#[cfg(feature = "dialog")]
 pub mod dialog;
+#[cfg(feature = "file_input")]
+pub mod file_input;
+#[cfg(feature = "focus_visible")]
+pub mod focus_visible;
+#[cfg(feature = "image")]
+pub mod image;
+#[cfg(feature = "input")]
 pub mod input;
+#[cfg(feature = "input_group")]
+pub mod input_group;
+#[cfg(feature = "kbd")]
+pub mod kbd;
+#[cfg(feature = "keybindings")]
+pub mod keybindings;
+#[cfg(feature = "link")]
+pub mod link;
+#[cfg(feature = "list")]
+pub mod list;
+#[cfg(feature = "otp_input")]
+pub mod otp_input;
+#[cfg(feature = "overlay")]
+pub mod overlay;
 pub mod prelude;
+#[cfg(feature = "rating")]
+pub mod rating;
+#[cfg(feature = "resizable")]
+pub mod resizable;
+#[cfg(feature = "select")]
+pub mod select;
+#[cfg(feature = "separator")]
+pub mod separator;
+#[cfg(feature = "sidebar")]
+pub mod sidebar;
+pub mod size;
+#[cfg(feature = "tabs")]
+pub mod tabs;
+#[cfg(feature = "tag_input")]
+pub mod tag_input;
+pub mod theme;
+#[cfg(feature = "toggle_button")]
+pub mod toggle_button;
+#[cfg(feature = "toggle_group")]
+pub mod toggle_group;
+#[cfg(feature = "toolbar")]
+pub mod toolbar;
+#[cfg(feature = "tooltip")]
+pub mod tooltip;
 pub mod traits;
+#[cfg(feature = "truncate")]
+pub mod truncate;
 
 // Re-export commonly used types
-pub use badge::{Badge, BadgeSize, BadgeVariant};
+#[cfg(feature = "accessibility")]
+pub use accessibility::{AccessibilityTree, AccessibleNode, Role};
+#[cfg(feature = "alert")]
+pub use alert::{Alert, AlertVariant, DismissReason};
+#[cfg(feature = "announce")]
+pub use announce::{announce, Politeness};
+#[cfg(feature = "aspect_ratio")]
+pub use aspect_ratio::AspectRatio;
+#[cfg(feature = "badge")]
+pub use badge::{format_count, with_badge, Badge, BadgeAnchor, BadgeSize, BadgeVariant};
+#[cfg(feature = "banner")]
+pub use banner::{Banner, BannerVariant};
+pub use breakpoint::Breakpoint;
+#[cfg(feature = "button")]
 pub use button::{Button, ButtonSize, ButtonVariant};
+#[cfg(feature = "card")]
 pub use card::{Card, CardContent, CardFooter, CardHeader, CardVariant};
+#[cfg(feature = "card_grid")]
+pub use card_grid::CardGrid;
+#[cfg(feature = "carousel")]
+pub use carousel::Carousel;
+#[cfg(feature = "checkbox")]
 pub use checkbox::{Checkbox, CheckboxSize};
-pub use dialog::{Dialog, DialogContent, DialogFooter, DialogHeader, DialogSize};
-pub use input::{Input, InputSize, InputVariant};
-pub use traits::{Clickable, Disableable, Toggleable, ToggleState};
+#[cfg(feature = "chip")]
+pub use chip::{Chip, ChipSize};
+#[cfg(feature = "collapsible")]
+pub use collapsible::Collapsible;
+#[cfg(feature = "copy_button")]
+pub use copy_button::CopyButton;
+#[cfg(feature = "date_range_picker")]
+pub use date_range_picker::{month_grid, DateRangePicker, RangeSelection};
+#[cfg(feature = "dialog")]
+pub use dialog::{Dialog, DialogContent, DialogFooter, DialogHeader, DialogSize, DialogStack};
+#[cfg(feature = "file_input")]
+pub use file_input::FileInput;
+#[cfg(feature = "focus_visible")]
+pub use focus_visible::{FocusVisible, InputModality};
+#[cfg(feature = "image")]
+pub use image::Image;
+#[cfg(feature = "input")]
+pub use input::{
+    apply_mask, Input, InputEvent, InputSize, InputType, InputVariant, MaskedValue, NumberGrouping,
+};
+#[cfg(feature = "input_group")]
+pub use input_group::InputGroup;
+#[cfg(feature = "kbd")]
+pub use kbd::Kbd;
+#[cfg(feature = "keybindings")]
+pub use keybindings::Shortcut;
+#[cfg(feature = "link")]
+pub use link::Link;
+#[cfg(feature = "list")]
+pub use list::{List, ListReorder};
+#[cfg(feature = "otp_input")]
+pub use otp_input::OtpInput;
+#[cfg(feature = "overlay")]
+pub use overlay::Overlay;
+#[cfg(feature = "rating")]
+pub use rating::{Rating, RatingSize};
+#[cfg(feature = "resizable")]
+pub use resizable::{ResizablePanel, ResizablePanelGroup, ResizeOrientation};
+#[cfg(feature = "select")]
+pub use select::{Select, SelectOption, SelectSize};
+#[cfg(feature = "separator")]
+pub use separator::{Separator, SeparatorOrientation};
+#[cfg(feature = "sidebar")]
+pub use sidebar::{Sidebar, SidebarItem, SidebarSection};
+pub use size::{ComponentSize, SizePreset, SizeScale};
+#[cfg(feature = "tabs")]
+pub use tabs::{Tab, Tabs};
+#[cfg(feature = "tag_input")]
+pub use tag_input::{fuzzy_score, matching_suggestions, TagInput};
+pub use theme::{ActiveTheme, Theme};
+#[cfg(feature = "toggle_button")]
+pub use toggle_button::ToggleButton;
+#[cfg(feature = "toggle_group")]
+pub use toggle_group::{ToggleGroup, ToggleGroupItem};
+#[cfg(feature = "toolbar")]
+pub use toolbar::{Toolbar, ToolbarAlign, ToolbarGroup};
+#[cfg(feature = "tooltip")]
+pub use tooltip::{Tooltip, TooltipPlacement, TooltipSettings};
+pub use traits::{Accessible, Clickable, Disableable, Hoverable, Selectable, Toggleable, ToggleState};
+#[cfg(feature = "truncate")]
+pub use truncate::{truncate_in_window_font, truncate_to_width, truncate_with};
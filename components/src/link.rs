@@ -0,0 +1,124 @@
+use crate::accessibility::{self, AccessibleNode, Role};
+use crate::prelude::*;
+
+/// Inline text that navigates when clicked.
+///
+/// With only an `href`, clicking opens the URL in the system browser. Give it
+/// an `on_navigate` handler to route the `href` inside the app instead, for
+/// example to switch views.
+#[derive(IntoElement)]
+pub struct Link {
+    id: ElementId,
+    label: SharedString,
+    href: Option<SharedString>,
+    underline: bool,
+    visited: bool,
+    visited_color: Rgba,
+    accessible_name: Option<SharedString>,
+    on_navigate: Option<Box<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+}
+
+impl Link {
+    pub fn new(id: impl Into<ElementId>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            href: None,
+            underline: false,
+            visited: false,
+            visited_color: colors::VISITED,
+            accessible_name: None,
+            on_navigate: None,
+        }
+    }
+
+    /// The URL or route to navigate to.
+    pub fn href(mut self, href: impl Into<SharedString>) -> Self {
+        self.href = Some(href.into());
+        self
+    }
+
+    /// Underlines the label at all times. By default the underline only
+    /// shows on hover.
+    pub fn underline(mut self, underline: bool) -> Self {
+        self.underline = underline;
+        self
+    }
+
+    /// Shows the link in its visited color.
+    pub fn visited(mut self, visited: bool) -> Self {
+        self.visited = visited;
+        self
+    }
+
+    /// The color used once `visited` is set. Defaults to violet.
+    pub fn visited_color(mut self, color: impl Into<Rgba>) -> Self {
+        self.visited_color = color.into();
+        self
+    }
+
+    /// Handles navigation in the app instead of opening the `href` in the
+    /// browser. The handler receives the `href`, or the label if none is set.
+    pub fn on_navigate(
+        mut self,
+        handler: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_navigate = Some(Box::new(handler));
+        self
+    }
+}
+
+impl Accessible for Link {
+    fn accessible_name(mut self, name: impl Into<SharedString>) -> Self {
+        self.accessible_name = Some(name.into());
+        self
+    }
+}
+
+impl RenderOnce for Link {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = cx.theme();
+        accessibility::expose(
+            &self.id,
+            AccessibleNode {
+                role: Role::Link,
+                name: accessibility::accessible_name(
+                    self.accessible_name.as_ref(),
+                    &self.label,
+                    None,
+                ),
+                disabled: false,
+                toggle_state: None,
+            },
+            window,
+            cx,
+        );
+
+        let color = if self.visited {
+            self.visited_color
+        } else {
+            theme.primary
+        };
+        let href = self.href;
+        let label = self.label.clone();
+        let on_navigate = self.on_navigate;
+
+        div()
+            .id(self.id)
+            .text_color(color)
+            .cursor_pointer()
+            .when(self.underline, |link| link.underline())
+            .when(!self.underline, |link| link.hover(|style| style.underline()))
+            .on_click(move |_, window, cx| {
+                match (&on_navigate, &href) {
+                    (Some(on_navigate), href) => {
+                        on_navigate(href.as_ref().unwrap_or(&label), window, cx)
+                    }
+                    (None, Some(href)) => cx.open_url(href),
+                    (None, None) => return,
+                }
+                cx.stop_propagation();
+            })
+            .child(self.label)
+    }
+}
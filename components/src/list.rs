@@ -0,0 +1,202 @@
+use crate::prelude::*;
+use std::rc::Rc;
+
+/// Fired when a row is dropped onto another row of a reorderable `List`.
+/// Both are row indices: the row at `from` should end up at index `to`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ListReorder {
+    pub from: usize,
+    pub to: usize,
+}
+
+/// Drag payload for a row being reordered.
+#[derive(Clone)]
+struct DraggedRow {
+    list_id: ElementId,
+    index: usize,
+}
+
+/// What follows the pointer while a row is dragged.
+struct DraggedRowPreview;
+
+impl Render for DraggedRowPreview {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .px_2()
+            .py_1()
+            .rounded(colors::RADIUS)
+            .bg(colors::BACKGROUND)
+            .border_1()
+            .border_color(colors::PRIMARY)
+            .shadow_md()
+            .text_xs()
+            .text_color(colors::FOREGROUND)
+            .child("⋮⋮")
+    }
+}
+
+/// A virtualized list of equally tall rows. Only the rows scrolled into
+/// view are built each frame, so it stays cheap with thousands of items.
+///
+/// The row closure receives the row index. To render from view state, build
+/// it with `cx.processor`:
+///
+/// ```ignore
+/// List::new("todos", self.todos.len(), cx.processor(|this, ix: usize, _window, cx| {
+///     this.render_todo(ix, cx).into_any_element()
+/// }))
+/// ```
+#[derive(IntoElement)]
+pub struct List {
+    id: ElementId,
+    item_count: usize,
+    render_item: Rc<dyn Fn(usize, &mut Window, &mut App) -> AnyElement + 'static>,
+    height: Option<Pixels>,
+    scroll_handle: Option<UniformListScrollHandle>,
+    empty_state: Option<AnyElement>,
+    on_reorder: Option<Rc<dyn Fn(&ListReorder, &mut Window, &mut App) + 'static>>,
+}
+
+impl List {
+    pub fn new(
+        id: impl Into<ElementId>,
+        item_count: usize,
+        render_item: impl Fn(usize, &mut Window, &mut App) -> AnyElement + 'static,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            item_count,
+            render_item: Rc::new(render_item),
+            height: None,
+            scroll_handle: None,
+            empty_state: None,
+            on_reorder: None,
+        }
+    }
+
+    /// Fixes the list's height. Without it the list fills its parent, which
+    /// must then have a definite height.
+    pub fn height(mut self, height: impl Into<Pixels>) -> Self {
+        self.height = Some(height.into());
+        self
+    }
+
+    /// Lets the owner scroll the list programmatically, e.g. to reveal a
+    /// newly added row with `scroll_to_item`.
+    pub fn track_scroll(mut self, handle: UniformListScrollHandle) -> Self {
+        self.scroll_handle = Some(handle);
+        self
+    }
+
+    /// Shown instead of the list when it has no rows.
+    pub fn empty_state(mut self, element: impl IntoElement) -> Self {
+        self.empty_state = Some(element.into_any_element());
+        self
+    }
+
+    /// Makes rows reorderable by dragging the handle shown at their start.
+    /// The list stays controlled: move the data in the handler.
+    pub fn on_reorder(
+        mut self,
+        handler: impl Fn(&ListReorder, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_reorder = Some(Rc::new(handler));
+        self
+    }
+}
+
+/// Wraps a row with a drag handle, a drop indicator, and the drop handler.
+fn reorderable_row(
+    list_id: ElementId,
+    index: usize,
+    row: AnyElement,
+    on_reorder: Rc<dyn Fn(&ListReorder, &mut Window, &mut App) + 'static>,
+) -> AnyElement {
+    let drop_list_id = list_id.clone();
+
+    div()
+        .id(("list-row", index))
+        .flex()
+        .items_center()
+        .w_full()
+        // The line marks where the row will land: above the target when
+        // moving up, below it when moving down.
+        .drag_over::<DraggedRow>(move |style, dragged, _, _| {
+            let style = if dragged.index < index {
+                style.border_b_2()
+            } else {
+                style.border_t_2()
+            };
+            style.border_color(colors::PRIMARY)
+        })
+        .on_drop(move |dragged: &DraggedRow, window, cx| {
+            if dragged.list_id == drop_list_id && dragged.index != index {
+                on_reorder(
+                    &ListReorder {
+                        from: dragged.index,
+                        to: index,
+                    },
+                    window,
+                    cx,
+                );
+            }
+        })
+        .child(
+            div()
+                .id(("list-handle", index))
+                .flex_none()
+                .px_1()
+                .text_color(colors::HOVER_BORDER)
+                .cursor_grab()
+                .child("⋮⋮")
+                .on_drag(
+                    DraggedRow { list_id, index },
+                    |_, _, _, cx| cx.new(|_| DraggedRowPreview),
+                ),
+        )
+        .child(div().flex_1().min_w_0().child(row))
+        .into_any_element()
+}
+
+impl RenderOnce for List {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let container = div()
+            .flex()
+            .flex_col()
+            .w_full()
+            .map(|container| match self.height {
+                Some(height) => container.h(height),
+                None => container.h_full(),
+            });
+
+        if self.item_count == 0 {
+            if let Some(empty_state) = self.empty_state {
+                return container
+                    .items_center()
+                    .justify_center()
+                    .child(empty_state);
+            }
+        }
+
+        let render_item = self.render_item;
+        let on_reorder = self.on_reorder;
+        let list_id = self.id.clone();
+        let list = uniform_list(self.id, self.item_count, move |range, window, cx| {
+            range
+                .map(|index| {
+                    let row = render_item(index, window, cx);
+                    match &on_reorder {
+                        Some(on_reorder) => {
+                            reorderable_row(list_id.clone(), index, row, on_reorder.clone())
+                        }
+                        None => row,
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .size_full()
+        .when_some(self.scroll_handle, |list, handle| list.track_scroll(handle));
+
+        container.child(list)
+    }
+}
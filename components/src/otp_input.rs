@@ -0,0 +1,280 @@
+use crate::input::{input_actions, InputVariant};
+use crate::keybindings::{self, OTP_INPUT_CONTEXT};
+use crate::prelude::*;
+use gpui::{FocusHandle, Focusable, KeyDownEvent};
+
+struct OtpInputBindings;
+
+impl Global for OtpInputBindings {}
+
+/// Binds ⌘V/Ctrl+V to paste into a focused `OtpInput`. `OtpInput` calls this
+/// when it renders, so apps only need it before rebinding.
+pub fn init(cx: &mut App) {
+    if cx.has_global::<OtpInputBindings>() {
+        return;
+    }
+
+    cx.set_global(OtpInputBindings);
+    keybindings::bind(
+        "secondary-v",
+        input_actions::Paste,
+        Some(OTP_INPUT_CONTEXT),
+        cx,
+    );
+}
+
+/// A segmented one-time-code / PIN entry. Each box holds one character;
+/// typing advances to the next box, backspace moves back, and pasting fills
+/// as many boxes as the clipboard text covers. Clicking the boxes focuses
+/// the input; ⌘V/Ctrl+V pastes, and can be moved with `keybindings::rebind`
+/// in the `OTP_INPUT_CONTEXT` context.
+pub struct OtpInput {
+    id: ElementId,
+    focus_handle: FocusHandle,
+    length: usize,
+    value: String,
+    numeric: bool,
+    masked: bool,
+    variant: InputVariant,
+    disabled: bool,
+    on_change: Option<Box<dyn Fn(&str, &mut Window, &mut App) + 'static>>,
+}
+
+impl OtpInput {
+    pub fn new(id: impl Into<ElementId>, length: usize, cx: &mut App) -> Self {
+        Self {
+            id: id.into(),
+            focus_handle: cx.focus_handle(),
+            length: length.max(1),
+            value: String::new(),
+            numeric: true,
+            masked: false,
+            variant: InputVariant::Default,
+            disabled: false,
+            on_change: None,
+        }
+    }
+
+    /// Restricts entry to digits (the default). Set to false to accept any
+    /// alphanumeric character.
+    pub fn numeric(mut self, numeric: bool) -> Self {
+        self.numeric = numeric;
+        self
+    }
+
+    /// Shows entered characters as bullets, e.g. for PINs.
+    pub fn masked(mut self, masked: bool) -> Self {
+        self.masked = masked;
+        self
+    }
+
+    /// Sets the visual state, e.g. `InputVariant::Error` after a wrong code.
+    pub fn variant(mut self, variant: InputVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Called with the assembled code whenever a box changes.
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(&str, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Box::new(handler));
+        self
+    }
+
+    /// Returns the code entered so far.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns whether every box has been filled.
+    pub fn is_complete(&self) -> bool {
+        self.value.chars().count() == self.length
+    }
+
+    /// Clears every box and moves back to the first one.
+    pub fn clear(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.set_value(String::new(), window, cx);
+    }
+
+    fn accepts(&self, ch: char) -> bool {
+        if self.numeric {
+            ch.is_ascii_digit()
+        } else {
+            ch.is_alphanumeric()
+        }
+    }
+
+    fn set_value(&mut self, value: String, window: &mut Window, cx: &mut Context<Self>) {
+        if value == self.value {
+            return;
+        }
+        self.value = value;
+        if let Some(on_change) = &self.on_change {
+            on_change(&self.value, window, cx);
+        }
+        cx.notify();
+    }
+
+    fn on_key_down(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if self.disabled {
+            return;
+        }
+
+        let keystroke = &event.keystroke;
+        if keystroke.modifiers.platform || keystroke.modifiers.control {
+            // Leave shortcuts such as paste to their actions.
+            return;
+        }
+
+        if keystroke.key == "backspace" {
+            let mut value = self.value.clone();
+            value.pop();
+            self.set_value(value, window, cx);
+            cx.stop_propagation();
+            return;
+        }
+
+        let Some(ch) = keystroke.key_char.as_ref().and_then(|text| text.chars().next()) else {
+            return;
+        };
+        if self.accepts(ch) && !self.is_complete() {
+            let mut value = self.value.clone();
+            value.push(ch);
+            self.set_value(value, window, cx);
+            cx.stop_propagation();
+        }
+    }
+
+    fn paste(&mut self, _: &input_actions::Paste, window: &mut Window, cx: &mut Context<Self>) {
+        if self.disabled {
+            return;
+        }
+        if let Some(text) = cx.read_from_clipboard().and_then(|item| item.text()) {
+            let value: String = text
+                .chars()
+                .filter(|ch| self.accepts(*ch))
+                .take(self.length)
+                .collect();
+            if !value.is_empty() {
+                self.set_value(value, window, cx);
+            }
+        }
+    }
+
+    fn get_border_color(&self, active: bool) -> Rgba {
+        if self.disabled {
+            return colors::BORDER;
+        }
+
+        match self.variant {
+            InputVariant::Error => colors::DESTRUCTIVE,
+            InputVariant::Success => colors::SUCCESS,
+            InputVariant::Warning => colors::WARNING,
+            InputVariant::Default if active => colors::PRIMARY,
+            InputVariant::Default => colors::INPUT_BORDER,
+        }
+    }
+}
+
+impl Render for OtpInput {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        init(cx);
+        let is_focused = self.focus_handle.is_focused(window);
+        let chars: Vec<char> = self.value.chars().collect();
+        let active_index = chars.len().min(self.length - 1);
+
+        div()
+            .id(self.id.clone())
+            .key_context(OTP_INPUT_CONTEXT)
+            .track_focus(&self.focus_handle)
+            .flex()
+            .items_center()
+            .gap_2()
+            .on_key_down(cx.listener(Self::on_key_down))
+            .on_action(cx.listener(Self::paste))
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|this, _, window, _| {
+                    if !this.disabled {
+                        window.focus(&this.focus_handle);
+                    }
+                }),
+            )
+            .when(self.disabled, |row| row.cursor_not_allowed())
+            .children((0..self.length).map(|index| {
+                let active = is_focused && index == active_index;
+                let text: SharedString = match chars.get(index) {
+                    Some(_) if self.masked => "•".into(),
+                    Some(ch) => ch.to_string().into(),
+                    None => "".into(),
+                };
+
+                div()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .size(px(40.0))
+                    .rounded(px(6.0))
+                    .border_1()
+                    .when(active, |cell| cell.border_2())
+                    .border_color(self.get_border_color(active))
+                    .bg(if self.disabled {
+                        colors::MUTED
+                    } else {
+                        colors::BACKGROUND
+                    })
+                    .text_lg()
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .text_color(colors::FOREGROUND)
+                    .child(text)
+            }))
+    }
+}
+
+impl Focusable for OtpInput {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OtpInput;
+    use gpui::{div, point, prelude::*, px, ClipboardItem, Entity, Modifiers, TestAppContext, Window};
+
+    struct Form {
+        otp: Entity<OtpInput>,
+    }
+
+    impl Render for Form {
+        fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+            div().size_full().child(self.otp.clone())
+        }
+    }
+
+    #[gpui::test]
+    fn click_then_paste_fills_the_boxes(cx: &mut TestAppContext) {
+        let (form, cx) = cx.add_window_view(|_, cx| Form {
+            otp: cx.new(|cx| OtpInput::new("code", 6, cx)),
+        });
+        cx.run_until_parked();
+
+        // The first box, at the window's top left
+        cx.simulate_click(point(px(20.0), px(20.0)), Modifiers::none());
+        cx.write_to_clipboard(ClipboardItem::new_string("12-34 56 7".to_string()));
+        cx.simulate_keystrokes("secondary-v");
+
+        let otp = form.read_with(cx, |form, _| form.otp.clone());
+        otp.read_with(cx, |otp, _| {
+            assert_eq!(otp.value(), "123456");
+            assert!(otp.is_complete());
+        });
+    }
+}
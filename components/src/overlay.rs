@@ -0,0 +1,159 @@
+use crate::prelude::*;
+use std::rc::Rc;
+
+/// Where an `Overlay` is placed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum OverlayKind {
+    /// Covers the whole window and centers its content.
+    Modal,
+    /// Floats next to the point where it sits in the layout, kept inside the
+    /// window.
+    Popover { anchor: Corner, offset: Point<Pixels> },
+}
+
+/// A layer painted above normal content, shared by dialogs, popovers, menus,
+/// and other floating components.
+///
+/// Overlays are painted after the rest of the window (higher `priority`
+/// paints later) and block mouse events from reaching what is underneath.
+/// `on_outside_click` fires for presses outside the overlay's content.
+#[derive(IntoElement)]
+pub struct Overlay {
+    id: ElementId,
+    kind: OverlayKind,
+    priority: usize,
+    backdrop: Option<Hsla>,
+    children: Vec<AnyElement>,
+    on_outside_click: Option<Rc<dyn Fn(&MouseDownEvent, &mut Window, &mut App) + 'static>>,
+}
+
+impl Overlay {
+    /// A window-sized layer that centers its content, for dialogs.
+    pub fn modal(id: impl Into<ElementId>) -> Self {
+        Self::new(id, OverlayKind::Modal)
+    }
+
+    /// A floating layer anchored where it is placed in the layout, for
+    /// popovers and menus. Place it right after the trigger element.
+    pub fn popover(id: impl Into<ElementId>) -> Self {
+        Self::new(
+            id,
+            OverlayKind::Popover {
+                anchor: Corner::TopLeft,
+                offset: Point::default(),
+            },
+        )
+    }
+
+    fn new(id: impl Into<ElementId>, kind: OverlayKind) -> Self {
+        Self {
+            id: id.into(),
+            kind,
+            priority: 1,
+            backdrop: None,
+            children: Vec::new(),
+            on_outside_click: None,
+        }
+    }
+
+    /// Paint order among overlays; higher values paint on top.
+    pub fn priority(mut self, priority: usize) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Fills a modal layer with this color behind its content.
+    pub fn backdrop(mut self, color: impl Into<Hsla>) -> Self {
+        self.backdrop = Some(color.into());
+        self
+    }
+
+    /// Which corner of a popover sits on its anchor point.
+    pub fn anchor(mut self, anchor: Corner) -> Self {
+        if let OverlayKind::Popover { offset, .. } = self.kind {
+            self.kind = OverlayKind::Popover { anchor, offset };
+        }
+        self
+    }
+
+    /// Shifts a popover away from its anchor point.
+    pub fn offset(mut self, offset: Point<Pixels>) -> Self {
+        if let OverlayKind::Popover { anchor, .. } = self.kind {
+            self.kind = OverlayKind::Popover { anchor, offset };
+        }
+        self
+    }
+
+    /// Called when the mouse is pressed outside the overlay's content.
+    pub fn on_outside_click(
+        mut self,
+        handler: impl Fn(&MouseDownEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_outside_click = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn child(mut self, child: impl IntoElement) -> Self {
+        self.children.push(child.into_any_element());
+        self
+    }
+
+    pub fn children(mut self, children: impl IntoIterator<Item = impl IntoElement>) -> Self {
+        self.children
+            .extend(children.into_iter().map(|child| child.into_any_element()));
+        self
+    }
+}
+
+impl RenderOnce for Overlay {
+    fn render(self, window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let on_outside_click = self.on_outside_click;
+
+        let layer = match self.kind {
+            OverlayKind::Modal => {
+                let viewport = window.viewport_size();
+
+                // Presses on the layer itself are outside the content; the
+                // content stops them from reaching the layer.
+                let content = div()
+                    .on_mouse_down(MouseButton::Left, |_, _, cx| cx.stop_propagation())
+                    .children(self.children);
+
+                anchored().position(Point::default()).child(
+                    div()
+                        .id(self.id)
+                        .occlude()
+                        .w(viewport.width)
+                        .h(viewport.height)
+                        .flex()
+                        .items_center()
+                        .justify_center()
+                        .when_some(self.backdrop, |layer, backdrop| layer.bg(backdrop))
+                        .when_some(on_outside_click, |layer, handler| {
+                            layer.on_mouse_down(MouseButton::Left, move |event, window, cx| {
+                                handler(event, window, cx)
+                            })
+                        })
+                        .child(content),
+                )
+            }
+            OverlayKind::Popover { anchor, offset } => anchored()
+                .anchor(anchor)
+                .offset(offset)
+                .snap_to_window_with_margin(px(8.0))
+                .child(
+                    div()
+                        .id(self.id)
+                        .occlude()
+                        .when_some(on_outside_click, |layer, handler| {
+                            layer.on_mouse_down_out(move |event, window, cx| {
+                                handler(event, window, cx)
+                            })
+                        })
+                        .children(self.children),
+                ),
+        };
+
+        deferred(layer).with_priority(self.priority)
+    }
+}
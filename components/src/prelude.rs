@@ -3,4 +3,8 @@ pub use gpui::*;
 pub use gpui::prelude::*;
 
 // Re-export our traits and types
-pub use crate::traits::{Clickable, Disableable, Toggleable, ToggleState};
+pub use crate::breakpoint::Breakpoint;
+pub use crate::colors;
+pub use crate::size::{ComponentSize, SizePreset};
+pub use crate::theme::{ActiveTheme, Theme};
+pub use crate::traits::{Accessible, Clickable, Disableable, Hoverable, Selectable, Toggleable, ToggleState};
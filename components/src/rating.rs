@@ -0,0 +1,236 @@
+use crate::prelude::*;
+use std::rc::Rc;
+
+/// Rounds a rating to the nearest whole star, or the nearest half star when
+/// `allow_half` is set, and clamps it to `0.0..=max`.
+pub fn round_rating(value: f32, max: usize, allow_half: bool) -> f32 {
+    if !value.is_finite() {
+        return 0.0;
+    }
+
+    let rounded = if allow_half {
+        (value * 2.0).round() / 2.0
+    } else {
+        value.round()
+    };
+
+    rounded.clamp(0.0, max as f32)
+}
+
+/// Rating size options
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RatingSize {
+    Small,
+    Medium,
+    Large,
+}
+
+struct RatingState {
+    hovered: Option<f32>,
+}
+
+/// A star rating that previews on hover and commits on click
+#[derive(IntoElement)]
+pub struct Rating {
+    id: ElementId,
+    value: f32,
+    max: usize,
+    size: RatingSize,
+    allow_half: bool,
+    readonly: bool,
+    on_change: Option<Box<dyn Fn(&f32, &mut Window, &mut App) + 'static>>,
+}
+
+impl Rating {
+    pub fn new(id: impl Into<ElementId>, value: f32) -> Self {
+        Self {
+            id: id.into(),
+            value,
+            max: 5,
+            size: RatingSize::Medium,
+            allow_half: false,
+            readonly: false,
+            on_change: None,
+        }
+    }
+
+    /// Sets the number of stars.
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = max;
+        self
+    }
+
+    pub fn size(mut self, size: RatingSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Allows half-star values, picked by hovering the left half of a star.
+    pub fn allow_half(mut self, allow_half: bool) -> Self {
+        self.allow_half = allow_half;
+        self
+    }
+
+    /// Renders the rating for display only, ignoring hover and clicks.
+    pub fn readonly(mut self, readonly: bool) -> Self {
+        self.readonly = readonly;
+        self
+    }
+
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(&f32, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Box::new(handler));
+        self
+    }
+
+    fn get_star_size(&self) -> Pixels {
+        match self.size {
+            RatingSize::Small => px(14.0),
+            RatingSize::Medium => px(18.0),
+            RatingSize::Large => px(24.0),
+        }
+    }
+
+    // The palette's `accent` is a near-white hover surface, lighter than the
+    // empty stars, so filled stars use the amber `warning` color instead.
+    fn get_filled_color(&self, cx: &App) -> Rgba {
+        cx.theme().warning
+    }
+
+    fn get_empty_color(&self) -> Rgba {
+        colors::INPUT_BORDER
+    }
+}
+
+impl RenderOnce for Rating {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let state = window.use_keyed_state(self.id.clone(), cx, |_, _| RatingState {
+            hovered: None,
+        });
+
+        let star_size = self.get_star_size();
+        let filled_color = self.get_filled_color(cx);
+        let empty_color = self.get_empty_color();
+        let interactive = !self.readonly && self.on_change.is_some();
+
+        let committed = round_rating(self.value, self.max, self.allow_half);
+        let shown = if interactive {
+            state.read(cx).hovered.unwrap_or(committed)
+        } else {
+            committed
+        };
+
+        let on_change = self.on_change.map(Rc::new);
+        let steps: &[f32] = if self.allow_half { &[0.5, 1.0] } else { &[1.0] };
+
+        let stars = (0..self.max).map(|index| {
+            let fill = (shown - index as f32).clamp(0.0, 1.0);
+
+            let mut star = div()
+                .relative()
+                .size(star_size)
+                .text_size(star_size)
+                .line_height(star_size)
+                .text_color(empty_color)
+                .child("★")
+                .child(
+                    div()
+                        .absolute()
+                        .top_0()
+                        .left_0()
+                        .h_full()
+                        .w(relative(fill))
+                        .overflow_hidden()
+                        .whitespace_nowrap()
+                        .text_color(filled_color)
+                        .child("★"),
+                );
+
+            if interactive {
+                // One hit area per selectable step, so half stars are picked
+                // by hovering the left half of a star.
+                let width = 1.0 / steps.len() as f32;
+                for (step_index, step) in steps.iter().enumerate() {
+                    let candidate = index as f32 + step;
+                    let state = state.clone();
+                    let on_change = on_change.clone();
+
+                    star = star.child(
+                        div()
+                            .id(("rating-step", index * steps.len() + step_index))
+                            .absolute()
+                            .top_0()
+                            .h_full()
+                            .left(relative(width * step_index as f32))
+                            .w(relative(width))
+                            .cursor_pointer()
+                            .on_hover({
+                                let state = state.clone();
+                                move |hovered, _window, cx| {
+                                    if *hovered {
+                                        state.update(cx, |state, cx| {
+                                            state.hovered = Some(candidate);
+                                            cx.notify();
+                                        });
+                                    }
+                                }
+                            })
+                            .on_click(move |_event, window, cx| {
+                                if let Some(on_change) = &on_change {
+                                    on_change(&candidate, window, cx);
+                                }
+                            }),
+                    );
+                }
+            }
+
+            star
+        });
+
+        div()
+            .id(self.id)
+            .flex()
+            .items_center()
+            .gap_1()
+            .children(stars)
+            .when(interactive, |rating| {
+                rating.on_hover(move |hovered, _window, cx| {
+                    if !*hovered {
+                        state.update(cx, |state, cx| {
+                            state.hovered = None;
+                            cx.notify();
+                        });
+                    }
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::round_rating;
+
+    #[test]
+    fn test_round_rating_whole_stars() {
+        assert_eq!(round_rating(3.4, 5, false), 3.0);
+        assert_eq!(round_rating(3.5, 5, false), 4.0);
+        assert_eq!(round_rating(0.2, 5, false), 0.0);
+    }
+
+    #[test]
+    fn test_round_rating_half_stars() {
+        assert_eq!(round_rating(3.2, 5, true), 3.0);
+        assert_eq!(round_rating(3.3, 5, true), 3.5);
+        assert_eq!(round_rating(3.74, 5, true), 3.5);
+        assert_eq!(round_rating(3.75, 5, true), 4.0);
+    }
+
+    #[test]
+    fn test_round_rating_clamps() {
+        assert_eq!(round_rating(-1.0, 5, true), 0.0);
+        assert_eq!(round_rating(7.0, 5, true), 5.0);
+        assert_eq!(round_rating(f32::NAN, 5, true), 0.0);
+    }
+}
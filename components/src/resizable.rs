@@ -0,0 +1,251 @@
+use crate::prelude::*;
+use std::rc::Rc;
+
+/// Direction panels are laid out in
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ResizeOrientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+/// A panel inside a `ResizablePanelGroup`
+pub struct ResizablePanel {
+    child: AnyElement,
+    min_size: f32,
+    max_size: f32,
+}
+
+impl ResizablePanel {
+    pub fn new(child: impl IntoElement) -> Self {
+        Self {
+            child: child.into_any_element(),
+            min_size: 0.0,
+            max_size: 1.0,
+        }
+    }
+
+    /// Smallest share of the group this panel may shrink to, from 0.0 to 1.0.
+    pub fn min_size(mut self, min_size: f32) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Largest share of the group this panel may grow to, from 0.0 to 1.0.
+    pub fn max_size(mut self, max_size: f32) -> Self {
+        self.max_size = max_size;
+        self
+    }
+}
+
+/// Payload carried while a handle between two panels is dragged.
+#[derive(Clone)]
+struct DraggedHandle {
+    group: ElementId,
+    index: usize,
+}
+
+/// Invisible drag preview; the handle itself shows the drag state.
+struct HandleDragPreview;
+
+impl Render for HandleDragPreview {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+    }
+}
+
+/// Computes new panel sizes after the handle after `index` is dragged to
+/// `position`, a fraction of the group's length. Only the two panels
+/// adjacent to the handle change; the rest keep their share.
+pub fn resize_panels(
+    sizes: &[f32],
+    limits: &[(f32, f32)],
+    index: usize,
+    position: f32,
+) -> Vec<f32> {
+    let mut sizes = sizes.to_vec();
+    if index + 1 >= sizes.len() {
+        return sizes;
+    }
+
+    let before: f32 = sizes[..index].iter().sum();
+    let pair = sizes[index] + sizes[index + 1];
+    let (first_min, first_max) = limits.get(index).copied().unwrap_or((0.0, 1.0));
+    let (second_min, second_max) = limits.get(index + 1).copied().unwrap_or((0.0, 1.0));
+
+    // The first panel's bounds, tightened so the second stays within its own.
+    let low = first_min.max(pair - second_max).max(0.0);
+    let high = first_max.min(pair - second_min).min(pair);
+    if low > high {
+        return sizes;
+    }
+
+    let first = (position - before).clamp(low, high);
+    sizes[index] = first;
+    sizes[index + 1] = pair - first;
+    sizes
+}
+
+/// Panels separated by draggable handles. Sizes are controlled: the group
+/// renders `sizes` (fractions summing to 1.0) and reports new proportions via
+/// `on_resize`, which the caller stores in its model.
+#[derive(IntoElement)]
+pub struct ResizablePanelGroup {
+    id: ElementId,
+    orientation: ResizeOrientation,
+    panels: Vec<ResizablePanel>,
+    sizes: Vec<f32>,
+    on_resize: Option<Rc<dyn Fn(&[f32], &mut Window, &mut App) + 'static>>,
+}
+
+impl ResizablePanelGroup {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            orientation: ResizeOrientation::Horizontal,
+            panels: Vec::new(),
+            sizes: Vec::new(),
+            on_resize: None,
+        }
+    }
+
+    pub fn orientation(mut self, orientation: ResizeOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn panel(mut self, panel: ResizablePanel) -> Self {
+        self.panels.push(panel);
+        self
+    }
+
+    /// Sets the share of the group each panel takes. Missing entries are
+    /// filled in evenly.
+    pub fn sizes(mut self, sizes: impl Into<Vec<f32>>) -> Self {
+        self.sizes = sizes.into();
+        self
+    }
+
+    pub fn on_resize(
+        mut self,
+        handler: impl Fn(&[f32], &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_resize = Some(Rc::new(handler));
+        self
+    }
+
+    fn get_sizes(&self) -> Vec<f32> {
+        let count = self.panels.len();
+        if self.sizes.len() == count {
+            return self.sizes.clone();
+        }
+        vec![1.0 / count.max(1) as f32; count]
+    }
+}
+
+impl RenderOnce for ResizablePanelGroup {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let sizes = self.get_sizes();
+        let limits: Vec<(f32, f32)> = self
+            .panels
+            .iter()
+            .map(|panel| (panel.min_size, panel.max_size))
+            .collect();
+        let orientation = self.orientation;
+        let horizontal = orientation == ResizeOrientation::Horizontal;
+        let group_id = self.id.clone();
+        let panel_count = self.panels.len();
+
+        let mut group = div()
+            .id(self.id.clone())
+            .flex()
+            .size_full()
+            .when(!horizontal, |group| group.flex_col());
+
+        for (index, panel) in self.panels.into_iter().enumerate() {
+            let share = relative(sizes[index]);
+            group = group.child(
+                div()
+                    .relative()
+                    .overflow_hidden()
+                    .flex_shrink()
+                    .map(|panel| {
+                        if horizontal {
+                            panel.h_full().w(share)
+                        } else {
+                            panel.w_full().h(share)
+                        }
+                    })
+                    .child(panel.child),
+            );
+
+            if index + 1 < panel_count {
+                let dragged = DraggedHandle {
+                    group: group_id.clone(),
+                    index,
+                };
+                group = group.child(
+                    div()
+                        .id(("resize-handle", index))
+                        .flex_none()
+                        .bg(colors::BORDER)
+                        .hover(|style| style.bg(colors::PRIMARY))
+                        .map(|handle| {
+                            if horizontal {
+                                handle.w(px(4.0)).h_full().cursor_col_resize()
+                            } else {
+                                handle.h(px(4.0)).w_full().cursor_row_resize()
+                            }
+                        })
+                        .on_drag(dragged, |_handle, _offset, _window, cx| {
+                            cx.new(|_| HandleDragPreview)
+                        }),
+                );
+            }
+        }
+
+        let on_resize = self.on_resize;
+        group.on_drag_move::<DraggedHandle>(move |event, window, cx| {
+            let handle = event.drag(cx).clone();
+            if handle.group != group_id {
+                return;
+            }
+            let Some(on_resize) = on_resize.as_ref() else {
+                return;
+            };
+
+            let bounds = event.bounds;
+            let position = event.event.position;
+            let fraction = if horizontal {
+                (position.x - bounds.left()) / bounds.size.width
+            } else {
+                (position.y - bounds.top()) / bounds.size.height
+            };
+
+            let new_sizes = resize_panels(&sizes, &limits, handle.index, fraction);
+            on_resize(&new_sizes, window, cx);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::resize_panels;
+
+    #[test]
+    fn test_resize_panels_moves_adjacent_pair() {
+        let sizes = resize_panels(&[0.25, 0.25, 0.5], &[], 0, 0.4);
+        assert!((sizes[0] - 0.4).abs() < f32::EPSILON);
+        assert!((sizes[1] - 0.1).abs() < 1e-6);
+        assert!((sizes[2] - 0.5).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_resize_panels_respects_limits() {
+        let limits = [(0.2, 0.6), (0.3, 1.0)];
+        let sizes = resize_panels(&[0.5, 0.5], &limits, 0, 0.9);
+        assert!((sizes[0] - 0.6).abs() < 1e-6);
+        let sizes = resize_panels(&[0.5, 0.5], &limits, 0, 0.05);
+        assert!((sizes[0] - 0.2).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,433 @@
+use crate::accessibility::{self, AccessibleNode, Role};
+use crate::focus_visible::{self, InputModality};
+use crate::overlay::Overlay;
+use crate::prelude::*;
+use std::rc::Rc;
+
+/// Select size options, drawn from the shared `SizeScale`
+pub type SelectSize = ComponentSize;
+
+/// One choice in a `Select`: the value reported to `on_change` and the label
+/// shown for it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelectOption {
+    pub value: SharedString,
+    pub label: SharedString,
+}
+
+impl SelectOption {
+    pub fn new(value: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+        }
+    }
+}
+
+/// Moves the highlight one option up or down, wrapping at either end.
+fn step_highlight(highlighted: usize, count: usize, forward: bool) -> usize {
+    if count == 0 {
+        return 0;
+    }
+    if forward {
+        (highlighted + 1) % count
+    } else {
+        (highlighted + count - 1) % count
+    }
+}
+
+/// Popup state kept across frames.
+struct SelectState {
+    open: bool,
+    /// The option Enter picks while the list is open.
+    highlighted: usize,
+    /// The trigger's width, so the list can match it.
+    trigger_width: Option<Pixels>,
+}
+
+/// A dropdown that picks one value from a list of options.
+///
+/// The value is controlled: pass the current one to `value` and update it
+/// from `on_change`. Clicking the trigger opens the list below it; clicking
+/// an option picks it, and clicking anywhere else closes the list. With
+/// `track_focus`, Up and Down move through the options, Enter or Space picks
+/// the highlighted one, and Escape closes the list.
+#[derive(IntoElement)]
+pub struct Select {
+    id: ElementId,
+    options: Vec<SelectOption>,
+    value: Option<SharedString>,
+    placeholder: SharedString,
+    size: SelectSize,
+    disabled: bool,
+    accessible_name: Option<SharedString>,
+    focus_handle: Option<FocusHandle>,
+    on_change: Option<Rc<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+}
+
+impl Select {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            options: Vec::new(),
+            value: None,
+            placeholder: "Select an option".into(),
+            size: SelectSize::Medium,
+            disabled: false,
+            accessible_name: None,
+            focus_handle: None,
+            on_change: None,
+        }
+    }
+
+    /// Adds an option with the given value and label.
+    pub fn option(
+        mut self,
+        value: impl Into<SharedString>,
+        label: impl Into<SharedString>,
+    ) -> Self {
+        self.options.push(SelectOption::new(value, label));
+        self
+    }
+
+    pub fn options(mut self, options: impl IntoIterator<Item = SelectOption>) -> Self {
+        self.options.extend(options);
+        self
+    }
+
+    /// The value of the selected option, or `None` to show the placeholder.
+    pub fn value(mut self, value: Option<SharedString>) -> Self {
+        self.value = value;
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    pub fn size(mut self, size: SelectSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Makes the select focusable with the given handle, enabling keyboard
+    /// selection. A focus ring shows when focus arrives by keyboard.
+    pub fn track_focus(mut self, handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(handle.clone());
+        self
+    }
+
+    /// Called with the value of the option picked. Picking the current value
+    /// again doesn't call it.
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        let value = self.value.as_ref()?;
+        self.options.iter().position(|option| &option.value == value)
+    }
+}
+
+impl Accessible for Select {
+    fn accessible_name(mut self, name: impl Into<SharedString>) -> Self {
+        self.accessible_name = Some(name.into());
+        self
+    }
+}
+
+/// Closes the list and reports `value` if it differs from `current`.
+fn pick(
+    state: &Entity<SelectState>,
+    value: SharedString,
+    current: Option<&SharedString>,
+    on_change: Option<&Rc<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    state.update(cx, |state, cx| {
+        state.open = false;
+        cx.notify();
+    });
+    if current != Some(&value) {
+        if let Some(on_change) = on_change {
+            on_change(&value, window, cx);
+        }
+    }
+}
+
+impl RenderOnce for Select {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        focus_visible::init(cx);
+        let preset = self.size.preset(cx);
+        let theme = cx.theme();
+        let selected = self.selected_index();
+        let state = window.use_keyed_state(self.id.clone(), cx, |_, _| SelectState {
+            open: false,
+            highlighted: 0,
+            trigger_width: None,
+        });
+        let (open, highlighted, trigger_width) = {
+            let state = state.read(cx);
+            let open = state.open && !self.disabled;
+            (open, state.highlighted, state.trigger_width)
+        };
+
+        let label = selected.map(|index| self.options[index].label.clone());
+        accessibility::expose(
+            &self.id,
+            AccessibleNode {
+                role: Role::ComboBox,
+                name: self
+                    .accessible_name
+                    .clone()
+                    .or_else(|| label.clone())
+                    .unwrap_or_else(|| self.placeholder.clone()),
+                disabled: self.disabled,
+                toggle_state: None,
+            },
+            window,
+            cx,
+        );
+
+        // Records the trigger's width whenever it's laid out.
+        let measure_state = state.clone();
+        let measure = canvas(
+            move |bounds, _, cx| {
+                measure_state.update(cx, |state, _| {
+                    state.trigger_width = Some(bounds.size.width);
+                })
+            },
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full();
+
+        let show_ring = !self.disabled
+            && self
+                .focus_handle
+                .as_ref()
+                .is_some_and(|handle| focus_visible::is_focus_visible(handle, window, cx));
+        let text_color = if self.disabled {
+            colors::DISABLED_FOREGROUND
+        } else if label.is_some() {
+            theme.foreground
+        } else {
+            theme.muted_foreground
+        };
+
+        let mut trigger = div()
+            .id("trigger")
+            .relative()
+            .flex()
+            .items_center()
+            .justify_between()
+            .gap_2()
+            .w_full()
+            .min_h(preset.min_height)
+            .px(preset.padding)
+            .bg(if self.disabled {
+                theme.muted
+            } else {
+                theme.background
+            })
+            .border_1()
+            .border_color(if open { theme.primary } else { theme.border })
+            .rounded(preset.radius)
+            .text_size(preset.text_size)
+            .text_color(text_color)
+            .when(show_ring, |trigger| {
+                trigger.shadow(focus_visible::focus_ring(theme.primary))
+            })
+            .child(
+                div()
+                    .min_w_0()
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .child(label.unwrap_or_else(|| self.placeholder.clone())),
+            )
+            .child(
+                div()
+                    .flex_none()
+                    .text_color(theme.muted_foreground)
+                    .child(if open { "▴" } else { "▾" }),
+            )
+            .child(measure);
+
+        if self.disabled {
+            // Swallow clicks so they don't fall through to a clickable parent.
+            trigger = trigger
+                .cursor_not_allowed()
+                .on_click(|_, _, cx| cx.stop_propagation());
+        } else {
+            let toggle_state = state.clone();
+            trigger = trigger
+                .cursor_pointer()
+                .hover(|style| style.border_color(colors::HOVER_BORDER))
+                .on_click(move |_, _, cx| {
+                    toggle_state.update(cx, |state, cx| {
+                        state.open = !state.open;
+                        state.highlighted = selected.unwrap_or(0);
+                        cx.notify();
+                    });
+                    cx.stop_propagation();
+                });
+        }
+
+        let count = self.options.len();
+        let on_change = self.on_change;
+        let value = self.value;
+
+        if let Some(handle) = self.focus_handle.as_ref().filter(|_| !self.disabled) {
+            let key_state = state.clone();
+            let on_change = on_change.clone();
+            let value = value.clone();
+            let options = self.options.clone();
+            trigger = trigger
+                .track_focus(handle)
+                .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                    focus_visible::set_modality(InputModality::Pointer, cx)
+                })
+                .on_key_down(move |event, window, cx| {
+                    let key = event.keystroke.key.as_str();
+                    let open = key_state.read(cx).open;
+                    match key {
+                        "down" | "up" if open => key_state.update(cx, |state, cx| {
+                            state.highlighted =
+                                step_highlight(state.highlighted, count, key == "down");
+                            cx.notify();
+                        }),
+                        "down" | "up" | "enter" | "space" if !open => {
+                            key_state.update(cx, |state, cx| {
+                                state.open = true;
+                                state.highlighted = selected.unwrap_or(0);
+                                cx.notify();
+                            })
+                        }
+                        "enter" | "space" => {
+                            let highlighted = key_state.read(cx).highlighted;
+                            if let Some(option) = options.get(highlighted) {
+                                pick(
+                                    &key_state,
+                                    option.value.clone(),
+                                    value.as_ref(),
+                                    on_change.as_ref(),
+                                    window,
+                                    cx,
+                                );
+                            }
+                        }
+                        "escape" if open => key_state.update(cx, |state, cx| {
+                            state.open = false;
+                            cx.notify();
+                        }),
+                        _ => return,
+                    }
+                    cx.stop_propagation();
+                });
+        }
+
+        let list = open.then(|| {
+            let items = self.options.into_iter().enumerate().map(|(index, option)| {
+                let is_selected = selected == Some(index);
+                let item_state = state.clone();
+                let hover_state = state.clone();
+                let on_change = on_change.clone();
+                let value = value.clone();
+
+                div()
+                    .id(("option", index))
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .gap_2()
+                    .px(preset.padding / 2.0)
+                    .py(preset.padding / 4.0)
+                    .rounded(preset.radius)
+                    .cursor_pointer()
+                    .text_color(theme.foreground)
+                    .when(index == highlighted, |item| item.bg(theme.muted))
+                    .when(is_selected, |item| item.font_weight(FontWeight::MEDIUM))
+                    .on_hover(move |hovered, _, cx| {
+                        if *hovered {
+                            hover_state.update(cx, |state, cx| {
+                                state.highlighted = index;
+                                cx.notify();
+                            });
+                        }
+                    })
+                    .on_click(move |_, window, cx| {
+                        pick(
+                            &item_state,
+                            option.value.clone(),
+                            value.as_ref(),
+                            on_change.as_ref(),
+                            window,
+                            cx,
+                        );
+                        cx.stop_propagation();
+                    })
+                    .child(option.label)
+                    .when(is_selected, |item| {
+                        item.child(div().text_color(theme.primary).child("✓"))
+                    })
+            });
+
+            let close_state = state.clone();
+            Overlay::popover("options")
+                .on_outside_click(move |_, _, cx| {
+                    close_state.update(cx, |state, cx| {
+                        state.open = false;
+                        cx.notify();
+                    });
+                })
+                .child(
+                    div()
+                        .mt_1()
+                        .p_1()
+                        .flex()
+                        .flex_col()
+                        .min_w(trigger_width.unwrap_or(px(160.0)))
+                        .bg(theme.background)
+                        .border_1()
+                        .border_color(theme.border)
+                        .rounded(preset.radius)
+                        .shadow_md()
+                        .text_size(preset.text_size)
+                        .children(items),
+                )
+        });
+
+        div()
+            .id(self.id)
+            .flex()
+            .flex_col()
+            .child(trigger)
+            .when_some(list, |this, list| this.child(list))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::step_highlight;
+
+    #[test]
+    fn highlight_wraps_at_both_ends() {
+        assert_eq!(step_highlight(0, 3, true), 1);
+        assert_eq!(step_highlight(2, 3, true), 0);
+        assert_eq!(step_highlight(0, 3, false), 2);
+        assert_eq!(step_highlight(0, 0, true), 0);
+    }
+}
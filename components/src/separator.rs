@@ -0,0 +1,53 @@
+use crate::prelude::*;
+
+/// Direction a `Separator` runs in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SeparatorOrientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+/// A thin divider line between groups of content.
+#[derive(IntoElement)]
+pub struct Separator {
+    orientation: SeparatorOrientation,
+}
+
+impl Separator {
+    /// A full-width horizontal rule.
+    pub fn horizontal() -> Self {
+        Self {
+            orientation: SeparatorOrientation::Horizontal,
+        }
+    }
+
+    /// A vertical rule, for rows such as toolbars.
+    pub fn vertical() -> Self {
+        Self {
+            orientation: SeparatorOrientation::Vertical,
+        }
+    }
+
+    pub fn orientation(mut self, orientation: SeparatorOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+}
+
+impl Default for Separator {
+    fn default() -> Self {
+        Self::horizontal()
+    }
+}
+
+impl RenderOnce for Separator {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let line = div().flex_none().bg(colors::BORDER);
+
+        match self.orientation {
+            SeparatorOrientation::Horizontal => line.w_full().h(px(1.0)),
+            SeparatorOrientation::Vertical => line.w(px(1.0)).h(px(20.0)).mx_1(),
+        }
+    }
+}
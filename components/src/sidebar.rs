@@ -0,0 +1,232 @@
+use crate::prelude::*;
+use crate::tooltip::Tooltip;
+use std::rc::Rc;
+
+/// Sidebar width with labels shown.
+const EXPANDED_WIDTH: f32 = 224.0;
+/// Sidebar width when collapsed to icons.
+const COLLAPSED_WIDTH: f32 = 52.0;
+
+/// One navigation entry: an icon glyph, a label, and a click handler.
+pub struct SidebarItem {
+    id: ElementId,
+    icon: SharedString,
+    label: SharedString,
+    selected: bool,
+    on_click: Option<Box<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>>,
+}
+
+impl SidebarItem {
+    pub fn new(
+        id: impl Into<ElementId>,
+        icon: impl Into<SharedString>,
+        label: impl Into<SharedString>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            icon: icon.into(),
+            label: label.into(),
+            selected: false,
+            on_click: None,
+        }
+    }
+}
+
+impl Selectable for SidebarItem {
+    fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+}
+
+impl Clickable for SidebarItem {
+    fn on_click(
+        mut self,
+        handler: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_click = Some(Box::new(handler));
+        self
+    }
+}
+
+/// A group of items under an optional header.
+pub struct SidebarSection {
+    header: Option<SharedString>,
+    items: Vec<SidebarItem>,
+}
+
+impl SidebarSection {
+    pub fn new() -> Self {
+        Self {
+            header: None,
+            items: Vec::new(),
+        }
+    }
+
+    pub fn header(mut self, header: impl Into<SharedString>) -> Self {
+        self.header = Some(header.into());
+        self
+    }
+
+    pub fn item(mut self, item: SidebarItem) -> Self {
+        self.items.push(item);
+        self
+    }
+}
+
+impl Default for SidebarSection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A vertical navigation rail.
+///
+/// Items are grouped into sections, and the selected item is highlighted.
+/// When collapsed, labels and headers are hidden and each icon shows its
+/// label in a tooltip instead. Collapsing is controlled by the parent.
+#[derive(IntoElement)]
+pub struct Sidebar {
+    id: ElementId,
+    sections: Vec<SidebarSection>,
+    collapsed: bool,
+    on_toggle_collapsed: Option<Rc<dyn Fn(&bool, &mut Window, &mut App) + 'static>>,
+}
+
+impl Sidebar {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            sections: Vec::new(),
+            collapsed: false,
+            on_toggle_collapsed: None,
+        }
+    }
+
+    pub fn section(mut self, section: SidebarSection) -> Self {
+        self.sections.push(section);
+        self
+    }
+
+    /// Adds an item to the last section, starting an untitled one if needed.
+    pub fn item(mut self, item: SidebarItem) -> Self {
+        match self.sections.last_mut() {
+            Some(section) => section.items.push(item),
+            None => self.sections.push(SidebarSection::new().item(item)),
+        }
+        self
+    }
+
+    /// Shows only icons, with labels in tooltips.
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+
+    /// Shows a collapse toggle at the bottom of the rail. The handler
+    /// receives the collapsed state to move to.
+    pub fn on_toggle_collapsed(
+        mut self,
+        handler: impl Fn(&bool, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_toggle_collapsed = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl RenderOnce for Sidebar {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = cx.theme();
+        let preset = ComponentSize::Medium.preset(cx);
+        let collapsed = self.collapsed;
+
+        let render_item = |item: SidebarItem| {
+            let label = item.label.clone();
+            div()
+                .id(item.id)
+                .flex()
+                .items_center()
+                .gap_3()
+                .h(preset.min_height)
+                .px(px(10.0))
+                .rounded(theme.radius)
+                .text_size(preset.text_size)
+                .cursor_pointer()
+                .when(item.selected, |row| {
+                    row.bg(theme.accent)
+                        .text_color(theme.primary)
+                        .font_weight(FontWeight::MEDIUM)
+                })
+                .when(!item.selected, |row| {
+                    row.text_color(theme.muted_foreground)
+                        .hover(|style| style.bg(theme.muted).text_color(theme.foreground))
+                })
+                .when(collapsed, |row| row.justify_center().tooltip(Tooltip::text(label)))
+                .child(div().flex_none().w(preset.icon_size).child(item.icon))
+                .when(!collapsed, |row| {
+                    row.child(div().min_w_0().whitespace_nowrap().child(item.label))
+                })
+                .when_some(item.on_click, |row, on_click| row.on_click(on_click))
+        };
+
+        let sections = self.sections.into_iter().enumerate().map(|(index, section)| {
+            div()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .when(index > 0, |section| {
+                    section.mt_2().pt_2().border_t_1().border_color(theme.border)
+                })
+                .when_some(section.header.filter(|_| !collapsed), |this, header| {
+                    this.child(
+                        div()
+                            .px(px(10.0))
+                            .pb_1()
+                            .text_xs()
+                            .font_weight(FontWeight::SEMIBOLD)
+                            .text_color(theme.muted_foreground)
+                            .child(header),
+                    )
+                })
+                .children(section.items.into_iter().map(&render_item))
+        });
+
+        div()
+            .id(self.id)
+            .flex()
+            .flex_col()
+            .h_full()
+            .flex_none()
+            .w(px(if collapsed { COLLAPSED_WIDTH } else { EXPANDED_WIDTH }))
+            .p_2()
+            .bg(theme.background)
+            .border_r_1()
+            .border_color(theme.border)
+            .child(
+                div()
+                    .id("sidebar-items")
+                    .flex()
+                    .flex_col()
+                    .flex_1()
+                    .overflow_y_scroll()
+                    .children(sections),
+            )
+            .when_some(self.on_toggle_collapsed, |sidebar, on_toggle| {
+                sidebar.child(
+                    div()
+                        .id("collapse-toggle")
+                        .flex()
+                        .items_center()
+                        .justify_center()
+                        .h(preset.min_height)
+                        .rounded(theme.radius)
+                        .text_color(theme.muted_foreground)
+                        .cursor_pointer()
+                        .hover(|style| style.bg(theme.muted).text_color(theme.foreground))
+                        .tooltip(Tooltip::text(if collapsed { "Expand" } else { "Collapse" }))
+                        .on_click(move |_, window, cx| on_toggle(&!collapsed, window, cx))
+                        .child(if collapsed { "»" } else { "«" }),
+                )
+            })
+    }
+}
@@ -0,0 +1,91 @@
+use crate::prelude::*;
+
+/// Size scale shared by every sized component, so a `Large` badge matches a
+/// `Large` button or input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ComponentSize {
+    Small,
+    #[default]
+    Medium,
+    Large,
+}
+
+/// Dimensions derived from a `ComponentSize`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SizePreset {
+    /// Horizontal padding for text controls. Components derive their vertical
+    /// padding from it.
+    pub padding: Pixels,
+    pub text_size: Pixels,
+    pub icon_size: Pixels,
+    pub radius: Pixels,
+    pub min_height: Pixels,
+}
+
+/// The table mapping each `ComponentSize` to its dimensions.
+///
+/// Set it as a global to tweak sizes app-wide:
+///
+/// ```ignore
+/// cx.set_global(SizeScale {
+///     medium: SizePreset { min_height: px(40.0), ..SizeScale::default().medium },
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SizeScale {
+    pub small: SizePreset,
+    pub medium: SizePreset,
+    pub large: SizePreset,
+}
+
+impl Global for SizeScale {}
+
+impl Default for SizeScale {
+    fn default() -> Self {
+        Self {
+            small: SizePreset {
+                padding: px(8.0),
+                text_size: px(12.0),
+                icon_size: px(14.0),
+                radius: px(4.0),
+                min_height: px(28.0),
+            },
+            medium: SizePreset {
+                padding: px(12.0),
+                text_size: px(14.0),
+                icon_size: px(16.0),
+                radius: px(6.0),
+                min_height: px(36.0),
+            },
+            large: SizePreset {
+                padding: px(16.0),
+                text_size: px(16.0),
+                icon_size: px(20.0),
+                radius: px(8.0),
+                min_height: px(44.0),
+            },
+        }
+    }
+}
+
+impl SizeScale {
+    pub fn get(&self, size: ComponentSize) -> SizePreset {
+        match size {
+            ComponentSize::Small => self.small,
+            ComponentSize::Medium => self.medium,
+            ComponentSize::Large => self.large,
+        }
+    }
+}
+
+impl ComponentSize {
+    /// Looks up this size in the active `SizeScale`, falling back to the
+    /// default table when none has been set.
+    pub fn preset(self, cx: &App) -> SizePreset {
+        match cx.try_global::<SizeScale>() {
+            Some(scale) => scale.get(self),
+            None => SizeScale::default().get(self),
+        }
+    }
+}
@@ -0,0 +1,331 @@
+use crate::focus_visible::{self, InputModality};
+use crate::prelude::*;
+use std::rc::Rc;
+
+/// Width of the fades shown at an edge of the tab list that has more tabs
+/// scrolled out of view.
+const FADE_WIDTH: f32 = 32.0;
+
+/// One tab: a label in the tab list and the content shown while selected.
+pub struct Tab {
+    id: SharedString,
+    label: SharedString,
+    disabled: bool,
+    content: Option<AnyElement>,
+}
+
+impl Tab {
+    pub fn new(id: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            disabled: false,
+            content: None,
+        }
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Content shown below the tab list while this tab is selected.
+    pub fn child(mut self, content: impl IntoElement) -> Self {
+        self.content = Some(content.into_any_element());
+        self
+    }
+}
+
+/// Keyboard focus and scroll position of a tab list, kept across frames.
+struct TabsState {
+    focus_handle: FocusHandle,
+    /// The tab arrow keys move from. Follows the selection until the user
+    /// moves it.
+    focused: Option<usize>,
+    scroll_handle: ScrollHandle,
+}
+
+/// A row of tabs with one selected panel.
+///
+/// When the tab list has focus, Left/Right move between tabs, Home/End jump
+/// to the first and last, and Enter/Space select the focused tab. Tabs that
+/// don't fit scroll horizontally, with a fade at each edge that hides more.
+#[derive(IntoElement)]
+pub struct Tabs {
+    id: ElementId,
+    tabs: Vec<Tab>,
+    selected: Option<SharedString>,
+    manual_activation: bool,
+    on_change: Option<Rc<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+}
+
+impl Tabs {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            tabs: Vec::new(),
+            selected: None,
+            manual_activation: true,
+            on_change: None,
+        }
+    }
+
+    pub fn tab(mut self, tab: Tab) -> Self {
+        self.tabs.push(tab);
+        self
+    }
+
+    pub fn tabs(mut self, tabs: impl IntoIterator<Item = Tab>) -> Self {
+        self.tabs.extend(tabs);
+        self
+    }
+
+    /// The id of the selected tab. Defaults to the first enabled tab.
+    pub fn selected(mut self, id: impl Into<SharedString>) -> Self {
+        self.selected = Some(id.into());
+        self
+    }
+
+    /// With manual activation (the default), arrow keys only move focus and
+    /// Enter or Space selects. Pass `false` to select tabs as focus reaches
+    /// them.
+    pub fn manual_activation(mut self, manual_activation: bool) -> Self {
+        self.manual_activation = manual_activation;
+        self
+    }
+
+    /// Called with the id of the tab the user selects.
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+}
+
+/// Steps from `from` to the next enabled tab in `direction`, wrapping around.
+fn step_enabled(disabled: &[bool], from: usize, direction: isize) -> Option<usize> {
+    let len = disabled.len() as isize;
+    (1..=len)
+        .map(|offset| (from as isize + direction * offset).rem_euclid(len) as usize)
+        .find(|index| !disabled[*index])
+}
+
+fn first_enabled(disabled: &[bool]) -> Option<usize> {
+    disabled.iter().position(|disabled| !disabled)
+}
+
+fn last_enabled(disabled: &[bool]) -> Option<usize> {
+    disabled.iter().rposition(|disabled| !disabled)
+}
+
+impl RenderOnce for Tabs {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        focus_visible::init(cx);
+
+        let state = window.use_keyed_state(self.id.clone(), cx, |_, cx| TabsState {
+            focus_handle: cx.focus_handle(),
+            focused: None,
+            scroll_handle: ScrollHandle::new(),
+        });
+        let (focus_handle, focused, scroll_handle) = {
+            let state = state.read(cx);
+            (
+                state.focus_handle.clone(),
+                state.focused,
+                state.scroll_handle.clone(),
+            )
+        };
+
+        let preset = ComponentSize::Medium.preset(cx);
+        let ids: Rc<Vec<SharedString>> =
+            Rc::new(self.tabs.iter().map(|tab| tab.id.clone()).collect());
+        let disabled: Rc<Vec<bool>> =
+            Rc::new(self.tabs.iter().map(|tab| tab.disabled).collect());
+
+        let selected = self
+            .selected
+            .as_ref()
+            .and_then(|selected| ids.iter().position(|id| id == selected))
+            .or_else(|| first_enabled(&disabled));
+        let focused = focused.filter(|index| *index < ids.len()).or(selected);
+        let show_ring = focus_visible::is_focus_visible(&focus_handle, window, cx);
+
+        let activate: Rc<dyn Fn(usize, &mut Window, &mut App)> = {
+            let ids = ids.clone();
+            let on_change = self.on_change.clone();
+            Rc::new(move |index, window, cx| {
+                if let Some(on_change) = &on_change {
+                    on_change(&ids[index], window, cx);
+                }
+            })
+        };
+
+        let on_key_down = {
+            let state = state.clone();
+            let disabled = disabled.clone();
+            let activate = activate.clone();
+            let manual_activation = self.manual_activation;
+            move |event: &KeyDownEvent, window: &mut Window, cx: &mut App| {
+                let Some(current) = focused else {
+                    return;
+                };
+                let target = match event.keystroke.key.as_str() {
+                    "left" => step_enabled(&disabled, current, -1),
+                    "right" => step_enabled(&disabled, current, 1),
+                    "home" => first_enabled(&disabled),
+                    "end" => last_enabled(&disabled),
+                    "enter" | "space" => {
+                        cx.stop_propagation();
+                        activate(current, window, cx);
+                        return;
+                    }
+                    _ => return,
+                };
+                let Some(target) = target else {
+                    return;
+                };
+
+                cx.stop_propagation();
+                focus_visible::set_modality(InputModality::Keyboard, cx);
+                state.update(cx, |state, cx| {
+                    state.focused = Some(target);
+                    state.scroll_handle.scroll_to_item(target);
+                    cx.notify();
+                });
+                if !manual_activation {
+                    activate(target, window, cx);
+                }
+            }
+        };
+
+        let mut content = None;
+        let tab_buttons: Vec<AnyElement> = self
+            .tabs
+            .into_iter()
+            .enumerate()
+            .map(|(index, tab)| {
+                let is_selected = selected == Some(index);
+                if is_selected {
+                    content = tab.content;
+                }
+
+                let state = state.clone();
+                let focus_handle = focus_handle.clone();
+                let activate = activate.clone();
+                div()
+                    .id(index)
+                    .flex_none()
+                    .px(preset.padding)
+                    .py(preset.padding / 2.0)
+                    .text_size(preset.text_size)
+                    .border_b_2()
+                    .border_color(if is_selected {
+                        colors::PRIMARY.into()
+                    } else {
+                        transparent_black()
+                    })
+                    .text_color(if tab.disabled {
+                        colors::DISABLED_FOREGROUND
+                    } else if is_selected {
+                        colors::FOREGROUND
+                    } else {
+                        colors::MUTED_FOREGROUND
+                    })
+                    .when(is_selected, |this| this.font_weight(FontWeight::MEDIUM))
+                    .when(show_ring && focused == Some(index), |this| {
+                        this.rounded(colors::RADIUS).shadow(focus_visible::focus_ring(colors::PRIMARY))
+                    })
+                    .when(tab.disabled, |this| this.cursor_not_allowed())
+                    .when(!tab.disabled, |this| {
+                        this.cursor_pointer()
+                            .hover(|style| style.text_color(colors::FOREGROUND))
+                            .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                                focus_visible::set_modality(InputModality::Pointer, cx);
+                            })
+                            .on_click(move |_, window, cx| {
+                                window.focus(&focus_handle);
+                                state.update(cx, |state, cx| {
+                                    state.focused = Some(index);
+                                    cx.notify();
+                                });
+                                activate(index, window, cx);
+                            })
+                    })
+                    .child(tab.label)
+                    .into_any_element()
+            })
+            .collect();
+
+        // Fades mark the edges that have tabs scrolled out of view
+        let offset = scroll_handle.offset();
+        let max_offset = scroll_handle.max_offset();
+        let more_before = offset.x < px(0.0);
+        let more_after = -offset.x < max_offset.width;
+        let background: Hsla = colors::BACKGROUND.into();
+        let fade = |angle: f32| {
+            div()
+                .absolute()
+                .top_0()
+                .bottom_0()
+                .w(px(FADE_WIDTH))
+                .bg(linear_gradient(
+                    angle,
+                    linear_color_stop(background, 0.0),
+                    linear_color_stop(background.opacity(0.0), 1.0),
+                ))
+        };
+
+        div()
+            .flex()
+            .flex_col()
+            .w_full()
+            .child(
+                div()
+                    .relative()
+                    .w_full()
+                    .border_b_1()
+                    .border_color(colors::BORDER)
+                    .child(
+                        div()
+                            .id(self.id)
+                            .flex()
+                            .w_full()
+                            .overflow_x_scroll()
+                            .track_scroll(&scroll_handle)
+                            .track_focus(&focus_handle)
+                            .on_key_down(on_key_down)
+                            .children(tab_buttons),
+                    )
+                    .when(more_before, |list| list.child(fade(90.0).left_0()))
+                    .when(more_after, |list| list.child(fade(270.0).right_0())),
+            )
+            .when_some(content, |tabs, content| {
+                tabs.child(div().pt(preset.padding).child(content))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{first_enabled, last_enabled, step_enabled};
+
+    #[test]
+    fn arrow_navigation_skips_disabled_tabs_and_wraps() {
+        let disabled = [false, true, false, false];
+        assert_eq!(step_enabled(&disabled, 0, 1), Some(2));
+        assert_eq!(step_enabled(&disabled, 2, -1), Some(0));
+        assert_eq!(step_enabled(&disabled, 3, 1), Some(0));
+        assert_eq!(step_enabled(&disabled, 0, -1), Some(3));
+        assert_eq!(step_enabled(&[true, true], 0, 1), None);
+    }
+
+    #[test]
+    fn home_and_end_find_enabled_tabs() {
+        let disabled = [true, false, false, true];
+        assert_eq!(first_enabled(&disabled), Some(1));
+        assert_eq!(last_enabled(&disabled), Some(2));
+        assert_eq!(first_enabled(&[]), None);
+    }
+}
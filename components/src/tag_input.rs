@@ -0,0 +1,430 @@
+use crate::overlay::Overlay;
+use crate::prelude::*;
+use gpui::{FocusHandle, Focusable, KeyDownEvent};
+
+/// Most suggestions shown at once.
+const MAX_SUGGESTIONS: usize = 8;
+
+/// Scores how well `query` matches `candidate`, or `None` if the query's
+/// characters don't all appear in order. Case-insensitive. Higher is better:
+/// consecutive runs, matches at the start of a word, and a shorter candidate
+/// all score more.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<usize> {
+    let query: Vec<char> = query.to_lowercase().chars().collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let candidate: Vec<char> = candidate.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut next = 0;
+    let mut previous_match = None;
+    for (index, ch) in candidate.iter().enumerate() {
+        if next == query.len() {
+            break;
+        }
+        if *ch != query[next] {
+            continue;
+        }
+
+        score += 1;
+        if previous_match == Some(index.wrapping_sub(1)) {
+            score += 4;
+        }
+        let word_start = index == 0 || !candidate[index - 1].is_alphanumeric();
+        if word_start {
+            score += 8;
+        }
+        previous_match = Some(index);
+        next += 1;
+    }
+
+    if next < query.len() {
+        return None;
+    }
+    // Prefer "go" over "google-cloud-go" for the same matches
+    Some(score * 100 / (candidate.len() + 4))
+}
+
+/// The entries of `options` matching `query`, best first, leaving out any
+/// already in `selected`. An empty query suggests nothing.
+pub fn matching_suggestions(
+    query: &str,
+    options: &[SharedString],
+    selected: &[SharedString],
+) -> Vec<SharedString> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut matches: Vec<(usize, &SharedString)> = options
+        .iter()
+        .filter(|option| {
+            !selected
+                .iter()
+                .any(|tag| tag.to_lowercase() == option.to_lowercase())
+        })
+        .filter_map(|option| fuzzy_score(query, option).map(|score| (score, option)))
+        .collect();
+    // Stable, so equal scores keep the order of `options`
+    matches.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+    matches
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, option)| option.clone())
+        .collect()
+}
+
+/// A text field that collects tags as removable pills, suggesting matches
+/// from a list as the user types.
+///
+/// Up and down move through the suggestions, Enter adds the highlighted one
+/// (or the typed text, when nothing matches), and Backspace in an empty field
+/// removes the last tag.
+pub struct TagInput {
+    id: ElementId,
+    focus_handle: FocusHandle,
+    tags: Vec<SharedString>,
+    query: String,
+    highlighted: usize,
+    suggestions: Vec<SharedString>,
+    allow_custom: bool,
+    placeholder: SharedString,
+    disabled: bool,
+    on_change: Option<Box<dyn Fn(&[SharedString], &mut Window, &mut App) + 'static>>,
+}
+
+impl TagInput {
+    pub fn new(id: impl Into<ElementId>, cx: &mut App) -> Self {
+        Self {
+            id: id.into(),
+            focus_handle: cx.focus_handle(),
+            tags: Vec::new(),
+            query: String::new(),
+            highlighted: 0,
+            suggestions: Vec::new(),
+            allow_custom: true,
+            placeholder: "Add a tag...".into(),
+            disabled: false,
+            on_change: None,
+        }
+    }
+
+    /// The tags to suggest while typing.
+    pub fn suggestions(
+        mut self,
+        suggestions: impl IntoIterator<Item = impl Into<SharedString>>,
+    ) -> Self {
+        self.suggestions = suggestions.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// The tags shown initially.
+    pub fn tags(mut self, tags: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.tags = tags.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Whether Enter adds typed text that matches no suggestion. On by
+    /// default; turn off to restrict tags to the suggestion list.
+    pub fn allow_custom(mut self, allow_custom: bool) -> Self {
+        self.allow_custom = allow_custom;
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Called with every tag whenever one is added or removed.
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(&[SharedString], &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Box::new(handler));
+        self
+    }
+
+    /// Returns the current tags.
+    pub fn value(&self) -> &[SharedString] {
+        &self.tags
+    }
+
+    fn visible_suggestions(&self) -> Vec<SharedString> {
+        matching_suggestions(&self.query, &self.suggestions, &self.tags)
+    }
+
+    fn set_query(&mut self, query: String, cx: &mut Context<Self>) {
+        self.query = query;
+        self.highlighted = 0;
+        cx.notify();
+    }
+
+    fn add_tag(&mut self, tag: SharedString, window: &mut Window, cx: &mut Context<Self>) {
+        let tag: SharedString = tag.trim().to_string().into();
+        let duplicate = self
+            .tags
+            .iter()
+            .any(|existing| existing.to_lowercase() == tag.to_lowercase());
+        self.set_query(String::new(), cx);
+        if tag.is_empty() || duplicate {
+            return;
+        }
+        self.tags.push(tag);
+        self.changed(window, cx);
+    }
+
+    fn remove_tag(&mut self, index: usize, window: &mut Window, cx: &mut Context<Self>) {
+        if index < self.tags.len() {
+            self.tags.remove(index);
+            self.changed(window, cx);
+        }
+    }
+
+    fn changed(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(on_change) = &self.on_change {
+            on_change(&self.tags, window, cx);
+        }
+        cx.notify();
+    }
+
+    fn on_key_down(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<Self>) {
+        if self.disabled {
+            return;
+        }
+
+        let keystroke = &event.keystroke;
+        if keystroke.modifiers.platform || keystroke.modifiers.control {
+            return;
+        }
+
+        let suggestions = self.visible_suggestions();
+        match keystroke.key.as_str() {
+            "down" | "up" if !suggestions.is_empty() => {
+                let count = suggestions.len();
+                self.highlighted = if keystroke.key == "down" {
+                    (self.highlighted + 1) % count
+                } else {
+                    (self.highlighted + count - 1) % count
+                };
+                cx.notify();
+            }
+            "enter" => {
+                match suggestions.get(self.highlighted) {
+                    Some(suggestion) => self.add_tag(suggestion.clone(), window, cx),
+                    None if self.allow_custom => {
+                        let query = self.query.clone();
+                        self.add_tag(query.into(), window, cx);
+                    }
+                    None => return,
+                }
+            }
+            "escape" if !self.query.is_empty() => self.set_query(String::new(), cx),
+            "backspace" => {
+                if self.query.is_empty() {
+                    match self.tags.len() {
+                        0 => return,
+                        len => self.remove_tag(len - 1, window, cx),
+                    }
+                } else {
+                    let mut query = self.query.clone();
+                    query.pop();
+                    self.set_query(query, cx);
+                }
+            }
+            _ => {
+                let Some(text) = keystroke.key_char.as_ref() else {
+                    return;
+                };
+                if text.chars().any(char::is_control) {
+                    return;
+                }
+                let query = format!("{}{}", self.query, text);
+                self.set_query(query, cx);
+            }
+        }
+        cx.stop_propagation();
+    }
+}
+
+impl Render for TagInput {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = cx.theme();
+        let is_focused = self.focus_handle.is_focused(window);
+        let suggestions = if is_focused {
+            self.visible_suggestions()
+        } else {
+            Vec::new()
+        };
+        let highlighted = self.highlighted.min(suggestions.len().saturating_sub(1));
+
+        let tags = self.tags.iter().enumerate().map(|(index, tag)| {
+            div()
+                .flex()
+                .items_center()
+                .gap_1()
+                .px_2()
+                .rounded(px(9999.0))
+                .bg(theme.muted)
+                .text_sm()
+                .text_color(theme.foreground)
+                .child(tag.clone())
+                .when(!self.disabled, |pill| {
+                    pill.child(
+                        div()
+                            .id(("remove-tag", index))
+                            .text_color(theme.muted_foreground)
+                            .cursor_pointer()
+                            .hover(|style| style.text_color(theme.foreground))
+                            .on_click(cx.listener(move |this, _, window, cx| {
+                                this.remove_tag(index, window, cx);
+                                cx.stop_propagation();
+                            }))
+                            .child("×"),
+                    )
+                })
+        });
+
+        let entry = if self.query.is_empty() && self.tags.is_empty() {
+            div()
+                .text_sm()
+                .text_color(colors::PLACEHOLDER)
+                .child(self.placeholder.clone())
+        } else {
+            div()
+                .text_sm()
+                .text_color(theme.foreground)
+                .child(self.query.clone())
+        };
+
+        let dropdown = Overlay::popover("suggestions")
+            .on_outside_click(cx.listener(|this, _, _, cx| this.set_query(String::new(), cx)))
+            .child(
+                div()
+                    .mt_1()
+                    .py_1()
+                    .min_w(px(160.0))
+                    .flex()
+                    .flex_col()
+                    .bg(theme.background)
+                    .border_1()
+                    .border_color(theme.border)
+                    .rounded(theme.radius)
+                    .shadow_lg()
+                    .children(suggestions.iter().enumerate().map(|(index, suggestion)| {
+                        let suggestion = suggestion.clone();
+                        div()
+                            .id(("suggestion", index))
+                            .px_3()
+                            .py_1()
+                            .text_sm()
+                            .text_color(theme.foreground)
+                            .cursor_pointer()
+                            .when(index == highlighted, |row| row.bg(theme.accent))
+                            .hover(|style| style.bg(theme.muted))
+                            .child(suggestion.clone())
+                            .on_click(cx.listener(move |this, _, window, cx| {
+                                this.add_tag(suggestion.clone(), window, cx);
+                            }))
+                    })),
+            );
+
+        div()
+            .id(self.id.clone())
+            .key_context("TagInput")
+            .track_focus(&self.focus_handle)
+            .on_key_down(cx.listener(Self::on_key_down))
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|this, _, window, _| window.focus(&this.focus_handle)),
+            )
+            .w_full()
+            .child(
+                div()
+                    .flex()
+                    .flex_wrap()
+                    .items_center()
+                    .gap_1()
+                    .min_h(px(36.0))
+                    .px_2()
+                    .py_1()
+                    .border_1()
+                    .border_color(if is_focused && !self.disabled {
+                        theme.primary
+                    } else {
+                        colors::INPUT_BORDER
+                    })
+                    .rounded(theme.radius)
+                    .bg(if self.disabled {
+                        theme.muted
+                    } else {
+                        theme.background
+                    })
+                    .when(self.disabled, |field| field.cursor_not_allowed())
+                    .when(!self.disabled, |field| field.cursor(CursorStyle::IBeam))
+                    .children(tags)
+                    .child(
+                        entry.when(is_focused, |entry| {
+                            entry
+                                .flex()
+                                .items_center()
+                                .child(div().w(px(1.0)).h(px(16.0)).bg(theme.primary))
+                        }),
+                    ),
+            )
+            .when(!suggestions.is_empty(), |this| this.child(dropdown))
+    }
+}
+
+impl Focusable for TagInput {
+    fn focus_handle(&self, _: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fuzzy_score, matching_suggestions};
+    use gpui::SharedString;
+
+    fn tags(names: &[&'static str]) -> Vec<SharedString> {
+        names.iter().map(|name| SharedString::from(*name)).collect()
+    }
+
+    #[test]
+    fn test_fuzzy_score_requires_ordered_characters() {
+        assert!(fuzzy_score("rst", "rust").is_some());
+        assert!(fuzzy_score("RU", "rust").is_some());
+        assert_eq!(fuzzy_score("tsur", "rust"), None);
+    }
+
+    #[test]
+    fn test_fuzzy_score_prefers_prefix_and_word_starts() {
+        let prefix = fuzzy_score("ty", "typescript").unwrap();
+        let inner = fuzzy_score("ty", "rusty").unwrap();
+        assert!(prefix > inner);
+
+        let word_starts = fuzzy_score("ui", "user-interface").unwrap();
+        let scattered = fuzzy_score("ui", "build").unwrap();
+        assert!(word_starts > scattered);
+    }
+
+    #[test]
+    fn test_matching_suggestions_excludes_selected() {
+        let options = tags(&["rust", "ruby", "react", "go"]);
+        let selected = tags(&["Rust"]);
+
+        assert_eq!(
+            matching_suggestions("r", &options, &selected),
+            vec!["ruby", "react"]
+        );
+        assert!(matching_suggestions("  ", &options, &selected).is_empty());
+    }
+}
@@ -0,0 +1,261 @@
+// App-wide theme.
+//
+// `Theme` holds the palette, corner radius, and animation timing as a global,
+// defaulting to the constants in `colors`. Install a custom one with
+// `cx.set_global(theme)`, or load it from the `style` section of the CLI's
+// `gpui-ui.json`. With the `hot_reload` feature, `watch_config` re-reads that
+// file whenever it changes so color and radius tweaks show up without a
+// rebuild during development.
+
+use crate::prelude::*;
+use serde::Deserialize;
+use std::time::Duration;
+
+/// The colors and radius components draw with.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Theme {
+    pub primary: Rgba,
+    pub secondary: Rgba,
+    pub destructive: Rgba,
+    pub success: Rgba,
+    pub warning: Rgba,
+    pub muted: Rgba,
+    pub accent: Rgba,
+    pub background: Rgba,
+    pub foreground: Rgba,
+    pub muted_foreground: Rgba,
+    pub border: Rgba,
+    pub radius: Pixels,
+    /// How long transitions such as a `Collapsible` expanding take.
+    pub animation_duration: Duration,
+}
+
+impl Global for Theme {}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            primary: colors::PRIMARY,
+            secondary: colors::SECONDARY,
+            destructive: colors::DESTRUCTIVE,
+            success: colors::SUCCESS,
+            warning: colors::WARNING,
+            muted: colors::MUTED,
+            accent: colors::ACCENT,
+            background: colors::BACKGROUND,
+            foreground: colors::FOREGROUND,
+            muted_foreground: colors::MUTED_FOREGROUND,
+            border: colors::BORDER,
+            radius: colors::RADIUS,
+            animation_duration: Duration::from_millis(150),
+        }
+    }
+}
+
+/// The parts of `gpui-ui.json` the theme reads. Colors are written in the
+/// forms the CLI accepts, e.g. `"rgb(0x3b82f6)"`, the radius as `"px(4.0)"`, and
+/// the animation duration as a number of milliseconds.
+#[derive(Deserialize, Default)]
+struct ConfigFile {
+    #[serde(default)]
+    style: StyleSection,
+}
+
+#[derive(Deserialize, Default)]
+struct StyleSection {
+    #[serde(default)]
+    colors: std::collections::HashMap<String, String>,
+    radius: Option<String>,
+    animation_duration_ms: Option<u64>,
+}
+
+impl Theme {
+    /// Returns the installed theme, or the default one if none is set.
+    pub fn get(cx: &App) -> Self {
+        cx.try_global::<Theme>().copied().unwrap_or_default()
+    }
+
+    /// Builds a theme from the contents of a `gpui-ui.json` file. Colors the
+    /// file doesn't set, or sets in a form that can't be read, keep their
+    /// default.
+    pub fn from_config(json: &str) -> serde_json::Result<Self> {
+        let config: ConfigFile = serde_json::from_str(json)?;
+        let mut theme = Self::default();
+
+        for (name, value) in &config.style.colors {
+            let Some(color) = parse_color(value) else {
+                continue;
+            };
+            let slot = match name.as_str() {
+                "primary" => &mut theme.primary,
+                "secondary" => &mut theme.secondary,
+                "destructive" => &mut theme.destructive,
+                "success" => &mut theme.success,
+                "warning" => &mut theme.warning,
+                "muted" => &mut theme.muted,
+                "accent" => &mut theme.accent,
+                "background" => &mut theme.background,
+                "foreground" => &mut theme.foreground,
+                "muted_foreground" => &mut theme.muted_foreground,
+                "border" => &mut theme.border,
+                _ => continue,
+            };
+            *slot = color;
+        }
+        if let Some(radius) = config.style.radius.as_deref().and_then(parse_pixels) {
+            theme.radius = radius;
+        }
+        if let Some(ms) = config.style.animation_duration_ms {
+            theme.animation_duration = Duration::from_millis(ms);
+        }
+
+        Ok(theme)
+    }
+}
+
+/// Reads the active theme from any context.
+pub trait ActiveTheme {
+    fn theme(&self) -> Theme;
+}
+
+impl ActiveTheme for App {
+    fn theme(&self) -> Theme {
+        Theme::get(self)
+    }
+}
+
+/// Parses the forms the CLI accepts in `gpui-ui.json`: `rgb(0x3b82f6)`,
+/// `0x3b82f6`, `#3b82f6`, or an opaque `hsla(h, s, l, 1.0)` with each part
+/// from 0 to 1.
+pub fn parse_color(text: &str) -> Option<Rgba> {
+    let text = text.trim();
+    if let Some(inner) = text
+        .strip_prefix("hsla(")
+        .and_then(|inner| inner.strip_suffix(')'))
+    {
+        let parts: Vec<f32> = inner
+            .split(',')
+            .map(|part| part.trim().parse::<f32>().ok())
+            .collect::<Option<_>>()?;
+        let [h, s, l, a] = parts[..] else {
+            return None;
+        };
+        if ![h, s, l].iter().all(|part| (0.0..=1.0).contains(part)) || a != 1.0 {
+            return None;
+        }
+        return Some(hsla(h, s, l, a).into());
+    }
+
+    let text = text
+        .strip_prefix("rgb(")
+        .and_then(|inner| inner.strip_suffix(')'))
+        .unwrap_or(text)
+        .trim();
+    let hex = text
+        .strip_prefix("0x")
+        .or_else(|| text.strip_prefix('#'))?;
+    if hex.len() != 6 {
+        return None;
+    }
+    u32::from_str_radix(hex, 16).ok().map(rgb)
+}
+
+/// Parses `px(4.0)` or a bare number of pixels.
+pub fn parse_pixels(text: &str) -> Option<Pixels> {
+    let text = text.trim();
+    let text = text
+        .strip_prefix("px(")
+        .and_then(|inner| inner.strip_suffix(')'))
+        .unwrap_or(text);
+    text.trim().parse::<f32>().ok().map(px)
+}
+
+/// Loads the theme from `path` and reloads it whenever the file changes,
+/// refreshing every window so components pick up the new values.
+///
+/// Meant for development, so it is only built with the `hot_reload` feature:
+/// the file is polled twice a second, and a file that fails to parse leaves
+/// the current theme in place.
+#[cfg(feature = "hot_reload")]
+pub fn watch_config(path: impl Into<std::path::PathBuf>, cx: &mut App) {
+    use std::time::{Duration, SystemTime};
+
+    const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+    fn load(path: &std::path::Path) -> Option<(Theme, SystemTime)> {
+        let modified = std::fs::metadata(path).and_then(|meta| meta.modified()).ok()?;
+        let json = std::fs::read_to_string(path).ok()?;
+        Some((Theme::from_config(&json).ok()?, modified))
+    }
+
+    let path = path.into();
+    let mut last_modified = None;
+    if let Some((theme, modified)) = load(&path) {
+        cx.set_global(theme);
+        last_modified = Some(modified);
+    }
+
+    cx.spawn(async move |cx| loop {
+        cx.background_executor().timer(POLL_INTERVAL).await;
+
+        let modified = std::fs::metadata(&path).and_then(|meta| meta.modified()).ok();
+        if modified.is_none() || modified == last_modified {
+            continue;
+        }
+        let Some((theme, modified)) = load(&path) else {
+            continue;
+        };
+        last_modified = Some(modified);
+
+        let applied = cx.update(|cx| {
+            cx.set_global(theme);
+            cx.refresh_windows();
+        });
+        if applied.is_err() {
+            break;
+        }
+    })
+    .detach();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_color, parse_pixels, Theme};
+    use gpui::{px, rgb};
+    use std::time::Duration;
+
+    #[test]
+    fn parses_cli_color_and_radius_formats() {
+        assert_eq!(parse_color("rgb(0x3b82f6)"), Some(rgb(0x3b82f6)));
+        assert_eq!(parse_color("#ef4444"), Some(rgb(0xef4444)));
+        assert_eq!(parse_color("0x22c55e"), Some(rgb(0x22c55e)));
+        assert_eq!(parse_color("blue"), None);
+        assert_eq!(parse_color("hsla(0.0, 1.0, 0.5, 1.0)"), Some(rgb(0xff0000)));
+        assert_eq!(parse_color("hsla(0.5, 1.0, 0.5, 1.0)"), Some(rgb(0x00ffff)));
+        assert_eq!(parse_color("hsla(0.5, 1.0, 0.5, 0.5)"), None);
+        assert_eq!(parse_color("hsla(2.0, 1.0, 0.5, 1.0)"), None);
+        assert_eq!(parse_pixels("px(6.0)"), Some(px(6.0)));
+        assert_eq!(parse_pixels("8"), Some(px(8.0)));
+    }
+
+    #[test]
+    fn reads_animation_duration_in_milliseconds() {
+        let theme =
+            Theme::from_config(r#"{ "style": { "animation_duration_ms": 0 } }"#).unwrap();
+        assert_eq!(theme.animation_duration, Duration::ZERO);
+    }
+
+    #[test]
+    fn config_overrides_only_the_colors_it_sets() {
+        let theme = Theme::from_config(
+            r#"{ "style": { "colors": { "primary": "rgb(0x000000)", "accent": "nope" }, "radius": "px(8.0)" } }"#,
+        )
+        .unwrap();
+
+        assert_eq!(theme.primary, rgb(0x000000));
+        assert_eq!(theme.accent, Theme::default().accent);
+        assert_eq!(theme.radius, px(8.0));
+        assert_eq!(theme.animation_duration, Duration::from_millis(150));
+        assert_eq!(Theme::from_config("{}").unwrap(), Theme::default());
+    }
+}
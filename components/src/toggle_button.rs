@@ -0,0 +1,131 @@
+use crate::button::{Button, ButtonSize, ButtonVariant};
+use crate::prelude::*;
+
+/// A button that reflects a pressed/selected state, like a bold or italic
+/// toolbar toggle. Selected toggles use the primary fill, unselected toggles
+/// render as outlined buttons.
+#[derive(IntoElement)]
+pub struct ToggleButton {
+    id: ElementId,
+    label: SharedString,
+    state: ToggleState,
+    size: ButtonSize,
+    disabled: bool,
+    disabled_reason: Option<SharedString>,
+    rounded: bool,
+    accessible_name: Option<SharedString>,
+    on_click: Option<Box<dyn Fn(&ToggleState, &mut Window, &mut App) + 'static>>,
+}
+
+impl ToggleButton {
+    pub fn new(id: impl Into<ElementId>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            state: ToggleState::Unselected,
+            size: ButtonSize::Medium,
+            disabled: false,
+            disabled_reason: None,
+            rounded: true,
+            accessible_name: None,
+            on_click: None,
+        }
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Rounds the corners. `ToggleGroup` turns this off so adjacent toggles
+    /// meet flush.
+    pub fn rounded(mut self, rounded: bool) -> Self {
+        self.rounded = rounded;
+        self
+    }
+
+    /// Sets the click handler. The handler receives the state the toggle
+    /// should move to, mirroring `Checkbox::on_click`.
+    pub fn on_click(
+        mut self,
+        handler: impl Fn(&ToggleState, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_click = Some(Box::new(handler));
+        self
+    }
+
+    fn get_variant(&self) -> ButtonVariant {
+        if self.state.selected() {
+            ButtonVariant::Default
+        } else {
+            ButtonVariant::Outline
+        }
+    }
+}
+
+impl Toggleable for ToggleButton {
+    fn toggle_state(mut self, state: ToggleState) -> Self {
+        self.state = state;
+        self
+    }
+}
+
+impl Selectable for ToggleButton {
+    fn selected(mut self, selected: bool) -> Self {
+        self.state = ToggleState::from(selected);
+        self
+    }
+}
+
+impl Disableable for ToggleButton {
+    fn disabled(self, disabled: bool) -> Self {
+        Self { disabled, ..self }
+    }
+
+    fn disabled_reason(mut self, reason: impl Into<SharedString>) -> Self {
+        self.disabled_reason = Some(reason.into());
+        self
+    }
+}
+
+impl Accessible for ToggleButton {
+    fn accessible_name(mut self, name: impl Into<SharedString>) -> Self {
+        self.accessible_name = Some(name.into());
+        self
+    }
+}
+
+impl RenderOnce for ToggleButton {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let variant = self.get_variant();
+        let new_state = self.state.inverse();
+
+        let mut button = Button::new(self.id, self.label)
+            .variant(variant)
+            .size(self.size)
+            .rounded(self.rounded)
+            .pressed(self.state)
+            .disabled(self.disabled);
+
+        if let Some(name) = self.accessible_name {
+            button = button.accessible_name(name);
+        }
+
+        if let Some(reason) = self.disabled_reason {
+            button = button.disabled_reason(reason);
+        }
+
+        if let Some(handler) = self.on_click {
+            button = button.on_click(move |_event, window, cx| {
+                handler(&new_state, window, cx);
+            });
+        }
+
+        button
+    }
+}
@@ -0,0 +1,244 @@
+use crate::button::ButtonSize;
+use crate::focus_visible::{self, InputModality};
+use crate::prelude::*;
+use crate::toggle_button::ToggleButton;
+use std::rc::Rc;
+
+type ChangeHandler = Rc<dyn Fn(&[bool], &mut Window, &mut App) + 'static>;
+
+/// One toggle in a `ToggleGroup`.
+pub struct ToggleGroupItem {
+    label: SharedString,
+    selected: bool,
+    disabled: bool,
+}
+
+impl ToggleGroupItem {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            selected: false,
+            disabled: false,
+        }
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl Selectable for ToggleGroupItem {
+    fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+}
+
+/// Keyboard focus kept across frames. The group is a single tab stop; the
+/// arrow keys move between toggles.
+struct ToggleGroupState {
+    focus_handle: FocusHandle,
+    focused: Option<usize>,
+}
+
+/// A connected row of toggles where any number can be on at once, like the
+/// bold, italic, and underline buttons of a formatting toolbar.
+///
+/// The group is controlled: `on_change` receives the selected state of every
+/// item after a toggle, in item order.
+#[derive(IntoElement)]
+pub struct ToggleGroup {
+    id: ElementId,
+    items: Vec<ToggleGroupItem>,
+    size: ButtonSize,
+    on_change: Option<ChangeHandler>,
+}
+
+impl ToggleGroup {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            items: Vec::new(),
+            size: ButtonSize::Medium,
+            on_change: None,
+        }
+    }
+
+    pub fn item(mut self, item: ToggleGroupItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn items(mut self, items: impl IntoIterator<Item = ToggleGroupItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn on_change(mut self, handler: impl Fn(&[bool], &mut Window, &mut App) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+}
+
+/// The next enabled item from `current` in `direction`, wrapping around.
+fn step_enabled(disabled: &[bool], current: usize, direction: isize) -> Option<usize> {
+    let count = disabled.len() as isize;
+    (1..=count)
+        .map(|offset| (current as isize + direction * offset).rem_euclid(count) as usize)
+        .find(|index| !disabled[*index])
+}
+
+/// `selected` with the item at `index` flipped.
+fn toggled(selected: &[bool], index: usize) -> Vec<bool> {
+    let mut selected = selected.to_vec();
+    selected[index] = !selected[index];
+    selected
+}
+
+impl RenderOnce for ToggleGroup {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        focus_visible::init(cx);
+
+        let theme = cx.theme();
+        let preset = self.size.preset(cx);
+        let state = window.use_keyed_state(self.id.clone(), cx, |_, cx| ToggleGroupState {
+            focus_handle: cx.focus_handle(),
+            focused: None,
+        });
+        let (focus_handle, focused) = {
+            let state = state.read(cx);
+            (state.focus_handle.clone(), state.focused)
+        };
+
+        let selected: Rc<Vec<bool>> =
+            Rc::new(self.items.iter().map(|item| item.selected).collect());
+        let disabled: Rc<Vec<bool>> =
+            Rc::new(self.items.iter().map(|item| item.disabled).collect());
+        let focused = focused
+            .filter(|index| *index < disabled.len() && !disabled[*index])
+            .or_else(|| disabled.iter().position(|disabled| !disabled));
+        let show_ring = focus_visible::is_focus_visible(&focus_handle, window, cx);
+
+        let toggle: Rc<dyn Fn(usize, &mut Window, &mut App)> = {
+            let selected = selected.clone();
+            let on_change = self.on_change.clone();
+            Rc::new(move |index, window, cx| {
+                if let Some(on_change) = &on_change {
+                    on_change(&toggled(&selected, index), window, cx);
+                }
+            })
+        };
+
+        let on_key_down = {
+            let state = state.clone();
+            let disabled = disabled.clone();
+            let toggle = toggle.clone();
+            move |event: &KeyDownEvent, window: &mut Window, cx: &mut App| {
+                let Some(current) = focused else {
+                    return;
+                };
+                let target = match event.keystroke.key.as_str() {
+                    "left" => step_enabled(&disabled, current, -1),
+                    "right" => step_enabled(&disabled, current, 1),
+                    "home" => disabled.iter().position(|disabled| !disabled),
+                    "end" => disabled.iter().rposition(|disabled| !disabled),
+                    "space" | "enter" => {
+                        cx.stop_propagation();
+                        toggle(current, window, cx);
+                        return;
+                    }
+                    _ => return,
+                };
+                if let Some(target) = target {
+                    cx.stop_propagation();
+                    state.update(cx, |state, cx| {
+                        state.focused = Some(target);
+                        cx.notify();
+                    });
+                }
+            }
+        };
+
+        let count = self.items.len();
+        let toggles = self.items.into_iter().enumerate().map(|(index, item)| {
+            let state = state.clone();
+            let focus_handle = focus_handle.clone();
+            let toggle = toggle.clone();
+            // Flex rows stretch their children by default, so every toggle
+            // fills the group's height
+            div()
+                .relative()
+                .flex()
+                .when(index + 1 < count, |segment| {
+                    segment.border_r_1().border_color(theme.border)
+                })
+                .on_mouse_down(MouseButton::Left, |_, _, cx| {
+                    focus_visible::set_modality(InputModality::Pointer, cx);
+                })
+                .child(
+                    ToggleButton::new(index, item.label)
+                        .size(self.size)
+                        .rounded(false)
+                        .selected(item.selected)
+                        .disabled(item.disabled)
+                        .on_click(move |_, window, cx| {
+                            window.focus(&focus_handle);
+                            state.update(cx, |state, cx| {
+                                state.focused = Some(index);
+                                cx.notify();
+                            });
+                            toggle(index, window, cx);
+                        }),
+                )
+                // The group clips its corners, so mark the focused toggle
+                // with an inset outline rather than an outer ring
+                .when(show_ring && focused == Some(index), |segment| {
+                    segment.child(
+                        div()
+                            .absolute()
+                            .inset_0()
+                            .border_2()
+                            .border_color(theme.primary),
+                    )
+                })
+        });
+
+        div()
+            .id(self.id)
+            .flex()
+            .flex_none()
+            .border_1()
+            .border_color(theme.border)
+            .rounded(preset.radius)
+            .overflow_hidden()
+            .track_focus(&focus_handle)
+            .on_key_down(on_key_down)
+            .children(toggles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{step_enabled, toggled};
+
+    #[test]
+    fn step_enabled_skips_disabled_and_wraps() {
+        let disabled = [false, true, false];
+        assert_eq!(step_enabled(&disabled, 0, 1), Some(2));
+        assert_eq!(step_enabled(&disabled, 2, 1), Some(0));
+        assert_eq!(step_enabled(&disabled, 0, -1), Some(2));
+        assert_eq!(step_enabled(&[true, true], 0, 1), None);
+    }
+
+    #[test]
+    fn toggled_flips_only_one_item() {
+        assert_eq!(toggled(&[true, false, true], 1), vec![true, true, true]);
+        assert_eq!(toggled(&[true, false, true], 0), vec![false, false, true]);
+    }
+}
@@ -0,0 +1,433 @@
+use crate::breakpoint::Breakpoint;
+use crate::button::{Button, ButtonSize, ButtonVariant};
+use crate::overlay::Overlay;
+use crate::prelude::*;
+use crate::separator::Separator;
+
+/// Which region of a `Toolbar` a group is placed in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToolbarAlign {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
+/// A run of related toolbar items. Groups in the same region are divided by
+/// vertical separators.
+pub struct ToolbarGroup {
+    items: Vec<AnyElement>,
+}
+
+impl ToolbarGroup {
+    pub fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    pub fn child(mut self, item: impl IntoElement) -> Self {
+        self.items.push(item.into_any_element());
+        self
+    }
+
+    pub fn children(mut self, items: impl IntoIterator<Item = impl IntoElement>) -> Self {
+        self.items
+            .extend(items.into_iter().map(|item| item.into_any_element()));
+        self
+    }
+}
+
+impl Default for ToolbarGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Spacing inside the bar, shared by the layout and the overflow measurement.
+const BAR_PADDING: Pixels = px(8.0);
+const REGION_GAP: Pixels = px(8.0);
+const ITEM_GAP: Pixels = px(4.0);
+/// A `Separator::vertical()` with its margins.
+const SEPARATOR_WIDTH: Pixels = px(9.0);
+/// Room kept for the "⋯" button before it has been laid out once.
+const OVERFLOW_BUTTON_ESTIMATE: Pixels = px(32.0);
+
+/// Measurements kept across frames to decide what overflows.
+struct ToolbarState {
+    overflow_open: bool,
+    /// The bar's width.
+    width: Option<Pixels>,
+    /// Each item's width the last time it was shown inline, counting start,
+    /// then center, then end.
+    item_widths: Vec<Option<Pixels>>,
+    /// The "⋯" button's width the last time it was shown.
+    overflow_button_width: Option<Pixels>,
+}
+
+/// Item widths by region (start, center, end), then by group.
+type RegionWidths = [Vec<Vec<Pixels>>; 3];
+
+/// The width a bar needs to show its first `count` items inline, with the
+/// "⋯" button at the end if any are left over.
+fn required_width(regions: &RegionWidths, count: usize, overflow_button: Pixels) -> Pixels {
+    let total: usize = regions.iter().flatten().map(Vec::len).sum();
+    let mut remaining = count;
+    let mut width = BAR_PADDING * 2.0 + REGION_GAP * 2.0;
+    for (index, groups) in regions.iter().enumerate() {
+        let mut children = 0;
+        for group in groups {
+            let shown = group.len().min(remaining);
+            remaining -= shown;
+            if shown == 0 {
+                continue;
+            }
+            if children > 0 {
+                width += SEPARATOR_WIDTH;
+                children += 1;
+            }
+            width = group[..shown].iter().fold(width, |width, item| width + *item);
+            children += shown;
+        }
+        if index == 2 && count < total {
+            width += overflow_button;
+            children += 1;
+        }
+        if children > 1 {
+            width += ITEM_GAP * (children - 1) as f32;
+        }
+    }
+    width
+}
+
+/// How many items, in order, fit inline in a bar `available` wide.
+fn visible_count(regions: &RegionWidths, available: Pixels, overflow_button: Pixels) -> usize {
+    let total: usize = regions.iter().flatten().map(Vec::len).sum();
+    (0..=total)
+        .rev()
+        .find(|&count| required_width(regions, count, overflow_button) <= available)
+        .unwrap_or(0)
+}
+
+/// A canvas that reports the width of the element it's placed in.
+fn measure_width(report: impl FnOnce(Pixels, &mut App) + 'static) -> impl IntoElement {
+    canvas(
+        move |bounds, _, cx| report(bounds.size.width, cx),
+        |_, _, _, _| {},
+    )
+    .absolute()
+    .top_0()
+    .left_0()
+    .size_full()
+}
+
+/// Stores a measured width, re-rendering the toolbar only when it changed.
+fn record_width(
+    state: &Entity<ToolbarState>,
+    cx: &mut App,
+    slot: impl FnOnce(&mut ToolbarState) -> &mut Option<Pixels>,
+    width: Pixels,
+) {
+    state.update(cx, |state, cx| {
+        let slot = slot(state);
+        if *slot != Some(width) {
+            *slot = Some(width);
+            cx.notify();
+        }
+    });
+}
+
+/// A horizontal bar of grouped controls with start, center, and end regions.
+///
+/// Items that don't fit in the bar's width move, from the last one back,
+/// into a "⋯" overflow menu at the end of the bar. With
+/// `.collapse_below(breakpoint)`, every item moves there while the window is
+/// narrower than that breakpoint.
+#[derive(IntoElement)]
+pub struct Toolbar {
+    id: ElementId,
+    start: Vec<ToolbarGroup>,
+    center: Vec<ToolbarGroup>,
+    end: Vec<ToolbarGroup>,
+    collapse_below: Option<Breakpoint>,
+}
+
+impl Toolbar {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            start: Vec::new(),
+            center: Vec::new(),
+            end: Vec::new(),
+            collapse_below: None,
+        }
+    }
+
+    /// Adds a group to the start region.
+    pub fn group(self, group: ToolbarGroup) -> Self {
+        self.group_in(ToolbarAlign::Start, group)
+    }
+
+    /// Adds a group to the given region.
+    pub fn group_in(mut self, align: ToolbarAlign, group: ToolbarGroup) -> Self {
+        match align {
+            ToolbarAlign::Start => self.start.push(group),
+            ToolbarAlign::Center => self.center.push(group),
+            ToolbarAlign::End => self.end.push(group),
+        }
+        self
+    }
+
+    /// Moves every item into the overflow menu while the window is narrower
+    /// than `breakpoint`.
+    pub fn collapse_below(mut self, breakpoint: Breakpoint) -> Self {
+        self.collapse_below = Some(breakpoint);
+        self
+    }
+
+    fn render_region(groups: Vec<Vec<AnyElement>>) -> Div {
+        let mut region = div().flex().flex_none().items_center().gap(ITEM_GAP);
+        let mut first = true;
+        for items in groups.into_iter().filter(|items| !items.is_empty()) {
+            if !first {
+                region = region.child(Separator::vertical());
+            }
+            first = false;
+            region = region.children(items);
+        }
+        region
+    }
+}
+
+impl RenderOnce for Toolbar {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let state = window.use_keyed_state(self.id.clone(), cx, |_, _| ToolbarState {
+            overflow_open: false,
+            width: None,
+            item_widths: Vec::new(),
+            overflow_button_width: None,
+        });
+
+        let regions = [self.start, self.center, self.end];
+        let total: usize = regions.iter().flatten().map(|group| group.items.len()).sum();
+        let collapsed = self
+            .collapse_below
+            .is_some_and(|breakpoint| Breakpoint::current(window) < breakpoint);
+
+        // Until the bar and every item have been measured, show everything so
+        // they can be.
+        let visible = state.update(cx, |state, _| {
+            state.item_widths.resize(total, None);
+            if collapsed {
+                return 0;
+            }
+            let Some(width) = state.width else {
+                return total;
+            };
+            let mut widths = state.item_widths.iter().copied();
+            let mut measured = true;
+            let region_widths: RegionWidths = [0, 1, 2].map(|region| {
+                regions[region]
+                    .iter()
+                    .map(|group| {
+                        (0..group.items.len())
+                            .map(|_| {
+                                widths.next().flatten().unwrap_or_else(|| {
+                                    measured = false;
+                                    Pixels::ZERO
+                                })
+                            })
+                            .collect()
+                    })
+                    .collect()
+            });
+            if !measured {
+                return total;
+            }
+            let overflow_button = state
+                .overflow_button_width
+                .unwrap_or(OVERFLOW_BUTTON_ESTIMATE);
+            visible_count(&region_widths, width, overflow_button)
+        });
+
+        // Split items into what fits inline and what overflows, keeping the
+        // group structure for the inline part.
+        let mut index = 0;
+        let mut overflow: Vec<AnyElement> = Vec::new();
+        let [start, center, end] = regions.map(|groups| -> Vec<Vec<AnyElement>> {
+            groups
+                .into_iter()
+                .map(|group| {
+                    let mut inline = Vec::new();
+                    for item in group.items {
+                        if index < visible {
+                            let measure_state = state.clone();
+                            let item_index = index;
+                            inline.push(
+                                div()
+                                    .relative()
+                                    .flex_none()
+                                    .child(item)
+                                    .child(measure_width(move |width, cx| {
+                                        record_width(
+                                            &measure_state,
+                                            cx,
+                                            |state| &mut state.item_widths[item_index],
+                                            width,
+                                        )
+                                    }))
+                                    .into_any_element(),
+                            );
+                        } else {
+                            overflow.push(item);
+                        }
+                        index += 1;
+                    }
+                    inline
+                })
+                .collect()
+        });
+
+        let overflow_open = state.read(cx).overflow_open;
+        let overflow_menu = (!overflow.is_empty()).then(|| {
+            let toggle_state = state.clone();
+            let close_state = state.clone();
+            let measure_state = state.clone();
+
+            div()
+                .relative()
+                .flex()
+                .flex_none()
+                .flex_col()
+                .debug_selector(|| "toolbar-overflow".into())
+                .child(
+                    Button::new("toolbar-overflow", "⋯")
+                        .variant(ButtonVariant::Ghost)
+                        .size(ButtonSize::Small)
+                        .on_click(move |_, _window, cx| {
+                            toggle_state.update(cx, |state, cx| {
+                                state.overflow_open = !state.overflow_open;
+                                cx.notify();
+                            });
+                        }),
+                )
+                .child(measure_width(move |width, cx| {
+                    record_width(
+                        &measure_state,
+                        cx,
+                        |state| &mut state.overflow_button_width,
+                        width,
+                    )
+                }))
+                .when(overflow_open, |this| {
+                    this.child(
+                        Overlay::popover("toolbar-overflow-menu")
+                            .on_outside_click(move |_, _window, cx| {
+                                close_state.update(cx, |state, cx| {
+                                    state.overflow_open = false;
+                                    cx.notify();
+                                });
+                            })
+                            .child(
+                                div()
+                                    .mt_1()
+                                    .flex()
+                                    .flex_col()
+                                    .gap_1()
+                                    .p_1()
+                                    .min_w(px(160.0))
+                                    .bg(colors::BACKGROUND)
+                                    .border_1()
+                                    .border_color(colors::BORDER)
+                                    .rounded(px(6.0))
+                                    .shadow_md()
+                                    .children(overflow),
+                            ),
+                    )
+                })
+        });
+
+        let measure_state = state.clone();
+        div()
+            .id(self.id)
+            .relative()
+            .flex()
+            .items_center()
+            .justify_between()
+            .gap(REGION_GAP)
+            .w_full()
+            .px(BAR_PADDING)
+            .py_1()
+            .overflow_hidden()
+            .bg(colors::BACKGROUND)
+            .border_b_1()
+            .border_color(colors::BORDER)
+            .child(Self::render_region(start))
+            .child(Self::render_region(center))
+            .child(
+                Self::render_region(end)
+                    .when_some(overflow_menu, |region, menu| region.child(menu)),
+            )
+            .child(measure_width(move |width, cx| {
+                record_width(&measure_state, cx, |state| &mut state.width, width)
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{required_width, visible_count, RegionWidths, Toolbar, ToolbarGroup};
+    use crate::button::Button;
+    use gpui::{div, prelude::*, px, Pixels, TestAppContext, Window};
+
+    #[test]
+    fn visible_count_leaves_room_for_the_overflow_button() {
+        let regions: RegionWidths =
+            [vec![vec![px(40.0), px(40.0)], vec![px(40.0)]], vec![], vec![]];
+        // 16 padding + 16 region gaps + 40 + 4 + 40 + 4 + 9 + 4 + 40
+        assert_eq!(required_width(&regions, 3, px(30.0)), px(173.0));
+        assert_eq!(visible_count(&regions, px(173.0), px(30.0)), 3);
+        // Two items and the button: 16 + 16 + 40 + 4 + 40 + 4 + 30
+        assert_eq!(visible_count(&regions, px(172.0), px(30.0)), 2);
+        assert_eq!(visible_count(&regions, px(10.0), px(30.0)), 0);
+    }
+
+    struct Panel {
+        width: Pixels,
+    }
+
+    impl Render for Panel {
+        fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+            let group = ToolbarGroup::new().children(
+                ["Bold", "Italic", "Underline", "Strike", "Code", "Quote"]
+                    .into_iter()
+                    .map(|label| {
+                        div()
+                            .debug_selector(move || label.into())
+                            .child(Button::new(label, label))
+                    }),
+            );
+            div()
+                .w(self.width)
+                .child(Toolbar::new("toolbar").group(group))
+        }
+    }
+
+    #[gpui::test]
+    fn items_that_do_not_fit_overflow(cx: &mut TestAppContext) {
+        let (_view, cx) = cx.add_window_view(|_, _| Panel { width: px(200.0) });
+        cx.run_until_parked();
+
+        assert!(cx.debug_bounds("toolbar-overflow").is_some());
+        assert!(cx.debug_bounds("Quote").is_none());
+        let bold = cx.debug_bounds("Bold").expect("first item stays inline");
+        assert!(bold.right() <= px(200.0));
+    }
+
+    #[gpui::test]
+    fn items_that_fit_stay_inline(cx: &mut TestAppContext) {
+        let (_view, cx) = cx.add_window_view(|_, _| Panel { width: px(1000.0) });
+        cx.run_until_parked();
+
+        assert!(cx.debug_bounds("toolbar-overflow").is_none());
+        assert!(cx.debug_bounds("Quote").is_some());
+    }
+}
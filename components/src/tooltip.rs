@@ -0,0 +1,321 @@
+use crate::prelude::*;
+use std::time::Duration;
+
+/// GPUI waits this long before asking an element for its tooltip. Delays
+/// configured below this cannot take effect.
+const BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Default cap on the bubble width; longer lines wrap.
+const DEFAULT_MAX_WIDTH: Pixels = px(280.0);
+/// Space between the pointer and an `Above` bubble.
+const POINTER_GAP: Pixels = px(8.0);
+/// Height of the arrow; it is twice as wide at its base.
+const ARROW_SIZE: usize = 5;
+/// Distance from the bubble's left edge to the arrow, so the arrow sits
+/// under the pointer GPUI anchors the tooltip to.
+const ARROW_INSET: Pixels = px(6.0);
+/// Height assumed for a text line until the bubble has been measured.
+const ESTIMATED_LINE_HEIGHT: Pixels = px(16.0);
+
+/// Which side of the pointer a tooltip opens on. A tooltip that would not fit
+/// on that side of the window opens on the other.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TooltipPlacement {
+    #[default]
+    Below,
+    Above,
+}
+
+/// App-wide tooltip defaults. Install with `cx.set_global(...)`; every
+/// tooltip built by this crate, including disabled-reason tooltips, reads it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TooltipSettings {
+    /// Hover time before the tooltip appears. GPUI's own 500ms hover delay is
+    /// the minimum.
+    pub delay: Duration,
+    pub placement: TooltipPlacement,
+}
+
+impl Global for TooltipSettings {}
+
+impl Default for TooltipSettings {
+    fn default() -> Self {
+        Self {
+            delay: BASE_DELAY,
+            placement: TooltipPlacement::Below,
+        }
+    }
+}
+
+impl TooltipSettings {
+    /// Returns the installed settings, or the defaults if none are set.
+    pub fn get(cx: &App) -> Self {
+        cx.try_global::<TooltipSettings>()
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// A small floating label shown while hovering an element.
+///
+/// Attach it to any element with an id through GPUI's `tooltip` builder:
+///
+/// ```ignore
+/// div().id("save").tooltip(Tooltip::text("Save the document"))
+/// ```
+///
+/// The title may span several lines: `\n` starts a new one and long lines wrap
+/// at the maximum width. Colors come from the theme, inverted so the bubble is
+/// dark on a light theme.
+#[derive(Clone)]
+pub struct Tooltip {
+    title: SharedString,
+    meta: Option<SharedString>,
+    delay: Option<Duration>,
+    placement: Option<TooltipPlacement>,
+    max_width: Pixels,
+    arrow: bool,
+    visible: bool,
+    /// The bubble's rendered height, used to decide whether it fits.
+    measured_height: Option<Pixels>,
+}
+
+impl Tooltip {
+    pub fn new(title: impl Into<SharedString>) -> Self {
+        Self {
+            title: title.into(),
+            meta: None,
+            delay: None,
+            placement: None,
+            max_width: DEFAULT_MAX_WIDTH,
+            arrow: false,
+            visible: true,
+            measured_height: None,
+        }
+    }
+
+    /// Adds a secondary line below the title, e.g. a shortcut or explanation.
+    pub fn meta(mut self, meta: impl Into<SharedString>) -> Self {
+        self.meta = Some(meta.into());
+        self
+    }
+
+    /// Overrides the global show delay for this tooltip.
+    pub fn with_tooltip_delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+
+    /// Overrides the global placement for this tooltip.
+    pub fn placement(mut self, placement: TooltipPlacement) -> Self {
+        self.placement = Some(placement);
+        self
+    }
+
+    /// Caps the bubble width. Defaults to 280px.
+    pub fn max_width(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.max_width = max_width.into();
+        self
+    }
+
+    /// Draws a small arrow on the side facing the pointer.
+    pub fn arrow(mut self, arrow: bool) -> Self {
+        self.arrow = arrow;
+        self
+    }
+
+    /// The main line of text, also used as the accessible name of icon-only
+    /// controls.
+    pub fn title(&self) -> &SharedString {
+        &self.title
+    }
+
+    /// Returns a tooltip builder for a plain text tooltip, suitable for
+    /// passing straight to `.tooltip(...)`.
+    pub fn text(title: impl Into<SharedString>) -> impl Fn(&mut Window, &mut App) -> AnyView {
+        Self::new(title).builder()
+    }
+
+    /// Turns a configured tooltip into a builder for `.tooltip(...)`.
+    pub fn builder(self) -> impl Fn(&mut Window, &mut App) -> AnyView {
+        move |_window, cx| {
+            let tooltip = self.clone();
+            cx.new(|cx| tooltip.schedule_reveal(cx)).into()
+        }
+    }
+
+    /// Keeps the tooltip hidden for whatever part of the configured delay
+    /// GPUI's hover delay has not already covered.
+    fn schedule_reveal(mut self, cx: &mut Context<Self>) -> Self {
+        let delay = self.delay.unwrap_or_else(|| TooltipSettings::get(cx).delay);
+        let remaining = delay.saturating_sub(BASE_DELAY);
+
+        if !remaining.is_zero() {
+            self.visible = false;
+            cx.spawn(async move |this, cx| {
+                cx.background_executor().timer(remaining).await;
+                this.update(cx, |tooltip, cx| {
+                    tooltip.visible = true;
+                    cx.notify();
+                })
+                .ok();
+            })
+            .detach();
+        }
+
+        self
+    }
+}
+
+/// The side the tooltip opens on: `preferred` unless the bubble would run
+/// past that edge of the window and fits on the other side.
+fn resolve_placement(
+    preferred: TooltipPlacement,
+    pointer_y: Pixels,
+    window_height: Pixels,
+    height: Pixels,
+) -> TooltipPlacement {
+    let fits_above = pointer_y - POINTER_GAP - height >= px(0.0);
+    let fits_below = pointer_y + height <= window_height;
+    match preferred {
+        TooltipPlacement::Above if !fits_above && fits_below => TooltipPlacement::Below,
+        TooltipPlacement::Below if !fits_below && fits_above => TooltipPlacement::Above,
+        placement => placement,
+    }
+}
+
+/// A triangle built from one-pixel rows, pointing up or down.
+fn arrow(color: Rgba, placement: TooltipPlacement) -> Div {
+    let mut rows: Vec<Div> = (1..=ARROW_SIZE)
+        .map(|row| div().h(px(1.0)).w(px(2.0 * row as f32)).bg(color))
+        .collect();
+    // Below the pointer the arrow points up, so its tip comes first.
+    if placement == TooltipPlacement::Above {
+        rows.reverse();
+    }
+    div()
+        .flex()
+        .flex_col()
+        .items_center()
+        .ml(ARROW_INSET)
+        .w(px(2.0 * ARROW_SIZE as f32))
+        .children(rows)
+}
+
+impl Render for Tooltip {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        if !self.visible {
+            return div().into_any_element();
+        }
+
+        let theme = cx.theme();
+        let line_count = self.title.split('\n').count() + usize::from(self.meta.is_some());
+        let estimated_height = ESTIMATED_LINE_HEIGHT * line_count as f32 + px(8.0);
+        let arrow_height = if self.arrow { px(ARROW_SIZE as f32) } else { px(0.0) };
+        let placement = resolve_placement(
+            self.placement
+                .unwrap_or_else(|| TooltipSettings::get(cx).placement),
+            window.mouse_position().y,
+            window.viewport_size().height,
+            self.measured_height.unwrap_or(estimated_height) + arrow_height,
+        );
+
+        let this = cx.entity();
+        let measure = canvas(
+            move |bounds, _, cx| {
+                this.update(cx, |tooltip, cx| {
+                    if tooltip.measured_height != Some(bounds.size.height) {
+                        tooltip.measured_height = Some(bounds.size.height);
+                        cx.notify();
+                    }
+                })
+            },
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .top_0()
+        .left_0()
+        .size_full();
+
+        let bubble = div()
+            .relative()
+            .flex()
+            .flex_col()
+            .gap(px(2.0))
+            .max_w(self.max_width)
+            .px_2()
+            .py_1()
+            .bg(theme.foreground)
+            .text_color(theme.background)
+            .text_xs()
+            .whitespace_normal()
+            .rounded(theme.radius)
+            .shadow_md()
+            .child(measure)
+            .children(
+                self.title
+                    .split('\n')
+                    .map(|line| div().child(SharedString::from(line.to_string()))),
+            )
+            .when_some(self.meta.clone(), |tooltip, meta| {
+                tooltip.child(div().opacity(0.7).child(meta))
+            });
+
+        let content = div()
+            .flex()
+            .flex_col()
+            .when(self.arrow && placement == TooltipPlacement::Below, |content| {
+                content.child(arrow(theme.foreground, placement))
+            })
+            .child(bubble)
+            .when(self.arrow && placement == TooltipPlacement::Above, |content| {
+                content.child(arrow(theme.foreground, placement))
+            });
+
+        // GPUI anchors tooltips at the pointer. Both placements hang the
+        // content from a zero-height wrapper so GPUI doesn't shift it
+        // vertically on its own; `resolve_placement` already did.
+        let content = match placement {
+            TooltipPlacement::Below => content.absolute().top_0(),
+            TooltipPlacement::Above => content.absolute().bottom(POINTER_GAP),
+        };
+        div()
+            .relative()
+            .w(self.max_width)
+            .child(content)
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{resolve_placement, TooltipPlacement};
+    use gpui::px;
+
+    #[test]
+    fn flips_when_the_preferred_side_overflows() {
+        let window_height = px(600.0);
+        let height = px(40.0);
+
+        // Room on both sides keeps the preference
+        assert_eq!(
+            resolve_placement(TooltipPlacement::Above, px(300.0), window_height, height),
+            TooltipPlacement::Above
+        );
+        // Too close to the top for Above
+        assert_eq!(
+            resolve_placement(TooltipPlacement::Above, px(20.0), window_height, height),
+            TooltipPlacement::Below
+        );
+        // Too close to the bottom for Below
+        assert_eq!(
+            resolve_placement(TooltipPlacement::Below, px(580.0), window_height, height),
+            TooltipPlacement::Above
+        );
+        // Fits neither way: keep the preference
+        assert_eq!(
+            resolve_placement(TooltipPlacement::Below, px(20.0), px(50.0), height),
+            TooltipPlacement::Below
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use gpui::{App, ClickEvent, Window};
+use gpui::{App, ClickEvent, SharedString, Window};
 
 /// A trait for elements that can be clicked. Enables the use of the `on_click` method.
 pub trait Clickable {
@@ -6,10 +6,35 @@ pub trait Clickable {
     fn on_click(self, handler: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static) -> Self;
 }
 
+/// A trait for elements that report pointer enter and leave. This is for
+/// running logic on hover; visual hover styles are applied separately.
+pub trait Hoverable {
+    /// Sets the handler called with `true` when the pointer enters the element
+    /// and `false` when it leaves.
+    fn on_hover(self, handler: impl Fn(bool, &mut Window, &mut App) + 'static) -> Self;
+}
+
 /// A trait for elements that can be disabled.
 pub trait Disableable {
     /// Sets whether the element is disabled.
     fn disabled(self, disabled: bool) -> Self;
+
+    /// Explains why the element is disabled. Shown as a tooltip while the
+    /// disabled element is hovered by elements that support it; others
+    /// ignore it.
+    fn disabled_reason(self, _reason: impl Into<SharedString>) -> Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+}
+
+/// A trait for elements that expose a name to assistive technology.
+pub trait Accessible {
+    /// Sets the name screen readers announce for the element. Defaults to the
+    /// visible label, or the tooltip for icon-only elements.
+    fn accessible_name(self, name: impl Into<SharedString>) -> Self;
 }
 
 /// A trait for elements that can be toggled.
@@ -18,6 +43,12 @@ pub trait Toggleable {
     fn toggle_state(self, state: ToggleState) -> Self;
 }
 
+/// A trait for elements that can be selected, such as toggle buttons or list items.
+pub trait Selectable {
+    /// Sets whether the element is selected.
+    fn selected(self, selected: bool) -> Self;
+}
+
 /// Represents the selection status of a toggleable element.
 #[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum ToggleState {
@@ -0,0 +1,102 @@
+// Width-based text truncation.
+//
+// CSS-style ellipsis only clips at paint time, so a component that needs to
+// know its label's final width during layout can't rely on it. These helpers
+// measure candidate prefixes with GPUI's text system and return the longest
+// one that fits, followed by "…".
+
+use crate::prelude::*;
+
+pub const ELLIPSIS: &str = "…";
+
+/// Truncates `text` so it fits in `max_width` when drawn in `font` at
+/// `font_size`, ending it with "…" if anything was cut.
+pub fn truncate_to_width(
+    text: impl Into<SharedString>,
+    max_width: Pixels,
+    font: &Font,
+    font_size: Pixels,
+    window: &Window,
+) -> SharedString {
+    let text_system = window.text_system();
+    let measure = |candidate: &str| {
+        let run = TextRun {
+            len: candidate.len(),
+            font: font.clone(),
+            color: Hsla::default(),
+            background_color: None,
+            underline: None,
+            strikethrough: None,
+        };
+        text_system
+            .shape_line(candidate.to_string().into(), font_size, &[run], None)
+            .width
+    };
+    truncate_with(text.into(), max_width, measure)
+}
+
+/// Like `truncate_to_width`, but in the window's current font.
+pub fn truncate_in_window_font(
+    text: impl Into<SharedString>,
+    max_width: Pixels,
+    font_size: Pixels,
+    window: &Window,
+) -> SharedString {
+    let font = window.text_style().font();
+    truncate_to_width(text, max_width, &font, font_size, window)
+}
+
+/// Truncates `text` using `measure` to find each candidate's width. Trailing
+/// whitespace before the ellipsis is dropped.
+pub fn truncate_with(
+    text: SharedString,
+    max_width: Pixels,
+    measure: impl Fn(&str) -> Pixels,
+) -> SharedString {
+    if measure(&text) <= max_width {
+        return text;
+    }
+
+    // Binary search for the most characters that fit alongside the ellipsis
+    let boundaries: Vec<usize> = text.char_indices().map(|(index, _)| index).collect();
+    let (mut low, mut high) = (0, boundaries.len().saturating_sub(1));
+    while low < high {
+        let mid = (low + high).div_ceil(2);
+        let candidate = format!("{}{}", text[..boundaries[mid]].trim_end(), ELLIPSIS);
+        if measure(&candidate) <= max_width {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+
+    let prefix = boundaries.get(low).map_or(text.as_ref(), |end| &text[..*end]);
+    format!("{}{}", prefix.trim_end(), ELLIPSIS).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::truncate_with;
+    use gpui::{px, Pixels};
+
+    // Every character is 10px wide
+    fn monospace(text: &str) -> Pixels {
+        px(text.chars().count() as f32 * 10.0)
+    }
+
+    #[test]
+    fn fitting_text_is_unchanged() {
+        assert_eq!(truncate_with("Save".into(), px(40.0), monospace), "Save");
+    }
+
+    #[test]
+    fn cuts_at_the_last_prefix_that_fits_with_the_ellipsis() {
+        assert_eq!(truncate_with("Settings".into(), px(50.0), monospace), "Sett…");
+        assert_eq!(truncate_with("Settings".into(), px(5.0), monospace), "…");
+    }
+
+    #[test]
+    fn drops_whitespace_before_the_ellipsis() {
+        assert_eq!(truncate_with("New project".into(), px(50.0), monospace), "New…");
+    }
+}
@@ -0,0 +1,55 @@
+// A card's click handler shouldn't fire for clicks on controls inside it.
+// This lives here rather than in card.rs because it needs Button too, and
+// card.rs is copied into apps that may not have it.
+
+use gpui::{div, point, prelude::*, px, Modifiers, TestAppContext, Window};
+use gpui_ui_components::{Button, Card, CardFooter, CardHeader, Clickable};
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[derive(Default)]
+struct Clicks {
+    card: Rc<Cell<usize>>,
+    delete: Rc<Cell<usize>>,
+}
+
+impl Render for Clicks {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        let card = self.card.clone();
+        let delete = self.delete.clone();
+        div().size_full().child(
+            Card::new()
+                .id("project")
+                .on_click(move |_, _, _| card.set(card.get() + 1))
+                .header(CardHeader::new().title("Project"))
+                .footer(
+                    CardFooter::new().child(
+                        div().debug_selector(|| "delete".into()).child(
+                            Button::new("delete", "Delete")
+                                .on_click(move |_, _, _| delete.set(delete.get() + 1)),
+                        ),
+                    ),
+                ),
+        )
+    }
+}
+
+#[gpui::test]
+fn nested_button_click_does_not_reach_card(cx: &mut TestAppContext) {
+    let (view, cx) = cx.add_window_view(|_, _| Clicks::default());
+    cx.run_until_parked();
+
+    let delete = cx.debug_bounds("delete").expect("delete button rendered");
+    cx.simulate_click(delete.center(), Modifiers::none());
+    view.read_with(cx, |clicks, _| {
+        assert_eq!(clicks.delete.get(), 1);
+        assert_eq!(clicks.card.get(), 0);
+    });
+
+    // Above the button, inside the header
+    cx.simulate_click(delete.origin - point(px(0.0), px(20.0)), Modifiers::none());
+    view.read_with(cx, |clicks, _| {
+        assert_eq!(clicks.delete.get(), 1);
+        assert_eq!(clicks.card.get(), 1);
+    });
+}
@@ -4,7 +4,8 @@ use gpui_ui_components::{
     Badge, BadgeSize, BadgeVariant, Button, ButtonVariant, ButtonSize,
     Card, CardContent, CardFooter, CardHeader, CardVariant,
     Checkbox, CheckboxSize, ToggleState,
-    Input, InputSize, InputVariant,
+    Dialog, DialogContent, DialogFooter, DialogHeader, DialogSize,
+    Input, InputEvent, InputGroup, InputSize, InputVariant, List, ListReorder,
 };
 use gpui_ui_components::input::input_actions;
 use gpui::Application;
@@ -33,24 +34,33 @@ struct TodoApp {
     todos: Vec<TodoItem>,
     show_completed: bool,
     input_text: Entity<Input>,
+    _input_subscription: Subscription,
+    show_manage_dialog: bool,
+    show_delete_all_confirm: bool,
 }
 
 impl TodoApp {
-    fn new(cx: &mut Context<Self>) -> Self {
-        let app_entity = cx.entity();
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
         let input_text = cx.new(|cx| {
             Input::new("todo-input", cx)
                 .placeholder("Add a new todo... (Press Enter to add)")
                 .size(InputSize::Medium)
                 .variant(InputVariant::Default)
-                .on_submit(move |text, _window, cx| {
-                    app_entity.update(cx, |this, cx| {
-                        this.add_todo(text.to_string());
-                        cx.notify();
-                    })
-                })
+                .bordered(false)
         });
-        
+
+        // React to submits only; keystrokes never call back into this view.
+        let _input_subscription = cx.subscribe_in(
+            &input_text,
+            window,
+            |this, _input, event: &InputEvent, window, cx| {
+                if let InputEvent::Submitted(_) = event {
+                    this.submit_input(window, cx);
+                    cx.notify();
+                }
+            },
+        );
+
         Self {
             todos: vec![
                 TodoItem::new("Learn GPUI"),
@@ -59,6 +69,9 @@ impl TodoApp {
             ],
             show_completed: true,
             input_text,
+            _input_subscription,
+            show_manage_dialog: false,
+            show_delete_all_confirm: false,
         }
     }
 
@@ -74,6 +87,12 @@ impl TodoApp {
         }
     }
 
+    fn submit_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let text = self.input_text.read(cx).content().to_string();
+        self.add_todo(text);
+        self.input_text.update(cx, |input, cx| input.clear(window, cx));
+    }
+
     fn remove_todo(&mut self, id: usize) {
         self.todos.retain(|t| t.id != id);
     }
@@ -85,6 +104,171 @@ impl TodoApp {
     fn toggle_show_completed(&mut self) {
         self.show_completed = !self.show_completed;
     }
+
+    /// Moves a todo so it ends up at position `to` of the visible list.
+    fn move_todo(&mut self, from: usize, to: usize) {
+        let ids: Vec<usize> = self.visible_todos().map(|t| t.id).collect();
+        let (Some(&from_id), Some(&to_id)) = (ids.get(from), ids.get(to)) else {
+            return;
+        };
+        let Some(from_index) = self.todos.iter().position(|t| t.id == from_id) else {
+            return;
+        };
+        let todo = self.todos.remove(from_index);
+        let to_index = self
+            .todos
+            .iter()
+            .position(|t| t.id == to_id)
+            .map_or(self.todos.len(), |index| if from < to { index + 1 } else { index });
+        self.todos.insert(to_index, todo);
+    }
+
+    fn visible_todos(&self) -> impl Iterator<Item = &TodoItem> {
+        self.todos
+            .iter()
+            .filter(|t| self.show_completed || !t.completed)
+    }
+
+    fn render_todo_row(&self, index: usize, cx: &mut Context<Self>) -> impl IntoElement {
+        let Some(todo) = self.visible_todos().nth(index).cloned() else {
+            return div();
+        };
+
+        let todo_id = todo.id;
+        let checkbox_id = ElementId::Name(format!("todo-checkbox-{}", todo_id).into());
+        let delete_btn_id = ElementId::Name(format!("todo-delete-{}", todo_id).into());
+        
+        div()
+            .flex()
+            .items_center()
+            .justify_between()
+            .p_2()
+            .rounded(px(4.0))
+            .hover(|s| s.bg(rgb(0xf1f5f9))) // slate-100
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        Checkbox::new(checkbox_id, ToggleState::from(todo.completed))
+                            .size(CheckboxSize::Medium)
+                            .on_click(cx.listener(move |this, _state, _window, cx| {
+                                this.toggle_todo(todo_id);
+                                cx.notify();
+                            }))
+                    )
+                    .child(
+                        div()
+                            .flex_1()
+                            .text_color(if todo.completed {
+                                rgb(0x94a3b8) // slate-400
+                            } else {
+                                rgb(0x0f172a) // slate-900
+                            })
+                            .when(todo.completed, |d| {
+                                d.line_through()
+                            })
+                            .child(todo.text.clone())
+                    )
+            )
+            .child(
+                Button::new(delete_btn_id, "Delete")
+                    .variant(ButtonVariant::Destructive)
+                    .size(ButtonSize::Small)
+                    .on_click(cx.listener(move |this, _event, _window, cx| {
+                        this.remove_todo(todo_id);
+                        cx.notify();
+                    }))
+            )
+    }
+
+    fn render_manage_dialog(&self, cx: &mut Context<Self>) -> Dialog {
+        Dialog::new()
+            .id("manage-dialog")
+            .open(self.show_manage_dialog)
+            .on_dismiss({
+                let view = cx.entity().downgrade();
+                move |_window, cx| {
+                    view.update(cx, |this, cx| {
+                        this.show_manage_dialog = false;
+                        cx.notify();
+                    })
+                    .ok();
+                }
+            })
+            .header(
+                DialogHeader::new()
+                    .title("Manage todos")
+                    .description(format!("{} todos in total", self.todos.len()))
+            )
+            .content(
+                DialogContent::new()
+                    .child(
+                        Button::new("delete-all-btn", "Delete all todos...")
+                            .variant(ButtonVariant::Destructive)
+                            .disabled(self.todos.is_empty())
+                            .on_click(cx.listener(|this, _event, _window, cx| {
+                                this.show_delete_all_confirm = true;
+                                cx.notify();
+                            }))
+                    )
+            )
+            .footer(
+                DialogFooter::new()
+                    .child(
+                        Button::new("manage-done-btn", "Done")
+                            .variant(ButtonVariant::Outline)
+                            .on_click(cx.listener(|this, _event, _window, cx| {
+                                this.show_manage_dialog = false;
+                                cx.notify();
+                            }))
+                    )
+            )
+    }
+
+    fn render_delete_all_confirm(&self, cx: &mut Context<Self>) -> Dialog {
+        Dialog::new()
+            .id("delete-all-confirm")
+            .size(DialogSize::Small)
+            .open(self.show_delete_all_confirm)
+            .on_dismiss({
+                let view = cx.entity().downgrade();
+                move |_window, cx| {
+                    view.update(cx, |this, cx| {
+                        this.show_delete_all_confirm = false;
+                        cx.notify();
+                    })
+                    .ok();
+                }
+            })
+            .header(
+                DialogHeader::new()
+                    .title("Delete all todos?")
+                    .description("This cannot be undone.")
+            )
+            .footer(
+                DialogFooter::new()
+                    .child(
+                        Button::new("delete-all-cancel-btn", "Cancel")
+                            .variant(ButtonVariant::Outline)
+                            .on_click(cx.listener(|this, _event, _window, cx| {
+                                this.show_delete_all_confirm = false;
+                                cx.notify();
+                            }))
+                    )
+                    .child(
+                        Button::new("delete-all-confirm-btn", "Delete all")
+                            .variant(ButtonVariant::Destructive)
+                            .on_click(cx.listener(|this, _event, _window, cx| {
+                                this.todos.clear();
+                                this.show_delete_all_confirm = false;
+                                this.show_manage_dialog = false;
+                                cx.notify();
+                            }))
+                    )
+            )
+    }
 }
 
 impl Render for TodoApp {
@@ -92,11 +276,7 @@ impl Render for TodoApp {
         let active_count = self.todos.iter().filter(|t| !t.completed).count();
         let completed_count = self.todos.iter().filter(|t| t.completed).count();
 
-        let filtered_todos: Vec<TodoItem> = if self.show_completed {
-            self.todos.clone()
-        } else {
-            self.todos.iter().filter(|t| !t.completed).cloned().collect()
-        };
+        let visible_count = self.visible_todos().count();
 
         div()
             .flex()
@@ -121,69 +301,34 @@ impl Render for TodoApp {
                                     .flex_col()
                                     .gap_4()
                                     .child(
-                                        div()
-                                            .flex()
-                                            .gap_2()
-                                            .child(
-                                                div()
-                                                    .flex_1()
-                                                    .child(self.input_text.clone())
+                                        InputGroup::new(self.input_text.clone())
+                                            .trailing(
+                                                Button::new("add-todo-btn", "Add")
+                                                    .on_click(cx.listener(|this, _event, window, cx| {
+                                                        this.submit_input(window, cx);
+                                                        cx.notify();
+                                                    }))
                                             )
                                     )
                                     .child(
-                                        div()
-                                            .flex()
-                                            .flex_col()
-                                            .gap_2()
-                                            .children(filtered_todos.into_iter().map(|todo| {
-                                                let todo_id = todo.id;
-                                                let checkbox_id = ElementId::Name(format!("todo-checkbox-{}", todo_id).into());
-                                                let delete_btn_id = ElementId::Name(format!("todo-delete-{}", todo_id).into());
-                                                
-                                                div()
-                                                    .flex()
-                                                    .items_center()
-                                                    .justify_between()
-                                                    .p_2()
-                                                    .rounded(px(4.0))
-                                                    .hover(|s| s.bg(rgb(0xf1f5f9))) // slate-100
-                                                    .child(
-                                                        div()
-                                                            .flex()
-                                                            .items_center()
-                                                            .gap_2()
-                                                            .child(
-                                                                Checkbox::new(checkbox_id, ToggleState::from(todo.completed))
-                                                                    .size(CheckboxSize::Medium)
-                                                                    .on_click(cx.listener(move |this, _state, _window, cx| {
-                                                                        this.toggle_todo(todo_id);
-                                                                        cx.notify();
-                                                                    }))
-                                                            )
-                                                            .child(
-                                                                div()
-                                                                    .flex_1()
-                                                                    .text_color(if todo.completed {
-                                                                        rgb(0x94a3b8) // slate-400
-                                                                    } else {
-                                                                        rgb(0x0f172a) // slate-900
-                                                                    })
-                                                                    .when(todo.completed, |d| {
-                                                                        d.line_through()
-                                                                    })
-                                                                    .child(todo.text.clone())
-                                                            )
-                                                    )
-                                                    .child(
-                                                        Button::new(delete_btn_id, "Delete")
-                                                            .variant(ButtonVariant::Destructive)
-                                                            .size(ButtonSize::Small)
-                                                            .on_click(cx.listener(move |this, _event, _window, cx| {
-                                                                this.remove_todo(todo_id);
-                                                                cx.notify();
-                                                            }))
-                                                    )
-                                            }))
+                                        List::new(
+                                            "todo-list",
+                                            visible_count,
+                                            cx.processor(|this, index: usize, _window, cx| {
+                                                this.render_todo_row(index, cx).into_any_element()
+                                            }),
+                                        )
+                                        .height(px(240.0))
+                                        .on_reorder(cx.listener(|this, reorder: &ListReorder, _window, cx| {
+                                            this.move_todo(reorder.from, reorder.to);
+                                            cx.notify();
+                                        }))
+                                        .empty_state(
+                                            div()
+                                                .text_sm()
+                                                .text_color(rgb(0x94a3b8)) // slate-400
+                                                .child("Nothing to do")
+                                        )
                                     )
                             )
                     )
@@ -212,6 +357,15 @@ impl Render for TodoApp {
                                                         cx.notify();
                                                     }))
                                             )
+                                            .child(
+                                                Button::new("manage-btn", "Manage")
+                                                    .variant(ButtonVariant::Ghost)
+                                                    .size(ButtonSize::Small)
+                                                    .on_click(cx.listener(|this, _event, _window, cx| {
+                                                        this.show_manage_dialog = true;
+                                                        cx.notify();
+                                                    }))
+                                            )
                                             .when(completed_count > 0, |d| {
                                                 d.child(
                                                     Button::new("clear-completed-btn", "Clear Completed")
@@ -227,6 +381,9 @@ impl Render for TodoApp {
                             )
                     )
             )
+            // Opened from inside the manage dialog, the confirm stacks above it.
+            .child(self.render_manage_dialog(cx))
+            .child(self.render_delete_all_confirm(cx))
     }
 }
 
@@ -262,7 +419,7 @@ fn main() {
                 }),
                 ..Default::default()
             },
-            |_window, cx| cx.new(|cx| TodoApp::new(cx)),
+            |window, cx| cx.new(|cx| TodoApp::new(window, cx)),
         )
         .unwrap();
 